@@ -0,0 +1,45 @@
+//! # AMM Interface for Refund Swaps
+//!
+//! Depositors can lock funds with
+//! [`lock_funds_with_refund_token`](crate::BountyEscrowContract::lock_funds_with_refund_token)
+//! to have refunds converted into a different token (e.g. a stablecoin).
+//! The conversion goes through the AMM configured by the admin via `set_amm`.
+//!
+//! ## AMM Contract Requirements
+//!
+//! ```text
+//! fn swap(env, from, token_in, token_out, amount_in, min_amount_out, to) -> i128
+//! ```
+//!
+//! The escrow approves the AMM to spend `amount_in` of `token_in` before
+//! calling `swap`. The AMM must pull it from `from` with `transfer_from`,
+//! send at least `min_amount_out` of `token_out` to `to` and return the
+//! amount sent, panicking if it cannot honour the minimum.
+//!
+//! ## Slippage Protection
+//!
+//! `min_amount_out` comes from the depositor, who fixes the minimum output
+//! for a full refund at lock time; smaller refunds scale it pro rata. The
+//! AMM's own quotes are never trusted. The escrow also re-checks the
+//! returned amount, so a misbehaving AMM cannot under-deliver silently.
+//!
+//! If no AMM is configured, or the swap fails, the approval is withdrawn
+//! and the refund is paid in the escrowed token instead, so a broken AMM
+//! can never hold a refund hostage.
+
+use soroban_sdk::{contractclient, Address, Env};
+
+/// Interface that swap venues used for refund conversion must implement.
+#[contractclient(name = "AmmClient")]
+pub trait Amm {
+    /// Pull `amount_in` of `token_in` from `from` and send the output to `to`.
+    fn swap(
+        env: Env,
+        from: Address,
+        token_in: Address,
+        token_out: Address,
+        amount_in: i128,
+        min_amount_out: i128,
+        to: Address,
+    ) -> i128;
+}
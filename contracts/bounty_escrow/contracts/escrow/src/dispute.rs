@@ -0,0 +1,42 @@
+//! # Dispute Resolver Interface
+//!
+//! Bounties locked with [`lock_funds_with_resolver`](crate::BountyEscrowContract::lock_funds_with_resolver)
+//! are linked to an external arbitration contract. When either party calls
+//! `raise_dispute`, the escrow contract asks the resolver for a verdict and
+//! settles the bounty accordingly.
+//!
+//! ## Resolver Contract Requirements
+//!
+//! Any contract can act as a resolver as long as it exposes:
+//!
+//! ```text
+//! fn resolve(env: Env, bounty_id: u64) -> ResolutionOutcome
+//! ```
+//!
+//! * `ResolutionOutcome::Release(contributor)` - the escrowed amount is paid
+//!   out to `contributor` (release fees apply as for `release_funds`)
+//! * `ResolutionOutcome::Refund` - the remaining amount is returned to the
+//!   depositor
+//!
+//! The resolver is invoked from inside the escrow contract while the
+//! reentrancy guard is held, so it must not call back into the escrow.
+//! A resolver that panics aborts the whole dispute, leaving the escrow locked.
+
+use soroban_sdk::{contractclient, contracttype, Address, Env};
+
+/// Verdict returned by a dispute resolver.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ResolutionOutcome {
+    /// Pay the escrowed funds to the given contributor.
+    Release(Address),
+    /// Return the remaining funds to the depositor.
+    Refund,
+}
+
+/// Interface that third-party arbitration contracts must implement.
+#[contractclient(name = "DisputeResolverClient")]
+pub trait DisputeResolver {
+    /// Decide the outcome of a disputed bounty.
+    fn resolve(env: Env, bounty_id: u64) -> ResolutionOutcome;
+}
@@ -22,8 +22,17 @@
 //! │       └─────→ Refund  → FundsRefunded                       │
 //! └─────────────────────────────────────────────────────────────┘
 //! ```
+//!
+//! ## Events From Failed Calls
+//!
+//! A contract call that returns an error is rolled back together with the
+//! events it published. Events emitted on an error path (such as
+//! `RefundNotYetAvailable` from `refund`) therefore only reach off-chain
+//! consumers through a call that succeeds; see `check_refund_available`.
 
-use soroban_sdk::{contracttype, symbol_short, Address, Env};
+use crate::dispute::ResolutionOutcome;
+use crate::Escrow;
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String};
 
 // ============================================================================
 // Contract Initialization Event
@@ -92,7 +101,7 @@ pub fn emit_bounty_initialized(env: &Env, event: BountyEscrowInitialized) {
 ///
 /// # Event Topic
 /// Symbol: `f_lock`
-/// Indexed: `bounty_id` (allows filtering by specific bounty)
+/// Indexed: `bounty_id`, `depositor` (allows filtering by bounty or depositor)
 ///
 /// # State Transition
 /// ```text
@@ -132,16 +141,59 @@ pub struct FundsLocked {
 /// * `event` - The funds locked event data
 ///
 /// # Event Structure
-/// Topic: `(symbol_short!("f_lock"), event.bounty_id)`
+/// Topic: `(symbol_short!("f_lock"), event.bounty_id, event.depositor)`
 /// Data: Complete `FundsLocked` struct
 ///
 /// # Indexing Note
 /// The bounty_id is included in topics for efficient filtering
 pub fn emit_funds_locked(env: &Env, event: FundsLocked) {
-    let topics = (symbol_short!("f_lock"), event.bounty_id);
+    let topics = (
+        symbol_short!("f_lock"),
+        event.bounty_id,
+        event.depositor.clone(),
+    );
     env.events().publish(topics, event.clone());
 }
 
+/// Schema version carried by every `EscrowCreated` event.
+pub const ESCROW_CREATED_VERSION: u32 = 1;
+
+/// Event emitted alongside `FundsLocked` with the full stored escrow record.
+///
+/// `FundsLocked` keeps its original four fields for existing consumers;
+/// indexers that want everything persisted at lock time (token, creation
+/// time, ...) should read this event instead. `version` is bumped whenever
+/// the `Escrow` layout changes, so consumers can pick a decoder before
+/// touching `escrow`.
+///
+/// # Event Topic
+/// Symbol: `esc_new`
+/// Indexed: `bounty_id`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct EscrowCreated {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub escrow: Escrow,
+}
+
+/// Emits an EscrowCreated event for a newly stored escrow.
+///
+/// # Event Structure
+/// Topic: `(symbol_short!("esc_new"), bounty_id)`
+/// Data: `EscrowCreated` at `ESCROW_CREATED_VERSION`
+pub fn emit_escrow_created(env: &Env, bounty_id: u64, escrow: &Escrow) {
+    let topics = (symbol_short!("esc_new"), bounty_id);
+    env.events().publish(
+        topics,
+        EscrowCreated {
+            version: ESCROW_CREATED_VERSION,
+            bounty_id,
+            escrow: escrow.clone(),
+        },
+    );
+}
+
 // ============================================================================
 // Funds Released Event
 // ============================================================================
@@ -156,7 +208,7 @@ pub fn emit_funds_locked(env: &Env, event: FundsLocked) {
 ///
 /// # Event Topic
 /// Symbol: `f_rel`
-/// Indexed: `bounty_id`
+/// Indexed: `bounty_id`, `recipient`
 ///
 /// # State Transition
 /// ```text
@@ -202,10 +254,14 @@ pub struct FundsReleased {
 /// * `event` - The funds released event data
 ///
 /// # Event Structure
-/// Topic: `(symbol_short!("f_rel"), event.bounty_id)`
+/// Topic: `(symbol_short!("f_rel"), event.bounty_id, event.recipient)`
 /// Data: Complete `FundsReleased` struct
 pub fn emit_funds_released(env: &Env, event: FundsReleased) {
-    let topics = (symbol_short!("f_rel"), event.bounty_id);
+    let topics = (
+        symbol_short!("f_rel"),
+        event.bounty_id,
+        event.recipient.clone(),
+    );
     env.events().publish(topics, event.clone());
 }
 
@@ -270,6 +326,7 @@ pub struct FundsRefunded {
     pub refund_to: Address,
     pub timestamp: u64,
     pub refund_mode: crate::RefundMode,
+    pub reason: crate::RefundReason,
     pub remaining_amount: i128,
 }
 
@@ -309,6 +366,69 @@ pub fn emit_fee_collected(env: &Env, event: FeeCollected) {
     env.events().publish(topics, event.clone());
 }
 
+/// Event emitted when a penalty is withheld from a refund.
+///
+/// Penalties are reported separately from `FeeCollected` so that penalty
+/// revenue can be reconciled independently of service fees.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PenaltyCollected {
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub penalty_rate: i128,
+    pub recipient: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_penalty_collected(env: &Env, event: PenaltyCollected) {
+    let topics = (symbol_short!("penalty"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+/// Event emitted when a keeper is paid for triggering a refund.
+///
+/// # Event Topic
+/// Symbol: `keeper`, bounty_id
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct KeeperFeePaid {
+    pub bounty_id: u64,
+    pub keeper: Address,
+    pub amount: i128,
+    pub keeper_fee_bps: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_keeper_fee_paid(env: &Env, event: KeeperFeePaid) {
+    let topics = (symbol_short!("keeper"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+/// Event emitted when a refund is attempted before the bounty deadline.
+///
+/// Emitted by `check_refund_available` while the refund is still
+/// time-locked, as a breadcrumb for keepers and indexers without trace
+/// access.
+///
+/// `refund` also emits it just before failing with `DeadlineNotPassed`, but
+/// a failed call's events are discarded on-chain, so that copy is only seen
+/// in simulation and tests.
+///
+/// # Event Topic
+/// Symbol: `ref_early`, bounty_id
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RefundNotYetAvailable {
+    pub bounty_id: u64,
+    pub deadline: u64,
+    pub now: u64,
+}
+
+pub fn emit_refund_not_yet_available(env: &Env, event: RefundNotYetAvailable) {
+    let topics = (symbol_short!("ref_early"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct BatchFundsLocked {
@@ -393,3 +513,384 @@ pub fn emit_emergency_withdrawal(env: &Env, event: EmergencyWithdrawal) {
     let topics = (symbol_short!("ewith"),);
     env.events().publish(topics, event.clone());
 }
+
+// ============================================================================
+// Dispute Events
+// ============================================================================
+
+/// Event emitted when a linked resolver settles a disputed bounty.
+///
+/// # Event Topic
+/// Symbol: `dispute`, bounty_id
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct DisputeResolved {
+    pub bounty_id: u64,
+    pub resolver: Address,
+    pub raised_by: Address,
+    pub outcome: ResolutionOutcome,
+    pub timestamp: u64,
+}
+
+pub fn emit_dispute_resolved(env: &Env, event: DisputeResolved) {
+    let topics = (symbol_short!("dispute"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+// ============================================================================
+// Upgrade Events
+// ============================================================================
+
+/// Event emitted when the contract WASM is replaced via `upgrade`.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ContractUpgraded {
+    pub new_wasm_hash: BytesN<32>,
+    pub upgraded_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_contract_upgraded(env: &Env, event: ContractUpgraded) {
+    let topics = (symbol_short!("upgrade"),);
+    env.events().publish(topics, event.clone());
+}
+
+/// Event emitted when `migrate` moves stored data to a newer schema.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SchemaMigrated {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub timestamp: u64,
+}
+
+pub fn emit_schema_migrated(env: &Env, event: SchemaMigrated) {
+    let topics = (symbol_short!("migrated"),);
+    env.events().publish(topics, event.clone());
+}
+
+// ============================================================================
+// Refund Swap Events
+// ============================================================================
+
+/// Event emitted when a refund is converted to the depositor's chosen token.
+///
+/// # Event Topic
+/// Symbol: `ref_swap`, bounty_id
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RefundSwapped {
+    pub bounty_id: u64,
+    pub token_in: Address,
+    pub token_out: Address,
+    pub amount_in: i128,
+    pub amount_out: i128,
+    pub recipient: Address,
+}
+
+pub fn emit_refund_swapped(env: &Env, event: RefundSwapped) {
+    let topics = (symbol_short!("ref_swap"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+// ============================================================================
+// Token Configuration Events
+// ============================================================================
+
+/// Event emitted when the admin replaces the escrow token.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct TokenUpdated {
+    pub old: Address,
+    pub new: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_token_updated(env: &Env, event: TokenUpdated) {
+    let topics = (symbol_short!("token"),);
+    env.events().publish(topics, event.clone());
+}
+
+// ============================================================================
+// Contributor Assignment Events
+// ============================================================================
+
+/// Event emitted when the admin assigns a contributor to a bounty.
+///
+/// # Event Topic
+/// Symbol: `assign`, bounty_id, contributor
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ContributorAssigned {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub strict: bool,
+    pub timestamp: u64,
+}
+
+pub fn emit_contributor_assigned(env: &Env, event: ContributorAssigned) {
+    let topics = (
+        symbol_short!("assign"),
+        event.bounty_id,
+        event.contributor.clone(),
+    );
+    env.events().publish(topics, event.clone());
+}
+
+/// Event emitted when a release is retried through `retry_release`, after
+/// the matching `FundsReleased`.
+///
+/// # Event Topic
+/// Symbol: `rel_retry`, bounty_id
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ReleaseRetried {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_release_retried(env: &Env, event: ReleaseRetried) {
+    let topics = (symbol_short!("rel_retry"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+/// Event emitted when a signer opens a multisig release.
+///
+/// # Event Topic
+/// Symbol: `rel_prop`, bounty_id
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ReleaseProposed {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub proposed_by: Address,
+    pub threshold: u32,
+    pub timestamp: u64,
+}
+
+pub fn emit_release_proposed(env: &Env, event: ReleaseProposed) {
+    let topics = (symbol_short!("rel_prop"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+/// Event emitted for each multisig confirmation. `confirmations` counts
+/// distinct current signers, including this one.
+///
+/// # Event Topic
+/// Symbol: `rel_conf`, bounty_id
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ReleaseConfirmed {
+    pub bounty_id: u64,
+    pub signer: Address,
+    pub confirmations: u32,
+    pub threshold: u32,
+    pub timestamp: u64,
+}
+
+pub fn emit_release_confirmed(env: &Env, event: ReleaseConfirmed) {
+    let topics = (symbol_short!("rel_conf"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+// ============================================================================
+// Admin Management Events
+// ============================================================================
+
+/// Event emitted when the contract admin is handed to a new address.
+///
+/// # Event Topic
+/// Symbol: `admin`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AdminTransferred {
+    pub previous_admin: Address,
+    pub new_admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_admin_transferred(env: &Env, event: AdminTransferred) {
+    let topics = (symbol_short!("admin"),);
+    env.events().publish(topics, event.clone());
+}
+
+/// Event emitted when the admin gives up the role for good.
+///
+/// # Event Topic
+/// Symbol: `admin_ren`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AdminRenounced {
+    pub previous_admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_admin_renounced(env: &Env, event: AdminRenounced) {
+    let topics = (symbol_short!("admin_ren"),);
+    env.events().publish(topics, event.clone());
+}
+
+/// Event emitted when the admin force-refunds a bounty with `admin_refund`,
+/// after the matching `FundsRefunded`.
+///
+/// # Event Topic
+/// Symbol: `adm_ref`, bounty_id
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AdminRefund {
+    pub bounty_id: u64,
+    pub admin: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_admin_refund(env: &Env, event: AdminRefund) {
+    let topics = (symbol_short!("adm_ref"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+/// Event emitted when `sweep_unaccounted` recovers configured-token funds
+/// that no escrow accounts for.
+///
+/// # Event Topic
+/// Symbol: `sweep`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct UnaccountedSwept {
+    pub amount: i128,
+    pub to: Address,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_unaccounted_swept(env: &Env, event: UnaccountedSwept) {
+    let topics = (symbol_short!("sweep"),);
+    env.events().publish(topics, event.clone());
+}
+
+/// Event emitted when an address joins the admin set.
+///
+/// # Event Topic
+/// Symbol: `admin_add`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AdminAdded {
+    pub admin: Address,
+    pub added_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_admin_added(env: &Env, event: AdminAdded) {
+    let topics = (symbol_short!("admin_add"),);
+    env.events().publish(topics, event.clone());
+}
+
+/// Event emitted when an address leaves the admin set.
+///
+/// # Event Topic
+/// Symbol: `admin_rm`
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AdminRemoved {
+    pub admin: Address,
+    pub removed_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_admin_removed(env: &Env, event: AdminRemoved) {
+    let topics = (symbol_short!("admin_rm"),);
+    env.events().publish(topics, event.clone());
+}
+
+// ============================================================================
+// Milestone Events
+// ============================================================================
+
+/// Event emitted when an oracle-verified milestone tranche is paid out.
+///
+/// # Event Topic
+/// Symbol: `mile_rel`, bounty_id
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct MilestoneReleased {
+    pub bounty_id: u64,
+    pub index: u32,
+    pub amount: i128,
+    pub recipient: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_milestone_released(env: &Env, event: MilestoneReleased) {
+    let topics = (symbol_short!("mile_rel"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+// ============================================================================
+// String-keyed Bounty Events
+// ============================================================================
+
+/// Event emitted when funds are locked for a string-keyed bounty.
+///
+/// # Event Topic
+/// Symbol: `f_lock_s`, bounty_id, depositor
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct FundsLockedStr {
+    pub bounty_id: String,
+    pub amount: i128,
+    pub depositor: Address,
+    pub deadline: u64,
+}
+
+pub fn emit_funds_locked_str(env: &Env, event: FundsLockedStr) {
+    let topics = (
+        symbol_short!("f_lock_s"),
+        event.bounty_id.clone(),
+        event.depositor.clone(),
+    );
+    env.events().publish(topics, event.clone());
+}
+
+/// Event emitted when a string-keyed bounty is released.
+///
+/// # Event Topic
+/// Symbol: `f_rel_s`, bounty_id, recipient
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct FundsReleasedStr {
+    pub bounty_id: String,
+    pub amount: i128,
+    pub recipient: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_funds_released_str(env: &Env, event: FundsReleasedStr) {
+    let topics = (
+        symbol_short!("f_rel_s"),
+        event.bounty_id.clone(),
+        event.recipient.clone(),
+    );
+    env.events().publish(topics, event.clone());
+}
+
+/// Event emitted when a string-keyed bounty is refunded. `amount` is what
+/// left the escrow; the depositor received `amount - penalty`.
+///
+/// # Event Topic
+/// Symbol: `f_ref_s`, bounty_id
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct FundsRefundedStr {
+    pub bounty_id: String,
+    pub amount: i128,
+    pub penalty: i128,
+    pub refund_to: Address,
+    pub timestamp: u64,
+    pub remaining_amount: i128,
+}
+
+pub fn emit_funds_refunded_str(env: &Env, event: FundsRefundedStr) {
+    let topics = (symbol_short!("f_ref_s"), event.bounty_id.clone());
+    env.events().publish(topics, event.clone());
+}
@@ -57,7 +57,9 @@
 //! 3. **Authorization Checks**: All state changes require proper auth
 //! 4. **Deadline Protection**: Prevents indefinite fund locking
 //! 5. **State Machine**: Enforces valid state transitions
-//! 6. **Atomic Operations**: Transfer + state update in single transaction
+//! 6. **Atomic Operations**: Transfer + state update in single transaction,
+//!    following checks-effects-interactions: escrow state is written before
+//!    any token call, and a failed transfer reverts the whole call
 //!
 //! ## Usage Example
 //!
@@ -87,30 +89,45 @@
 //! ```
 
 #![no_std]
+mod amm;
+mod dispute;
 mod events;
+mod migration;
+mod oracle;
 mod test_bounty_escrow;
+mod test_invariants;
 
+pub use amm::{Amm, AmmClient};
+pub use dispute::{DisputeResolver, DisputeResolverClient, ResolutionOutcome};
 use events::{
     emit_batch_funds_locked, emit_batch_funds_released, emit_bounty_initialized,
-    emit_contract_paused, emit_contract_unpaused, emit_emergency_withdrawal, emit_funds_locked,
-    emit_funds_refunded, emit_funds_released, BatchFundsLocked, BatchFundsReleased,
-    BountyEscrowInitialized, ContractPaused, ContractUnpaused, EmergencyWithdrawal, FundsLocked,
-    FundsRefunded, FundsReleased,
+    emit_contract_paused, emit_contract_unpaused, emit_emergency_withdrawal, emit_escrow_created,
+    emit_funds_locked, emit_funds_refunded, emit_funds_released, BatchFundsLocked,
+    BatchFundsReleased, BountyEscrowInitialized, ContractPaused, ContractUnpaused,
+    EmergencyWithdrawal, FundsLocked, FundsRefunded, FundsReleased,
 };
+use migration::EscrowV1;
+pub use oracle::{MilestoneOracle, MilestoneOracleClient};
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Env,
-    Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Bytes,
+    BytesN, Env, Map, String, Symbol, TryFromVal, Val, Vec,
 };
 
 // ==================== MONITORING MODULE ====================
+// Failure counters are written on the error paths of each entrypoint. Note
+// that the host discards storage writes of an invocation that returns `Err`,
+// so failures only persist when the failing call is made from a committed
+// frame (e.g. an in-contract call that handles the error).
 mod monitoring {
-    use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
+    use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
 
     // Storage keys
     const OPERATION_COUNT: &str = "op_count";
-    #[allow(dead_code)]
     const USER_COUNT: &str = "usr_count";
     const ERROR_COUNT: &str = "err_count";
+    const LAST_SNAPSHOT: &str = "last_snap";
+    const EVENTS_ENABLED: &str = "mon_on";
+    const TRACKED_FUNCTIONS: &str = "perf_fns";
 
     // Event: Operation metric
     #[contracttype]
@@ -139,6 +156,8 @@ mod monitoring {
         pub last_operation: u64,
         pub total_operations: u64,
         pub contract_version: String,
+        pub insolvent: bool,
+        pub reentrancy_locked: bool,
     }
 
     // Data: Analytics
@@ -161,6 +180,18 @@ mod monitoring {
         pub total_errors: u64,
     }
 
+    // Data: Change between two state snapshots
+    #[contracttype]
+    #[derive(Clone, Debug)]
+    pub struct SnapshotDelta {
+        pub from_timestamp: u64,
+        pub to_timestamp: u64,
+        pub elapsed: u64,
+        pub operations: u64,
+        pub users: u64,
+        pub errors: u64,
+    }
+
     // Data: Performance stats
     #[contracttype]
     #[derive(Clone, Debug)]
@@ -170,6 +201,21 @@ mod monitoring {
         pub total_time: u64,
         pub avg_time: u64,
         pub last_called: u64,
+        pub error_count: u64,
+    }
+
+    // Metric events are on unless disabled at init; counters always update
+    pub fn events_enabled(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&Symbol::new(env, EVENTS_ENABLED))
+            .unwrap_or(true)
+    }
+
+    pub fn set_events_enabled(env: &Env, enabled: bool) {
+        env.storage()
+            .instance()
+            .set(&Symbol::new(env, EVENTS_ENABLED), &enabled);
     }
 
     // Track operation
@@ -182,8 +228,20 @@ mod monitoring {
             let err_key = Symbol::new(env, ERROR_COUNT);
             let err_count: u64 = env.storage().persistent().get(&err_key).unwrap_or(0);
             env.storage().persistent().set(&err_key, &(err_count + 1));
+
+            let fn_err_key = (Symbol::new(env, "perf_err"), operation.clone());
+            let fn_errors: u64 = env.storage().persistent().get(&fn_err_key).unwrap_or(0);
+            if fn_errors == 0 {
+                register_function(env, &operation);
+            }
+            env.storage()
+                .persistent()
+                .set(&fn_err_key, &(fn_errors + 1));
         }
 
+        if !events_enabled(env) {
+            return;
+        }
         env.events().publish(
             (symbol_short!("metric"), symbol_short!("op")),
             OperationMetric {
@@ -199,15 +257,25 @@ mod monitoring {
     pub fn emit_performance(env: &Env, function: Symbol, duration: u64) {
         let count_key = (Symbol::new(env, "perf_cnt"), function.clone());
         let time_key = (Symbol::new(env, "perf_time"), function.clone());
+        let last_key = (Symbol::new(env, "perf_last"), function.clone());
 
         let count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
         let total: u64 = env.storage().persistent().get(&time_key).unwrap_or(0);
 
+        if count == 0 {
+            register_function(env, &function);
+        }
         env.storage().persistent().set(&count_key, &(count + 1));
         env.storage()
             .persistent()
             .set(&time_key, &(total + duration));
+        env.storage()
+            .persistent()
+            .set(&last_key, &env.ledger().timestamp());
 
+        if !events_enabled(env) {
+            return;
+        }
         env.events().publish(
             (symbol_short!("metric"), symbol_short!("perf")),
             PerformanceMetric {
@@ -218,8 +286,21 @@ mod monitoring {
         );
     }
 
+    // Record a function the first time it shows up in the stats
+    fn register_function(env: &Env, function: &Symbol) {
+        let key = Symbol::new(env, TRACKED_FUNCTIONS);
+        let mut functions: Vec<Symbol> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        if !functions.contains(function) {
+            functions.push_back(function.clone());
+            env.storage().persistent().set(&key, &functions);
+        }
+    }
+
     // Health check
-    #[allow(dead_code)]
     pub fn health_check(env: &Env) -> HealthStatus {
         let key = Symbol::new(env, OPERATION_COUNT);
         let ops: u64 = env.storage().persistent().get(&key).unwrap_or(0);
@@ -229,11 +310,12 @@ mod monitoring {
             last_operation: env.ledger().timestamp(),
             total_operations: ops,
             contract_version: String::from_str(env, "1.0.0"),
+            insolvent: false,
+            reentrancy_locked: false,
         }
     }
 
     // Get analytics
-    #[allow(dead_code)]
     pub fn get_analytics(env: &Env) -> Analytics {
         let op_key = Symbol::new(env, OPERATION_COUNT);
         let usr_key = Symbol::new(env, USER_COUNT);
@@ -258,7 +340,6 @@ mod monitoring {
     }
 
     // Get state snapshot
-    #[allow(dead_code)]
     pub fn get_state_snapshot(env: &Env) -> StateSnapshot {
         let op_key = Symbol::new(env, OPERATION_COUNT);
         let usr_key = Symbol::new(env, USER_COUNT);
@@ -272,18 +353,47 @@ mod monitoring {
         }
     }
 
+    // Diff the current snapshot against the stored one, then store it
+    pub fn take_snapshot_delta(env: &Env) -> SnapshotDelta {
+        let key = Symbol::new(env, LAST_SNAPSHOT);
+        let current = get_state_snapshot(env);
+        let last: StateSnapshot = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(StateSnapshot {
+                timestamp: 0,
+                total_operations: 0,
+                total_users: 0,
+                total_errors: 0,
+            });
+        env.storage().persistent().set(&key, &current);
+
+        SnapshotDelta {
+            from_timestamp: last.timestamp,
+            to_timestamp: current.timestamp,
+            elapsed: current.timestamp.saturating_sub(last.timestamp),
+            operations: current
+                .total_operations
+                .saturating_sub(last.total_operations),
+            users: current.total_users.saturating_sub(last.total_users),
+            errors: current.total_errors.saturating_sub(last.total_errors),
+        }
+    }
+
     // Get performance stats
-    #[allow(dead_code)]
     pub fn get_performance_stats(env: &Env, function_name: Symbol) -> PerformanceStats {
         let count_key = (Symbol::new(env, "perf_cnt"), function_name.clone());
         let time_key = (Symbol::new(env, "perf_time"), function_name.clone());
         let last_key = (Symbol::new(env, "perf_last"), function_name.clone());
+        let err_key = (Symbol::new(env, "perf_err"), function_name.clone());
 
         let count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
         let total: u64 = env.storage().persistent().get(&time_key).unwrap_or(0);
         let last: u64 = env.storage().persistent().get(&last_key).unwrap_or(0);
+        let errors: u64 = env.storage().persistent().get(&err_key).unwrap_or(0);
 
-        let avg = if count > 0 { total / count } else { 0 };
+        let avg = total.checked_div(count).unwrap_or(0);
 
         PerformanceStats {
             function_name,
@@ -291,7 +401,22 @@ mod monitoring {
             total_time: total,
             avg_time: avg,
             last_called: last,
+            error_count: errors,
+        }
+    }
+
+    // Get stats for every function tracked so far, in first-seen order
+    pub fn get_all_performance_stats(env: &Env) -> Vec<(Symbol, PerformanceStats)> {
+        let functions: Vec<Symbol> = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(env, TRACKED_FUNCTIONS))
+            .unwrap_or(Vec::new(env));
+        let mut all = Vec::new(env);
+        for function in functions.iter() {
+            all.push_back((function.clone(), get_performance_stats(env, function)));
         }
+        all
     }
 }
 // ==================== END MONITORING MODULE ====================
@@ -323,6 +448,8 @@ mod anti_abuse {
         State(Address),
         Whitelist(Address),
         Admin,
+        MinLockInterval,
+        LastLock(Address),
     }
 
     pub fn get_config(env: &Env) -> AntiAbuseConfig {
@@ -370,6 +497,40 @@ mod anti_abuse {
         env.storage().instance().set(&AntiAbuseKey::Admin, &admin);
     }
 
+    pub fn get_min_lock_interval(env: &Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&AntiAbuseKey::MinLockInterval)
+            .unwrap_or(0)
+    }
+
+    pub fn set_min_lock_interval(env: &Env, seconds: u64) {
+        env.storage()
+            .instance()
+            .set(&AntiAbuseKey::MinLockInterval, &seconds);
+    }
+
+    /// Records a lock by `depositor` now, returning false if its previous
+    /// lock is less than `min_lock_interval` seconds old. Whitelisted
+    /// addresses are exempt.
+    pub fn record_lock(env: &Env, depositor: &Address) -> bool {
+        let interval = get_min_lock_interval(env);
+        if interval == 0 || is_whitelisted(env, depositor.clone()) {
+            return true;
+        }
+
+        let now = env.ledger().timestamp();
+        let key = AntiAbuseKey::LastLock(depositor.clone());
+        let last: Option<u64> = env.storage().persistent().get(&key);
+        if let Some(last) = last {
+            if now < last.saturating_add(interval) {
+                return false;
+            }
+        }
+        env.storage().persistent().set(&key, &now);
+        true
+    }
+
     pub fn check_rate_limit(env: &Env, address: Address) {
         if is_whitelisted(env, address.clone()) {
             return;
@@ -458,7 +619,6 @@ pub enum Error {
     /// Returned when caller lacks required authorization for the operation
     Unauthorized = 7,
     InvalidFeeRate = 8,
-    FeeRecipientNotSet = 9,
     InvalidBatchSize = 10,
     /// Returned when contract is paused and operation is blocked
     ContractPaused = 11,
@@ -471,7 +631,83 @@ pub enum Error {
     InsufficientFunds = 16,
     /// Returned when refund is attempted without admin approval
     RefundNotApproved = 17,
-    BatchSizeMismatch = 18,
+    /// Returned when a dispute is raised on a bounty without a linked resolver
+    ResolverNotSet = 19,
+    /// Returned when a refund swap delivers less than the slippage-adjusted quote
+    SlippageExceeded = 20,
+    /// Returned when a refund swap is requested but no AMM is configured
+    AmmNotSet = 21,
+    /// Returned when an operation requires that no escrowed funds are outstanding
+    EscrowsOutstanding = 22,
+    /// Returned when a bounty has no milestone plan or the index is out of range
+    MilestoneNotFound = 23,
+    /// Returned when a milestone tranche has already been paid out
+    MilestoneAlreadyReleased = 24,
+    /// Returned when the lock amount is below the configured minimum
+    AmountTooSmall = 25,
+    /// Returned when the lock amount is above the configured maximum
+    AmountTooLarge = 26,
+    /// Returned when the deadline is beyond the configured lock horizon
+    DeadlineTooFar = 27,
+    /// Returned when registration is required and the contributor hasn't registered
+    ContributorNotRegistered = 28,
+    /// Returned when the contributor is on the admin denylist
+    ContributorDenied = 29,
+    /// Returned when approval is required and no matching release approval exists
+    ReleaseNotApproved = 30,
+    /// Returned when the contributor hasn't accepted the current terms version
+    TermsNotAccepted = 31,
+    /// Returned when a strict assignment exists and the release targets someone else
+    ContributorMismatch = 32,
+    /// Returned when releasing to the assigned contributor but none is assigned
+    NoContributorAssigned = 33,
+    /// Returned when a lock would push `TotalLocked` above the configured TVL cap
+    TvlCapExceeded = 34,
+    /// Returned when a depositor already has the maximum number of active bounties
+    TooManyActiveBounties = 35,
+    /// Returned when auto-release is attempted but no review window is configured
+    AutoReleaseNotConfigured = 36,
+    /// Returned when the admin vetoed auto-release for the bounty
+    AutoReleaseVetoed = 37,
+    /// Returned when auto-release is attempted before the review window ends
+    ReviewWindowOpen = 38,
+    /// Returned when funds would be sent to the escrow contract itself
+    InvalidRecipient = 39,
+    /// Returned when locking in a token that is not on the allowlist
+    TokenNotAllowed = 40,
+    /// Returned when renouncing the admin while escrows still hold funds
+    FundsStillLocked = 41,
+    /// Returned when anyone but the depositor triggers a deadline refund on
+    /// a deployment initialized with `permissionless_refund = false`
+    PermissionlessRefundDisabled = 42,
+    /// Returned when stored data uses a newer schema than this code knows,
+    /// or an operation needs a migration that has not finished
+    SchemaMismatch = 43,
+    /// Returned when the depositor allowlist is enabled and the depositor
+    /// is not on it
+    DepositorNotAllowed = 44,
+    /// Returned when timestamp arithmetic (deadline plus grace, assignment
+    /// plus review window) would overflow `u64`
+    TimeOverflow = 45,
+    /// Returned when a single-key release targets an escrow that needs
+    /// multisig confirmation
+    MultisigRequired = 47,
+    /// Returned when a multisig threshold is zero, exceeds the signer
+    /// count, or the signers contain duplicates
+    InvalidThreshold = 48,
+    /// Returned when confirming a bounty that has no release proposal
+    ReleaseNotProposed = 49,
+    /// Returned when a signer confirms the same proposal twice
+    AlreadyConfirmed = 50,
+    /// Returned when a depositor locks again within `min_lock_interval`
+    RateLimited = 51,
+    /// Returned when a deadline is sooner than `now + min_lock_duration`
+    DeadlineTooSoon = 52,
+    /// Returned when a contributor claims after the claim deadline
+    ClaimExpired = 53,
+    /// Returned when revoking a release approval that was never given or
+    /// has already been used
+    ApprovalNotFound = 54,
 }
 
 // ============================================================================
@@ -512,12 +748,29 @@ pub enum RefundMode {
     Custom,
 }
 
+/// Why funds went back out of an escrow, recorded with each refund.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RefundReason {
+    /// Refunded after the escrow deadline
+    DeadlinePassed,
+    /// Refunded early with the admin's approval
+    DepositorCancelled,
+    /// Refunded by a dispute resolver ruling
+    DisputeResolved,
+    /// Refunded early by the admin through `admin_refund`
+    AdminForced,
+    /// Returned to the depositor after the contributor let a claim expire
+    ClaimExpired,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RefundRecord {
     pub amount: i128,
     pub recipient: Address,
     pub mode: RefundMode,
+    pub reason: RefundReason,
     pub timestamp: u64,
 }
 
@@ -532,6 +785,93 @@ pub struct RefundApproval {
     pub approved_at: u64,
 }
 
+/// Admin commitment to release a bounty to a specific contributor.
+///
+/// Approved amounts count towards `total_pending_releases` until the
+/// release is executed, revoked, or the escrow is refunded.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseApproval {
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub contributor: Address,
+    pub approved_by: Address,
+    pub approved_at: u64,
+}
+
+/// Release the contributor pulls with `claim` instead of the admin pushing
+/// it. If not claimed by `claim_deadline`, anyone may return the funds to
+/// the depositor with `reclaim_unclaimed`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingClaim {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub amount: i128,
+    pub authorized_by: Address,
+    pub claim_deadline: u64,
+}
+
+/// M-of-N signer set required to release high-value escrows.
+///
+/// Escrows whose remaining amount is at least `min_amount` can only be
+/// released through `propose_release`/`confirm_release`, once `threshold`
+/// distinct `signers` have confirmed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseMultisig {
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+    pub min_amount: i128,
+}
+
+/// A release waiting on multisig confirmations.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseProposal {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub proposed_by: Address,
+    pub proposed_at: u64,
+    pub confirmations: Vec<Address>,
+}
+
+/// Depositor's choice of token to receive refunds in.
+///
+/// # Fields
+/// * `refund_token` - Token refunds are converted into via the configured AMM
+/// * `min_amount_out` - Minimum `refund_token` output for refunding the full
+///   locked amount; partial refunds scale it pro rata
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundSwapConfig {
+    pub refund_token: Address,
+    pub min_amount_out: i128,
+}
+
+/// A single oracle-verified tranche of a milestone bounty.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Milestone {
+    pub amount: i128,
+    pub metric: Symbol,
+    pub released: bool,
+}
+
+/// Staged payout plan attached to a bounty at lock time.
+///
+/// # Fields
+/// * `oracle` - Contract consulted by `check_milestone`
+/// * `contributor` - Recipient of every released tranche
+/// * `milestones` - Tranches in the order supplied by the depositor
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestonePlan {
+    pub oracle: Address,
+    pub contributor: Address,
+    pub milestones: Vec<Milestone>,
+}
+
 /// Complete escrow record for a bounty.
 ///
 /// # Fields
@@ -539,6 +879,8 @@ pub struct RefundApproval {
 /// * `amount` - Token amount held in escrow (in smallest denomination)
 /// * `status` - Current state of the escrow (Locked/Released/Refunded)
 /// * `deadline` - Unix timestamp after which refunds are allowed
+/// * `created_at` - Ledger timestamp at which the funds were locked
+/// * `token` - Token the funds are held in; releases and refunds pay out in it
 ///
 /// # Storage
 /// Stored in persistent storage with key `DataKey::Escrow(bounty_id)`.
@@ -551,6 +893,7 @@ pub struct RefundApproval {
 ///     amount: 1000_0000000, // 1000 tokens
 ///     status: EscrowStatus::Locked,
 ///     deadline: current_time + 2592000, // 30 days
+///     token: usdc_address,
 /// };
 /// ```
 #[contracttype]
@@ -562,6 +905,105 @@ pub struct Escrow {
     pub deadline: u64,
     pub refund_history: Vec<RefundRecord>,
     pub remaining_amount: i128,
+    pub created_at: u64,
+    pub token: Address,
+}
+
+/// Everything an arbitration screen needs about a bounty, in one read.
+///
+/// # Fields
+/// * `contributor` - Contributor named by a pending release approval or a
+///   dispute verdict, if any
+/// * `resolver` - Linked dispute resolver, if the bounty was locked with one
+/// * `dispute_state` - Whether a dispute can be raised or has been resolved
+/// * `refund_history` - All refunds paid out so far
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeContext {
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub amount: i128,
+    pub remaining_amount: i128,
+    pub status: EscrowStatus,
+    pub deadline: u64,
+    pub created_at: u64,
+    pub contributor: Option<Address>,
+    pub resolver: Option<Address>,
+    pub dispute_state: DisputeState,
+    pub refund_history: Vec<RefundRecord>,
+}
+
+/// An escrow record plus the time-based fields clients would otherwise
+/// derive from it, evaluated at the current ledger timestamp.
+///
+/// # Fields
+/// * `is_expired` - The deadline has been reached
+/// * `remaining_time` - Seconds until the deadline, 0 once expired
+/// * `is_refundable` - Same answer as `is_refundable`; `false` when the
+///   refund unlock time overflows
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowDetails {
+    pub escrow: Escrow,
+    pub is_expired: bool,
+    pub remaining_time: u64,
+    pub is_refundable: bool,
+}
+
+/// Contract-wide totals for dashboards, returned by `get_contract_stats`.
+///
+/// Amounts cover escrows in the configured token only, so that
+/// `total_locked + total_released + total_refunded` reconciles with what was
+/// deposited (net of lock fees).
+///
+/// # Fields
+/// * `total_locked` - Held for unsettled escrows, as `get_total_locked`
+/// * `total_released` - Lifetime amount paid out to contributors, before
+///   release fees
+/// * `total_refunded` - Lifetime amount returned to depositors, before
+///   refund penalties
+/// * `active_count` - Escrows that are locked or partially refunded, in any
+///   token
+/// * `admin` - Current admin, `None` once renounced
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractStats {
+    pub total_locked: i128,
+    pub total_released: i128,
+    pub total_refunded: i128,
+    pub active_count: u32,
+    pub admin: Option<Address>,
+}
+
+/// Optional behaviour fixed at initialization, for `init_with_options`.
+///
+/// # Fields
+/// * `permissionless_refund` - Anyone may trigger deadline refunds (as with
+///   plain `init`); when `false`, only `refund_by_depositor` can
+/// * `depositor_allowlist_enabled` - Only depositors added with
+///   `allow_depositor` may lock funds
+/// * `monitoring_enabled` - Emit the `metric` operation and performance
+///   events (as with plain `init`). When `false` they are skipped to save
+///   fees; the counters behind `get_analytics` and `get_performance_stats`
+///   still update, and functional events are unaffected
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitOptions {
+    pub permissionless_refund: bool,
+    pub depositor_allowlist_enabled: bool,
+    pub monitoring_enabled: bool,
+}
+
+/// Dispute lifecycle of a bounty.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeState {
+    /// Bounty was locked without a dispute resolver
+    NoResolver,
+    /// A resolver is linked but no dispute has been raised
+    Open,
+    /// `raise_dispute` applied the given verdict
+    Resolved(ResolutionOutcome),
 }
 
 /// Storage keys for contract data.
@@ -590,6 +1032,24 @@ pub struct ReleaseFundsItem {
     pub contributor: Address,
 }
 
+/// What happened to one entry of a lenient batch call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BatchOutcome {
+    Ok,
+    /// Left untouched; carries the `Error` code a single-item call would
+    /// have returned.
+    Skipped(u32),
+}
+
+/// Per-item result of a batch call, in input order.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchResult {
+    pub id: u64,
+    pub outcome: BatchOutcome,
+}
+
 // Maximum batch size to prevent gas limit issues
 const MAX_BATCH_SIZE: u32 = 100;
 
@@ -602,20 +1062,191 @@ pub struct FeeConfig {
     pub fee_enabled: bool,   // Global fee enable/disable flag
 }
 
+/// Bounds on the amount a single escrow may lock.
+///
+/// * `min_amount` - Smallest accepted lock (0 = only the positivity check)
+/// * `max_amount` - Largest accepted lock (0 = no cap)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AmountLimits {
+    pub min_amount: i128,
+    pub max_amount: i128,
+}
+
+/// Gates a contributor must pass before `release_funds` pays them.
+///
+/// * `require_registration` - Contributor must have called `register_contributor`
+/// * `require_approval` - A release approval naming the contributor must exist
+/// * `terms_version` - Terms version to accept via `accept_terms` (0 = no terms)
+///
+/// The denylist is always enforced.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EligibilityConfig {
+    pub require_registration: bool,
+    pub require_approval: bool,
+    pub terms_version: u32,
+}
+
+/// Contributor assigned to work on a bounty.
+///
+/// * `strict` - Releases to anyone other than `contributor` are rejected
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributorAssignment {
+    pub contributor: Address,
+    pub strict: bool,
+    pub assigned_at: u64,
+}
+
+/// Lifetime payouts received by a contributor.
+///
+/// * `payout_count` - Bounties fully paid out to the contributor
+/// * `total_received` - Net amount received, in the configured token only
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ContributorPayoutStats {
+    pub payout_count: u64,
+    pub total_received: i128,
+}
+
+/// Penalty withheld from refunds, accounted separately from service fees.
+///
+/// * `penalty_rate` - Basis points of each refund withheld (0 = disabled)
+/// * `penalty_recipient` - Collector for withheld penalties
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PenaltyConfig {
+    pub penalty_rate: i128,
+    pub penalty_recipient: Address,
+}
+
 // Fee rate is stored in basis points (1 basis point = 0.01%)
 // Example: 100 basis points = 1%, 1000 basis points = 10%
 const BASIS_POINTS: i128 = 10_000;
 const MAX_FEE_RATE: i128 = 1_000; // Maximum 10% fee
 
+/// Default lock horizon: deadlines may be at most one year out.
+const DEFAULT_MAX_DEADLINE_SECONDS: u64 = 365 * 24 * 60 * 60;
+
+/// Version number written at initialization. Bump alongside code changes
+/// that ship through `upgrade`.
+const VERSION: u32 = 1;
+
+/// Layout version of persisted records (`Escrow` and friends). Bump it when
+/// a stored type changes shape and add the matching step to `migrate`.
+///
+/// * 1 - Original layout
+/// * 2 - `Escrow` gains `created_at` and `token`, `RefundRecord` gains
+///   `reason`; running counters are maintained
+const SCHEMA_VERSION: u32 = 2;
+
+/// `Asset::Native` serialized as XDR (a bare zero discriminant). Hashing it
+/// yields the address of the native XLM Stellar Asset Contract.
+const NATIVE_ASSET_XDR: [u8; 4] = [0, 0, 0, 0];
+
+// Feature symbols understood by `supports`
+pub const FEATURE_FEES: Symbol = symbol_short!("fees");
+pub const FEATURE_PENALTIES: Symbol = symbol_short!("penalty");
+pub const FEATURE_REFUND_SWAP: Symbol = symbol_short!("ref_swap");
+pub const FEATURE_AMOUNT_LIMITS: Symbol = symbol_short!("limits");
+pub const FEATURE_ELIGIBILITY: Symbol = symbol_short!("elig");
+pub const FEATURE_DISPUTES: Symbol = symbol_short!("dispute");
+pub const FEATURE_ORACLES: Symbol = symbol_short!("oracle");
+
 #[contracttype]
 pub enum DataKey {
     Admin,
+    Admins, // Vec<Address> of release-authorized admins, including `Admin`
     Token,
-    Escrow(u64),         // bounty_id
-    FeeConfig,           // Fee configuration
-    RefundApproval(u64), // bounty_id -> RefundApproval
-    ReentrancyGuard,
-    IsPaused, // Contract pause state
+    Escrow(u64),                    // bounty_id
+    FeeConfig,                      // Fee configuration
+    RefundApproval(u64),            // bounty_id -> RefundApproval
+    ReentrancyGuard,                // Temporary storage; expires on its own if ever left set
+    IsPaused,                       // Contract pause state
+    DisputeResolver(u64),           // bounty_id -> resolver contract address
+    ReleaseApproval(u64),           // bounty_id -> ReleaseApproval
+    PendingReleases,                // Sum of approved-but-unreleased amounts
+    Version,                        // Running code version (instance storage)
+    Amm,                            // AMM used for refund token swaps
+    RefundSwap(u64),                // bounty_id -> RefundSwapConfig
+    DisputeOutcome(u64),            // bounty_id -> ResolutionOutcome applied by raise_dispute
+    TotalLocked,                    // Sum of remaining amounts across unsettled escrows
+    Milestones(u64),                // bounty_id -> MilestonePlan
+    AmountLimits,                   // Per-escrow min/max lock amounts
+    MaxDeadlineSeconds,             // Furthest allowed deadline, relative to lock time
+    EligibilityConfig,              // Which contributor gates are enforced on release
+    ContributorRegistered(Address), // contributor -> bool
+    ContributorDenied(Address),     // contributor -> bool
+    TermsAccepted(Address),         // contributor -> accepted terms version
+    DepositorBounties(Address),     // depositor -> Vec<u64> of bounty ids, in lock order
+    PenaltyConfig,                  // Refund penalty rate and collector
+    TotalPenalties,                 // Lifetime refund penalties collected
+    TotalFees,                      // Lifetime lock/release fees collected
+    AssignedContributor(u64),       // bounty_id -> ContributorAssignment
+    MaxTvl,                         // Cap on TotalLocked (0 = uncapped)
+    MaxActivePerDepositor,          // Cap on unsettled bounties per depositor (0 = uncapped)
+    ActiveBounties(Address),        // depositor -> count of unsettled bounties
+    ReviewWindow,                   // Seconds after assignment before auto-release (0 = off)
+    AutoReleaseVetoed(u64),         // bounty_id -> bool
+    BountyIndex,                    // Vec<u64> of every bounty id, in lock order
+    EscrowStr(String),              // string bounty_id -> Escrow, separate from Escrow(u64)
+    TokenDecimals,                  // Token decimals, read from the token at init
+    TokenLocked(Address),           // token -> TotalLocked counterpart for non-default tokens
+    AllowedToken(Address),          // token -> bool, accepted by lock_funds_token
+    TotalReleased,                  // Lifetime amount released in the configured token
+    TotalRefunded,                  // Lifetime amount refunded in the configured token
+    ActiveCount,                    // Unsettled escrows across all depositors
+    RefundGraceSeconds,             // Delay after the deadline before refunds unlock
+    KeeperFeeBps,                   // Keeper fee rate offered to new opt-in locks
+    KeeperFee(u64),                 // bounty_id -> keeper fee rate fixed at lock time
+    PermissionlessRefund,           // false if only depositors may trigger deadline refunds
+    SchemaVersion,                  // Layout version of persisted records
+    EscrowCount,                    // Escrows ever created; never decremented
+    DepositorAllowlistEnabled,      // true if only allowlisted depositors may lock
+    AllowedDepositor(Address),      // depositor -> may lock while the allowlist is enabled
+    ContributorPayouts(Address),    // contributor -> ContributorPayoutStats
+}
+
+/// An escrow's ID in either namespace; selects its storage key and the
+/// events emitted for it.
+enum EscrowId {
+    Num(u64),
+    Str(String),
+}
+
+impl EscrowId {
+    fn key(&self) -> DataKey {
+        match self {
+            EscrowId::Num(bounty_id) => DataKey::Escrow(*bounty_id),
+            EscrowId::Str(bounty_id) => DataKey::EscrowStr(bounty_id.clone()),
+        }
+    }
+}
+
+/// Storage keys for the release multisig, kept apart from `DataKey`, which
+/// is at the variant limit for a contract type. Variant names double as the
+/// stored key, so they must not collide with `DataKey` or `AntiAbuseKey`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MultisigKey {
+    ReleaseMultisig,      // ReleaseMultisig signer set and threshold
+    ReleaseProposal(u64), // bounty_id -> ReleaseProposal
+}
+
+/// Instance-storage settings added after `DataKey` reached the variant
+/// limit. Same naming rule as `MultisigKey`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConfigKey {
+    MinLockDuration, // Shortest allowed deadline, relative to lock time (0 = off)
+}
+
+/// Persistent keys for pull-style releases. Same naming rule as `MultisigKey`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ClaimKey {
+    PendingClaim(u64), // bounty_id -> PendingClaim
 }
 
 // ============================================================================
@@ -645,6 +1276,7 @@ impl BountyEscrowContract {
     /// # State Changes
     /// - Sets Admin address in instance storage
     /// - Sets Token address in instance storage
+    /// - Caches the token's `decimals` in instance storage
     /// - Emits BountyEscrowInitialized event
     ///
     /// # Security Considerations
@@ -666,14 +1298,35 @@ impl BountyEscrowContract {
     /// # Gas Cost
     /// Low - Only two storage writes
     pub fn init(env: Env, admin: Address, token: Address) -> Result<(), Error> {
+        Self::initialize(
+            env,
+            admin,
+            token,
+            InitOptions {
+                permissionless_refund: true,
+                depositor_allowlist_enabled: false,
+                monitoring_enabled: true,
+            },
+        )
+    }
+
+    /// Shared body of `init` and `init_with_options`. Options are stored
+    /// before anything is emitted, so `init` itself honours
+    /// `monitoring_enabled`.
+    fn initialize(
+        env: Env,
+        admin: Address,
+        token: Address,
+        options: InitOptions,
+    ) -> Result<(), Error> {
         // Apply rate limiting
         anti_abuse::check_rate_limit(&env, admin.clone());
 
         let start = env.ledger().timestamp();
         let caller = admin.clone();
 
-        // Prevent re-initialization
-        if env.storage().instance().has(&DataKey::Admin) {
+        // Prevent re-initialization, including after the admin renounced
+        if env.storage().instance().has(&DataKey::Token) {
             monitoring::track_operation(&env, symbol_short!("init"), caller, false);
             return Err(Error::AlreadyInitialized);
         }
@@ -681,6 +1334,25 @@ impl BountyEscrowContract {
         // Store configuration
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::Token, &token);
+        env.storage().instance().set(&DataKey::Version, &VERSION);
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+        env.storage().instance().set(
+            &DataKey::PermissionlessRefund,
+            &options.permissionless_refund,
+        );
+        env.storage().instance().set(
+            &DataKey::DepositorAllowlistEnabled,
+            &options.depositor_allowlist_enabled,
+        );
+        monitoring::set_events_enabled(&env, options.monitoring_enabled);
+        // Left unset if the token doesn't answer; `get_token_decimals` retries
+        if let Ok(Ok(decimals)) = token::Client::new(&env, &token).try_decimals() {
+            env.storage()
+                .instance()
+                .set(&DataKey::TokenDecimals, &decimals);
+        }
 
         // Initialize fee config with zero fees (disabled by default)
         let fee_config = FeeConfig {
@@ -693,6 +1365,22 @@ impl BountyEscrowContract {
             .instance()
             .set(&DataKey::FeeConfig, &fee_config);
 
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxDeadlineSeconds, &DEFAULT_MAX_DEADLINE_SECONDS);
+
+        // No TVL cap by default
+        env.storage().instance().set(&DataKey::MaxTvl, &0i128);
+
+        // No amount limits by default
+        env.storage().instance().set(
+            &DataKey::AmountLimits,
+            &AmountLimits {
+                min_amount: 0,
+                max_amount: 0,
+            },
+        );
+
         // Emit initialization event
         emit_bounty_initialized(
             &env,
@@ -713,318 +1401,703 @@ impl BountyEscrowContract {
         Ok(())
     }
 
-    /// Calculate fee amount based on rate (in basis points)
-    fn calculate_fee(amount: i128, fee_rate: i128) -> i128 {
-        if fee_rate == 0 {
-            return 0;
-        }
-        // Fee = (amount * fee_rate) / BASIS_POINTS
-        // Using checked arithmetic to prevent overflow
-        amount
-            .checked_mul(fee_rate)
-            .and_then(|x| x.checked_div(BASIS_POINTS))
-            .unwrap_or(0)
-    }
-
-    /// Get fee configuration (internal helper)
-    fn get_fee_config_internal(env: &Env) -> FeeConfig {
-        env.storage()
-            .instance()
-            .get(&DataKey::FeeConfig)
-            .unwrap_or_else(|| FeeConfig {
-                lock_fee_rate: 0,
-                release_fee_rate: 0,
-                fee_recipient: env.storage().instance().get(&DataKey::Admin).unwrap(),
-                fee_enabled: false,
-            })
+    /// Initializes the contract with native XLM as the escrow token.
+    ///
+    /// Resolves the wrapped XLM Stellar Asset Contract address on-chain, so
+    /// callers do not need to know its ID. All amounts are in stroops
+    /// (1 XLM = 10_000_000 stroops).
+    ///
+    /// # Returns
+    /// * `Ok(())` - Contract successfully initialized
+    /// * `Err(Error::AlreadyInitialized)` - Contract already initialized
+    pub fn init_native(env: Env, admin: Address) -> Result<(), Error> {
+        let native_token = env
+            .deployer()
+            .with_stellar_asset(Bytes::from_array(&env, &NATIVE_ASSET_XDR))
+            .deployed_address();
+        Self::init(env, admin, native_token)
     }
 
-    /// Update fee configuration (admin only)
-    pub fn update_fee_config(
-        env: Env,
-        lock_fee_rate: Option<i128>,
-        release_fee_rate: Option<i128>,
-        fee_recipient: Option<Address>,
-        fee_enabled: Option<bool>,
+    /// Initializes the contract and chooses who may trigger deadline refunds.
+    ///
+    /// With `permissionless_refund = true` this is the same as `init`:
+    /// anyone can call `refund` once a bounty's deadline has passed. With
+    /// `false`, `refund` (except admin-approved early `Custom` refunds),
+    /// `refund_str` and `refund_as_keeper` are disabled and depositors
+    /// reclaim expired bounties through `refund_by_depositor`. That stops a
+    /// keeper from refunding a bounty the admin is about to release.
+    ///
+    /// The mode is fixed for the life of the contract.
+    ///
+    /// # Returns
+    /// * `Ok(())` - Contract successfully initialized
+    /// * `Err(Error::AlreadyInitialized)` - Contract already initialized
+    pub fn init_with_refund_mode(
+        env: Env,
+        admin: Address,
+        token: Address,
+        permissionless_refund: bool,
     ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
-
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+        Self::init_with_options(
+            env,
+            admin,
+            token,
+            InitOptions {
+                permissionless_refund,
+                depositor_allowlist_enabled: false,
+                monitoring_enabled: true,
+            },
+        )
+    }
 
-        let mut fee_config = Self::get_fee_config_internal(&env);
+    /// Initializes the contract with the optional behaviour in `options`.
+    ///
+    /// `init` is equivalent to `permissionless_refund = true`,
+    /// `depositor_allowlist_enabled = false` and `monitoring_enabled = true`.
+    /// See [`InitOptions`], `init_with_refund_mode` and `allow_depositor`.
+    /// All options are fixed for the life of the contract.
+    ///
+    /// # Returns
+    /// * `Ok(())` - Contract successfully initialized
+    /// * `Err(Error::AlreadyInitialized)` - Contract already initialized
+    pub fn init_with_options(
+        env: Env,
+        admin: Address,
+        token: Address,
+        options: InitOptions,
+    ) -> Result<(), Error> {
+        Self::initialize(env, admin, token, options)
+    }
 
-        if let Some(rate) = lock_fee_rate {
-            if !(0..=MAX_FEE_RATE).contains(&rate) {
-                return Err(Error::InvalidFeeRate);
-            }
-            fee_config.lock_fee_rate = rate;
-        }
+    /// Returns whether anyone may trigger deadline refunds (the default),
+    /// as opposed to depositors only via `refund_by_depositor`.
+    pub fn is_permissionless_refund(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::PermissionlessRefund)
+            .unwrap_or(true)
+    }
 
-        if let Some(rate) = release_fee_rate {
-            if !(0..=MAX_FEE_RATE).contains(&rate) {
-                return Err(Error::InvalidFeeRate);
-            }
-            fee_config.release_fee_rate = rate;
+    /// Returns the number of decimals of the escrow token.
+    ///
+    /// All amounts this contract accepts and reports are in the token's
+    /// smallest unit; a token with 7 decimals (every Stellar Asset Contract,
+    /// including XLM and USDC) needs `10_000_000` units for one whole token.
+    /// The value is cached at `init`; if it was not (the contract predates
+    /// the cache, or the token was not yet reachable) the token is queried.
+    ///
+    /// # Errors
+    /// * `NotInitialized` - `init` has not been called
+    pub fn get_token_decimals(env: Env) -> Result<u32, Error> {
+        if let Some(decimals) = env.storage().instance().get(&DataKey::TokenDecimals) {
+            return Ok(decimals);
         }
+        let token_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .ok_or(Error::NotInitialized)?;
+        Ok(token::Client::new(&env, &token_addr).decimals())
+    }
 
-        if let Some(recipient) = fee_recipient {
-            fee_config.fee_recipient = recipient;
+    /// Converts a whole-token amount to the token's smallest unit.
+    ///
+    /// `to_smallest_unit(25)` on a 7-decimal token returns `250_000_000`,
+    /// which is the value to pass as `amount` to `lock_funds` for 25 tokens.
+    /// Fractional amounts must be converted off-chain.
+    ///
+    /// # Errors
+    /// * `NotInitialized` - `init` has not been called
+    /// * `InvalidAmount` - `whole_amount` is negative or the result overflows
+    pub fn to_smallest_unit(env: Env, whole_amount: i128) -> Result<i128, Error> {
+        if whole_amount < 0 {
+            return Err(Error::InvalidAmount);
         }
+        let decimals = Self::get_token_decimals(env)?;
+        10i128
+            .checked_pow(decimals)
+            .and_then(|scale| whole_amount.checked_mul(scale))
+            .ok_or(Error::InvalidAmount)
+    }
 
-        if let Some(enabled) = fee_enabled {
-            fee_config.fee_enabled = enabled;
+    /// Calculate fee amount based on rate (in basis points)
+    fn calculate_fee(amount: i128, fee_rate: i128) -> i128 {
+        if fee_rate == 0 {
+            return 0;
         }
+        // Fee = (amount * fee_rate) / BASIS_POINTS
+        // Using checked arithmetic to prevent overflow
+        amount
+            .checked_mul(fee_rate)
+            .and_then(|x| x.checked_div(BASIS_POINTS))
+            .unwrap_or(0)
+    }
 
+    /// Get fee configuration (internal helper)
+    fn get_fee_config_internal(env: &Env) -> FeeConfig {
         env.storage()
             .instance()
-            .set(&DataKey::FeeConfig, &fee_config);
+            .get(&DataKey::FeeConfig)
+            .unwrap_or_else(|| FeeConfig {
+                lock_fee_rate: 0,
+                release_fee_rate: 0,
+                fee_recipient: env.storage().instance().get(&DataKey::Admin).unwrap(),
+                fee_enabled: false,
+            })
+    }
 
-        events::emit_fee_config_updated(
-            &env,
-            events::FeeConfigUpdated {
-                lock_fee_rate: fee_config.lock_fee_rate,
-                release_fee_rate: fee_config.release_fee_rate,
-                fee_recipient: fee_config.fee_recipient.clone(),
-                fee_enabled: fee_config.fee_enabled,
-                timestamp: env.ledger().timestamp(),
-            },
-        );
+    /// Get penalty config (internal helper)
+    fn get_penalty_config_internal(env: &Env) -> PenaltyConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::PenaltyConfig)
+            .unwrap_or_else(|| PenaltyConfig {
+                penalty_rate: 0,
+                // Unused at a zero rate; the admin may have been renounced
+                penalty_recipient: env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::Admin)
+                    .unwrap_or_else(|| env.current_contract_address()),
+            })
+    }
 
-        Ok(())
+    /// Adds `amount` to an i128 accounting counter in instance storage.
+    fn add_to_counter(env: &Env, key: DataKey, amount: i128) {
+        let total: i128 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage().instance().set(&key, &(total + amount));
     }
 
-    /// Get current fee configuration (view function)
-    pub fn get_fee_config(env: Env) -> FeeConfig {
-        Self::get_fee_config_internal(&env)
+    /// Adds to a released/refunded total if the escrow is in the configured
+    /// token; totals in other tokens would mix units.
+    fn add_to_settled(env: &Env, token: &Address, key: DataKey, amount: i128) {
+        if *token == Self::default_token(env) {
+            Self::add_to_counter(env, key, amount);
+        }
     }
 
-    // ========================================================================
-    // Pause and Emergency Functions
-    // ========================================================================
+    fn get_contributor_stats(env: &Env, contributor: &Address) -> ContributorPayoutStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ContributorPayouts(contributor.clone()))
+            .unwrap_or_default()
+    }
 
-    /// Check if contract is paused (internal helper)
-    fn is_paused_internal(env: &Env) -> bool {
+    /// Books `amount` paid to `contributor` from an escrow in `token`.
+    /// `settled` is set once the bounty has been paid out in full, so
+    /// milestone payments count as a single bounty.
+    fn record_contributor_payout(
+        env: &Env,
+        token: &Address,
+        contributor: &Address,
+        amount: i128,
+        settled: bool,
+    ) {
+        let mut stats = Self::get_contributor_stats(env, contributor);
+        if *token == Self::default_token(env) {
+            stats.total_received = stats.total_received.saturating_add(amount);
+        }
+        if settled {
+            stats.payout_count += 1;
+        }
         env.storage()
             .persistent()
-            .get::<_, bool>(&DataKey::IsPaused)
-            .unwrap_or(false)
+            .set(&DataKey::ContributorPayouts(contributor.clone()), &stats);
     }
 
-    /// Get pause status (view function)
-    pub fn is_paused(env: Env) -> bool {
-        Self::is_paused_internal(&env)
+    /// Get amount limits (internal helper)
+    fn get_amount_limits_internal(env: &Env) -> AmountLimits {
+        env.storage()
+            .instance()
+            .get(&DataKey::AmountLimits)
+            .unwrap_or(AmountLimits {
+                min_amount: 0,
+                max_amount: 0,
+            })
     }
 
-    /// Pause the contract (admin only)
-    /// Prevents new fund locks, releases, and refunds
-    pub fn pause(env: Env) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+    /// Rejects lock amounts outside the configured `AmountLimits`.
+    fn check_amount_limits(env: &Env, amount: i128) -> Result<(), Error> {
+        let limits = Self::get_amount_limits_internal(env);
+        if amount < limits.min_amount {
+            return Err(Error::AmountTooSmall);
         }
-
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-
-        if Self::is_paused_internal(&env) {
-            return Ok(()); // Already paused, idempotent
+        if limits.max_amount > 0 && amount > limits.max_amount {
+            return Err(Error::AmountTooLarge);
         }
+        Ok(())
+    }
 
-        env.storage().persistent().set(&DataKey::IsPaused, &true);
+    /// Rejects a lock of `amount` that would take `TotalLocked` past `MaxTvl`.
+    fn check_tvl_cap(env: &Env, amount: i128) -> Result<(), Error> {
+        let max_tvl: i128 = env.storage().instance().get(&DataKey::MaxTvl).unwrap_or(0);
+        if max_tvl == 0 {
+            return Ok(());
+        }
+        let total: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalLocked)
+            .unwrap_or(0);
+        match total.checked_add(amount) {
+            Some(new_total) if new_total <= max_tvl => Ok(()),
+            _ => Err(Error::TvlCapExceeded),
+        }
+    }
 
-        emit_contract_paused(
-            &env,
-            ContractPaused {
-                paused_by: admin.clone(),
-                timestamp: env.ledger().timestamp(),
-            },
-        );
+    /// Latest deadline a lock created now may use.
+    fn deadline_horizon(env: &Env) -> u64 {
+        let max_seconds: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MaxDeadlineSeconds)
+            .unwrap_or(DEFAULT_MAX_DEADLINE_SECONDS);
+        env.ledger().timestamp().saturating_add(max_seconds)
+    }
 
+    /// Rejects deadlines sooner than the configured minimum lock duration.
+    fn check_min_lock_duration(env: &Env, deadline: u64) -> Result<(), Error> {
+        let min_seconds = Self::get_min_lock_duration(env.clone());
+        if min_seconds > 0 && deadline < env.ledger().timestamp().saturating_add(min_seconds) {
+            return Err(Error::DeadlineTooSoon);
+        }
         Ok(())
     }
 
-    /// Unpause the contract (admin only)
-    /// Resumes normal operations
-    pub fn unpause(env: Env) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
+    /// Get eligibility config (internal helper)
+    fn get_eligibility_config_internal(env: &Env) -> EligibilityConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::EligibilityConfig)
+            .unwrap_or(EligibilityConfig {
+                require_registration: false,
+                require_approval: false,
+                terms_version: 0,
+            })
+    }
 
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+    /// Runs every payout gate for `contributor` on `bounty_id`, returning the
+    /// first blocking error.
+    fn check_contributor_eligibility(
+        env: &Env,
+        bounty_id: u64,
+        contributor: &Address,
+    ) -> Result<(), Error> {
+        Self::check_contributor_standing(env, contributor)?;
 
-        if !Self::is_paused_internal(&env) {
-            return Ok(()); // Already unpaused, idempotent
+        let assignment: Option<ContributorAssignment> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AssignedContributor(bounty_id));
+        if let Some(assignment) = assignment {
+            if assignment.strict && assignment.contributor != *contributor {
+                return Err(Error::ContributorMismatch);
+            }
         }
 
-        env.storage().persistent().set(&DataKey::IsPaused, &false);
-
-        emit_contract_unpaused(
-            &env,
-            ContractUnpaused {
-                unpaused_by: admin.clone(),
-                timestamp: env.ledger().timestamp(),
-            },
-        );
+        if Self::get_eligibility_config_internal(env).require_approval {
+            let approval: Option<ReleaseApproval> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ReleaseApproval(bounty_id));
+            match approval {
+                Some(approval) if approval.contributor == *contributor => {}
+                _ => return Err(Error::ReleaseNotApproved),
+            }
+        }
 
         Ok(())
     }
 
-    /// Emergency withdrawal for all contract funds (admin only, only when paused)
-    /// This function allows admins to recover all contract funds in case of critical
-    /// security issues or unrecoverable bugs. It can only be called when the contract
-    /// is paused to prevent misuse.
-    pub fn emergency_withdraw(env: Env, recipient: Address) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+    /// Rejects single-key releases of escrows large enough to need multisig.
+    fn check_multisig_not_required(env: &Env, escrow: &Escrow) -> Result<(), Error> {
+        let config: Option<ReleaseMultisig> =
+            env.storage().instance().get(&MultisigKey::ReleaseMultisig);
+        match config {
+            Some(config) if escrow.remaining_amount >= config.min_amount => {
+                Err(Error::MultisigRequired)
+            }
+            _ => Ok(()),
         }
+    }
 
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-
-        // Only allow emergency withdrawal when contract is paused
-        if !Self::is_paused_internal(&env) {
-            return Err(Error::Unauthorized);
+    /// Rejects the escrow contract's own address as a payout recipient:
+    /// tokens sent there would sit unaccounted with the escrow settled.
+    fn check_recipient(env: &Env, recipient: &Address) -> Result<(), Error> {
+        if *recipient == env.current_contract_address() {
+            return Err(Error::InvalidRecipient);
         }
+        Ok(())
+    }
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
+    /// Contributor-level gates that don't depend on the bounty: the
+    /// contract's own address, denylist, registration and accepted terms.
+    fn check_contributor_standing(env: &Env, contributor: &Address) -> Result<(), Error> {
+        Self::check_recipient(env, contributor)?;
+        let config = Self::get_eligibility_config_internal(env);
 
-        // Get contract balance
-        let balance = client.balance(&env.current_contract_address());
+        if env
+            .storage()
+            .persistent()
+            .get(&DataKey::ContributorDenied(contributor.clone()))
+            .unwrap_or(false)
+        {
+            return Err(Error::ContributorDenied);
+        }
 
-        if balance <= 0 {
-            return Ok(()); // No funds to withdraw
+        if config.require_registration
+            && !env
+                .storage()
+                .persistent()
+                .get(&DataKey::ContributorRegistered(contributor.clone()))
+                .unwrap_or(false)
+        {
+            return Err(Error::ContributorNotRegistered);
         }
 
-        // Transfer all funds to recipient
-        client.transfer(&env.current_contract_address(), &recipient, &balance);
+        if config.terms_version > 0 {
+            let accepted: u32 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::TermsAccepted(contributor.clone()))
+                .unwrap_or(0);
+            if accepted != config.terms_version {
+                return Err(Error::TermsNotAccepted);
+            }
+        }
 
-        emit_emergency_withdrawal(
-            &env,
-            EmergencyWithdrawal {
-                withdrawn_by: admin.clone(),
-                amount: balance,
-                recipient: recipient.clone(),
+        Ok(())
+    }
+
+    /// Pays out a locked escrow to `contributor`, collecting the release fee
+    /// if enabled, and marks it as released. Returns the net amount paid.
+    fn release_escrow(
+        env: &Env,
+        bounty_id: u64,
+        escrow: &mut Escrow,
+        contributor: &Address,
+    ) -> i128 {
+        Self::clear_release_approval(env, bounty_id);
+
+        // Settle and store the escrow before any token call; a failed
+        // transfer reverts the whole invocation, leaving it `Locked`.
+        let (net_amount, fee_amount) = Self::settle_release(env, escrow);
+        Self::record_contributor_payout(env, &escrow.token, contributor, net_amount, true);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), escrow);
+
+        // Emit release event
+        emit_funds_released(
+            env,
+            FundsReleased {
+                bounty_id,
+                amount: net_amount, // Emit net amount (after fee)
+                recipient: contributor.clone(),
                 timestamp: env.ledger().timestamp(),
             },
         );
 
-        Ok(())
+        Self::transfer_release(env, &escrow.token, contributor, net_amount, fee_amount);
+
+        net_amount
     }
 
-    /// Lock funds for a specific bounty.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `depositor` - Address depositing the funds (must authorize)
-    /// * `bounty_id` - Unique identifier for this bounty
-    /// * `amount` - Token amount to lock (in smallest denomination)
-    /// * `deadline` - Unix timestamp after which refund is allowed
-    ///
-    /// # Returns
-    /// * `Ok(())` - Funds successfully locked
-    /// * `Err(Error::NotInitialized)` - Contract not initialized
-    /// * `Err(Error::BountyExists)` - Bounty ID already in use
-    ///
-    /// # State Changes
-    /// - Transfers `amount` tokens from depositor to contract
-    /// - Creates Escrow record in persistent storage
-    /// - Emits FundsLocked event
-    ///
-    /// # Authorization
-    /// - Depositor must authorize the transaction
-    /// - Depositor must have sufficient token balance
-    /// - Depositor must have approved contract for token transfer
-    ///
-    /// # Security Considerations
-    /// - Bounty ID must be unique (prevents overwrites)
-    /// - Amount must be positive (enforced by token contract)
-    /// - Deadline should be reasonable (recommended: 7-90 days)
-    /// - Token transfer is atomic with state update
-    ///
-    /// # Events
-    /// Emits: `FundsLocked { bounty_id, amount, depositor, deadline }`
-    ///
-    /// # Example
-    /// ```rust
-    /// let depositor = Address::from_string("GDEPOSIT...");
-    /// let amount = 1000_0000000; // 1000 USDC
-    /// let deadline = env.ledger().timestamp() + (30 * 24 * 60 * 60); // 30 days
-    ///
-    /// escrow_client.lock_funds(&depositor, &42, &amount, &deadline)?;
-    /// // Funds are now locked and can be released or refunded
-    /// ```
-    ///
-    /// # Gas Cost
-    /// Medium - Token transfer + storage write + event emission
+    /// Transfers an escrow's remaining balance to `contributor` less the
+    /// release fee, and marks it released. The caller persists the escrow.
+    fn pay_out_escrow(env: &Env, escrow: &mut Escrow, contributor: &Address) -> i128 {
+        let (net_amount, fee_amount) = Self::settle_release(env, escrow);
+        Self::record_contributor_payout(env, &escrow.token, contributor, net_amount, true);
+        Self::transfer_release(env, &escrow.token, contributor, net_amount, fee_amount);
+        net_amount
+    }
+
+    /// Sends a settled release: `net_amount` to `contributor` and the fee,
+    /// if any, to the fee recipient.
+    fn transfer_release(
+        env: &Env,
+        token_addr: &Address,
+        contributor: &Address,
+        net_amount: i128,
+        fee_amount: i128,
+    ) {
+        let client = token::Client::new(env, token_addr);
+        client.transfer(&env.current_contract_address(), contributor, &net_amount);
+        if fee_amount > 0 {
+            let fee_config = Self::get_fee_config_internal(env);
+            client.transfer(
+                &env.current_contract_address(),
+                &fee_config.fee_recipient,
+                &fee_amount,
+            );
+        }
+    }
+
+    /// Marks an escrow released and books the release fee and totals,
+    /// without moving tokens. Returns `(net_amount, fee_amount)`.
+    fn settle_release(env: &Env, escrow: &mut Escrow) -> (i128, i128) {
+        // Calculate and book fee if enabled
+        let fee_config = Self::get_fee_config_internal(env);
+        let fee_amount = if fee_config.fee_enabled && fee_config.release_fee_rate > 0 {
+            Self::calculate_fee(escrow.remaining_amount, fee_config.release_fee_rate)
+        } else {
+            0
+        };
+        let net_amount = escrow.remaining_amount - fee_amount;
+
+        if fee_amount > 0 {
+            Self::add_to_counter(env, DataKey::TotalFees, fee_amount);
+            events::emit_fee_collected(
+                env,
+                events::FeeCollected {
+                    operation_type: events::FeeOperationType::Release,
+                    amount: fee_amount,
+                    fee_rate: fee_config.release_fee_rate,
+                    recipient: fee_config.fee_recipient.clone(),
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+
+        // Update escrow state - mark as released and set remaining_amount to 0
+        Self::add_to_settled(
+            env,
+            &escrow.token,
+            DataKey::TotalReleased,
+            escrow.remaining_amount,
+        );
+        Self::adjust_total_locked(env, &escrow.token, -escrow.remaining_amount);
+        Self::adjust_active_bounties(env, &escrow.depositor, false);
+        escrow.status = EscrowStatus::Released;
+        escrow.remaining_amount = 0;
+
+        (net_amount, fee_amount)
+    }
+
+    /// Transfers `amount` of the escrow's remaining balance to `recipient`,
+    /// records it in the refund history and updates the escrow status.
     ///
-    /// # Common Pitfalls
-    /// - Forgetting to approve token contract before calling
-    /// - Using a bounty ID that already exists
-    /// - Setting deadline in the past or too far in the future
-    pub fn lock_funds(
-        env: Env,
-        depositor: Address,
-        bounty_id: u64,
+    /// Approvals, refund swaps and the per-bounty events only exist for
+    /// numeric IDs; string-keyed escrows emit `FundsRefundedStr` instead.
+    fn refund_escrow(
+        env: &Env,
+        id: &EscrowId,
+        escrow: &mut Escrow,
         amount: i128,
-        deadline: u64,
+        recipient: &Address,
+        mode: RefundMode,
+        reason: RefundReason,
     ) -> Result<(), Error> {
-        // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, depositor.clone());
+        if let EscrowId::Num(bounty_id) = id {
+            Self::clear_release_approval(env, *bounty_id);
+        }
 
-        let start = env.ledger().timestamp();
-        let caller = depositor.clone();
+        // Withhold the refund penalty, if configured. A resolver ruling for
+        // the depositor, an admin-forced refund or an expired claim is never
+        // penalised.
+        let penalty_config = Self::get_penalty_config_internal(env);
+        let penalty = if reason != RefundReason::DisputeResolved
+            && reason != RefundReason::AdminForced
+            && reason != RefundReason::ClaimExpired
+        {
+            Self::calculate_fee(amount, penalty_config.penalty_rate)
+        } else {
+            0
+        };
+        let payout = amount - penalty;
 
-        // Check if contract is paused
-        if Self::is_paused_internal(&env) {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
-            return Err(Error::ContractPaused);
+        // Record and store the refund before any token call
+        Self::record_refund(env, escrow, amount, recipient, &mode, &reason);
+        env.storage().persistent().set(&id.key(), escrow);
+
+        if penalty > 0 {
+            Self::add_to_counter(env, DataKey::TotalPenalties, penalty);
         }
 
-        // Verify depositor authorization
-        depositor.require_auth();
+        // Emit refund event
+        match id {
+            EscrowId::Num(bounty_id) => {
+                if penalty > 0 {
+                    events::emit_penalty_collected(
+                        env,
+                        events::PenaltyCollected {
+                            bounty_id: *bounty_id,
+                            amount: penalty,
+                            penalty_rate: penalty_config.penalty_rate,
+                            recipient: penalty_config.penalty_recipient.clone(),
+                            timestamp: env.ledger().timestamp(),
+                        },
+                    );
+                }
+                emit_funds_refunded(
+                    env,
+                    FundsRefunded {
+                        bounty_id: *bounty_id,
+                        amount,
+                        refund_to: recipient.clone(),
+                        timestamp: env.ledger().timestamp(),
+                        refund_mode: mode,
+                        reason,
+                        remaining_amount: escrow.remaining_amount,
+                    },
+                );
+            }
+            EscrowId::Str(bounty_id) => events::emit_funds_refunded_str(
+                env,
+                events::FundsRefundedStr {
+                    bounty_id: bounty_id.clone(),
+                    amount,
+                    penalty,
+                    refund_to: recipient.clone(),
+                    timestamp: env.ledger().timestamp(),
+                    remaining_amount: escrow.remaining_amount,
+                },
+            ),
+        }
 
-        // Ensure contract is initialized
-        if env.storage().instance().has(&DataKey::ReentrancyGuard) {
-            panic!("Reentrancy detected");
+        // Token calls last
+        let client = token::Client::new(env, &escrow.token);
+        if penalty > 0 {
+            client.transfer(
+                &env.current_contract_address(),
+                &penalty_config.penalty_recipient,
+                &penalty,
+            );
         }
-        env.storage()
-            .instance()
-            .set(&DataKey::ReentrancyGuard, &true);
+        let swap_config: Option<(u64, RefundSwapConfig)> = match id {
+            EscrowId::Num(bounty_id) => env
+                .storage()
+                .persistent()
+                .get(&DataKey::RefundSwap(*bounty_id))
+                .map(|config| (*bounty_id, config)),
+            EscrowId::Str(_) => None,
+        };
+        match swap_config {
+            Some((bounty_id, config)) if *recipient == escrow.depositor => {
+                Self::swap_refund(env, bounty_id, escrow, &config, payout, recipient)
+            }
+            _ => {
+                client.transfer(&env.current_contract_address(), recipient, &payout);
+                Ok(())
+            }
+        }
+    }
+
+    /// Deducts a refund from the escrow, appends it to the refund history and
+    /// moves the escrow to `Refunded` or `PartiallyRefunded`.
+    fn record_refund(
+        env: &Env,
+        escrow: &mut Escrow,
+        amount: i128,
+        recipient: &Address,
+        mode: &RefundMode,
+        reason: &RefundReason,
+    ) {
+        escrow.remaining_amount -= amount;
+        Self::adjust_total_locked(env, &escrow.token, -amount);
+        Self::add_to_settled(env, &escrow.token, DataKey::TotalRefunded, amount);
+
+        escrow.refund_history.push_back(RefundRecord {
+            amount,
+            recipient: recipient.clone(),
+            mode: mode.clone(),
+            reason: reason.clone(),
+            timestamp: env.ledger().timestamp(),
+        });
+
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Refunded;
+            Self::adjust_active_bounties(env, &escrow.depositor, false);
+        } else {
+            escrow.status = EscrowStatus::PartiallyRefunded;
+        }
+    }
 
+    /// Shared lock validation for the numeric and string-keyed entrypoints.
+    fn validate_lock(env: &Env, amount: i128, deadline: u64) -> Result<(), Error> {
         if amount <= 0 {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
             return Err(Error::InvalidAmount);
         }
-
+        Self::check_amount_limits(env, amount)?;
         if deadline <= env.ledger().timestamp() {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
             return Err(Error::InvalidDeadline);
         }
-        if !env.storage().instance().has(&DataKey::Admin) {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+        Self::check_min_lock_duration(env, deadline)?;
+        if deadline > Self::deadline_horizon(env) {
+            return Err(Error::DeadlineTooFar);
+        }
+        // A deadline whose refund unlock time cannot be represented would
+        // leave the funds stuck until release
+        if deadline
+            .checked_add(Self::get_refund_grace(env.clone()))
+            .is_none()
+        {
+            return Err(Error::InvalidDeadline);
+        }
+        // Locks stay open after `renounce_admin`, so check the token
+        if !env.storage().instance().has(&DataKey::Token) {
             return Err(Error::NotInitialized);
         }
+        Ok(())
+    }
 
-        // Prevent duplicate bounty IDs
-        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::BountyExists);
+    /// Pulls a deposit of `amount` of `token` from `depositor`, collecting the
+    /// lock fee and enforcing the TVL cap. Returns what actually arrived,
+    /// which can be less than requested for tokens that charge a transfer fee.
+    fn collect_deposit(
+        env: &Env,
+        token: &Address,
+        depositor: &Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        let (net_amount, fee_amount) = Self::prepare_deposit(env, token, amount)?;
+        Self::transfer_deposit(env, token, depositor, net_amount, fee_amount, false)
+    }
+
+    /// Moves a deposit split by `prepare_deposit` into the contract and the
+    /// lock fee to its recipient. Returns what actually arrived.
+    ///
+    /// With `from_allowance` set, both legs are pulled with `transfer_from`
+    /// against the allowance the depositor granted this contract, so the
+    /// depositor's signature need not cover a nested token transfer.
+    fn transfer_deposit(
+        env: &Env,
+        token: &Address,
+        depositor: &Address,
+        net_amount: i128,
+        fee_amount: i128,
+        from_allowance: bool,
+    ) -> Result<i128, Error> {
+        let client = token::Client::new(env, token);
+        let contract_address = env.current_contract_address();
+        let pull = |to: &Address, amount: i128| {
+            if from_allowance {
+                client.transfer_from(&contract_address, depositor, to, &amount);
+            } else {
+                client.transfer(depositor, to, &amount);
+            }
+        };
+
+        let balance_before = client.balance(&contract_address);
+        pull(&contract_address, net_amount);
+        let received = client.balance(&contract_address) - balance_before;
+        if received <= 0 {
+            return Err(Error::InvalidAmount);
         }
 
-        // Get token contract and transfer funds
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
+        if fee_amount > 0 {
+            let fee_config = Self::get_fee_config_internal(env);
+            pull(&fee_config.fee_recipient, fee_amount);
+        }
+        Ok(received)
+    }
 
-        // Calculate and collect fee if enabled
-        let fee_config = Self::get_fee_config_internal(&env);
+    /// Splits a deposit of `amount` into what the escrow holds and the lock
+    /// fee, enforcing the TVL cap and booking the fee. Moves no tokens.
+    /// Returns `(net_amount, fee_amount)`.
+    fn prepare_deposit(env: &Env, token: &Address, amount: i128) -> Result<(i128, i128), Error> {
+        // Calculate and book fee if enabled
+        let fee_config = Self::get_fee_config_internal(env);
         let fee_amount = if fee_config.fee_enabled && fee_config.lock_fee_rate > 0 {
             Self::calculate_fee(amount, fee_config.lock_fee_rate)
         } else {
@@ -1032,14 +2105,14 @@ impl BountyEscrowContract {
         };
         let net_amount = amount - fee_amount;
 
-        // Transfer net amount from depositor to contract
-        client.transfer(&depositor, &env.current_contract_address(), &net_amount);
+        if *token == Self::default_token(env) {
+            Self::check_tvl_cap(env, net_amount)?;
+        }
 
-        // Transfer fee to fee recipient if applicable
         if fee_amount > 0 {
-            client.transfer(&depositor, &fee_config.fee_recipient, &fee_amount);
+            Self::add_to_counter(env, DataKey::TotalFees, fee_amount);
             events::emit_fee_collected(
-                &env,
+                env,
                 events::FeeCollected {
                     operation_type: events::FeeOperationType::Lock,
                     amount: fee_amount,
@@ -1050,431 +2123,3756 @@ impl BountyEscrowContract {
             );
         }
 
-        // Create escrow record
-        let escrow = Escrow {
-            depositor: depositor.clone(),
-            amount: net_amount, // Store net amount (after fee)
-            status: EscrowStatus::Locked,
-            deadline,
-            refund_history: vec![&env],
-            remaining_amount: amount,
+        Ok((net_amount, fee_amount))
+    }
+
+    /// Converts `amount` of the escrow token into the depositor's chosen
+    /// refund token through the configured AMM and delivers it to `recipient`.
+    fn swap_refund(
+        env: &Env,
+        bounty_id: u64,
+        escrow: &Escrow,
+        config: &RefundSwapConfig,
+        amount: i128,
+        recipient: &Address,
+    ) -> Result<(), Error> {
+        let client = token::Client::new(env, &escrow.token);
+        let contract_address = env.current_contract_address();
+        let amm: Option<Address> = env.storage().instance().get(&DataKey::Amm);
+        let Some(amm) = amm else {
+            client.transfer(&contract_address, recipient, &amount);
+            return Ok(());
         };
 
-        // Store in persistent storage with extended TTL
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+        // The depositor's floor covers the full locked amount
+        let min_out = config
+            .min_amount_out
+            .checked_mul(amount)
+            .and_then(|x| x.checked_div(escrow.amount))
+            .ok_or(Error::InvalidAmount)?;
+
+        // The AMM pulls the input itself, so a failed swap leaves it here
+        client.approve(&contract_address, &amm, &amount, &env.ledger().sequence());
+        let swapped = AmmClient::new(env, &amm).try_swap(
+            &contract_address,
+            &escrow.token,
+            &config.refund_token,
+            &amount,
+            &min_out,
+            recipient,
+        );
+        client.approve(&contract_address, &amm, &0, &env.ledger().sequence());
 
-        // Emit event for off-chain indexing
-        emit_funds_locked(
-            &env,
-            FundsLocked {
+        let amount_out = match swapped {
+            Ok(Ok(amount_out)) => amount_out,
+            _ => {
+                client.transfer(&contract_address, recipient, &amount);
+                return Ok(());
+            }
+        };
+        if amount_out < min_out {
+            return Err(Error::SlippageExceeded);
+        }
+
+        events::emit_refund_swapped(
+            env,
+            events::RefundSwapped {
                 bounty_id,
-                amount: net_amount, // Emit net amount (after fee)
-                depositor: depositor.clone(),
-                deadline,
+                token_in: escrow.token.clone(),
+                token_out: config.refund_token.clone(),
+                amount_in: amount,
+                amount_out,
+                recipient: recipient.clone(),
             },
         );
 
-        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+        Ok(())
+    }
 
-        // Track successful operation
-        monitoring::track_operation(&env, symbol_short!("lock"), caller, true);
+    /// Removes a pending release approval (if any) and deducts its amount
+    /// from the pending releases total. Any pending claim goes with it, as
+    /// every release or refund path settles the escrow through here.
+    fn clear_release_approval(env: &Env, bounty_id: u64) {
+        env.storage()
+            .persistent()
+            .remove(&ClaimKey::PendingClaim(bounty_id));
+        let key = DataKey::ReleaseApproval(bounty_id);
+        if let Some(approval) = env.storage().persistent().get::<_, ReleaseApproval>(&key) {
+            env.storage().persistent().remove(&key);
+            let pending: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey::PendingReleases)
+                .unwrap_or(0);
+            env.storage()
+                .instance()
+                .set(&DataKey::PendingReleases, &(pending - approval.amount));
+        }
+    }
 
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("lock"), duration);
+    /// Applies `delta` to the running total of funds held in `token` for
+    /// unsettled escrows.
+    fn adjust_total_locked(env: &Env, token: &Address, delta: i128) {
+        let key = Self::locked_key(env, token);
+        let total: i128 = env.storage().instance().get(&key).unwrap_or(0);
+        env.storage().instance().set(&key, &(total + delta));
+    }
 
-        Ok(())
+    /// `TotalLocked` for the configured token, `TokenLocked` for any other.
+    fn locked_key(env: &Env, token: &Address) -> DataKey {
+        if *token == Self::default_token(env) {
+            DataKey::TotalLocked
+        } else {
+            DataKey::TokenLocked(token.clone())
+        }
     }
 
-    /// Releases escrowed funds to a contributor.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `bounty_id` - The bounty to release funds for
-    /// * `contributor` - Address to receive the funds
-    ///
-    /// # Returns
-    /// * `Ok(())` - Funds successfully released
-    /// * `Err(Error::NotInitialized)` - Contract not initialized
-    /// * `Err(Error::Unauthorized)` - Caller is not the admin
-    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
-    /// * `Err(Error::FundsNotLocked)` - Funds not in LOCKED state
-    ///
-    /// # State Changes
-    /// - Transfers tokens from contract to contributor
-    /// - Updates escrow status to Released
-    /// - Emits FundsReleased event
-    ///
-    /// # Authorization
-    /// - **CRITICAL**: Only admin can call this function
-    /// - Admin address must match initialization value
-    ///
-    /// # Security Considerations
-    /// - This is the most security-critical function
-    /// - Admin should verify task completion off-chain before calling
-    /// - Once released, funds cannot be retrieved
-    /// - Recipient address should be verified carefully
-    /// - Consider implementing multi-sig for admin
-    ///
-    /// # Events
-    /// Emits: `FundsReleased { bounty_id, amount, recipient, timestamp }`
-    ///
-    /// # Example
-    /// ```rust
-    /// // After verifying task completion off-chain:
-    /// let contributor = Address::from_string("GCONTRIB...");
-    ///
-    /// // Admin calls release
-    /// escrow_client.release_funds(&42, &contributor)?;
-    /// // Funds transferred to contributor, escrow marked as Released
-    /// ```
-    ///
-    /// # Gas Cost
-    /// Medium - Token transfer + storage update + event emission
-    ///
-    /// # Best Practices
-    /// 1. Verify contributor identity off-chain
-    /// 2. Confirm task completion before release
-    /// 3. Log release decisions in backend system
-    /// 4. Monitor release events for anomalies
-    /// 5. Consider implementing release delays for high-value bounties
-    pub fn release_funds(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
-        let start = env.ledger().timestamp();
+    /// The token configured at `init` (or by `update_token`).
+    fn default_token(env: &Env) -> Address {
+        env.storage().instance().get(&DataKey::Token).unwrap()
+    }
 
-        // Ensure contract is initialized
-        if env.storage().instance().has(&DataKey::ReentrancyGuard) {
+    /// Takes the reentrancy guard around a call into `token`, unless it is
+    /// the configured token. `lock_funds`, `release_funds` and `refund`
+    /// store all their state before touching the token, so re-entry through
+    /// the trusted token sees a finished operation; other allowlisted tokens
+    /// keep the guard. Returns whether the guard was taken.
+    fn enter_token_guard(env: &Env, token: &Address) -> bool {
+        if *token == Self::default_token(env) {
+            return false;
+        }
+        if env.storage().temporary().has(&DataKey::ReentrancyGuard) {
             panic!("Reentrancy detected");
         }
         env.storage()
-            .instance()
+            .temporary()
             .set(&DataKey::ReentrancyGuard, &true);
-        if !env.storage().instance().has(&DataKey::Admin) {
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::NotInitialized);
-        }
+        true
+    }
+
+    /// Releases a guard taken by `enter_token_guard`.
+    fn exit_token_guard(env: &Env, taken: bool) {
+        if taken {
+            env.storage().temporary().remove(&DataKey::ReentrancyGuard);
+        }
+    }
+
+    /// Appends `bounty_id` to the global and depositor bounty indexes.
+    /// Entries are never removed, so the indexes double as history.
+    fn index_bounty(env: &Env, depositor: &Address, bounty_id: u64) {
+        for key in [
+            DataKey::BountyIndex,
+            DataKey::DepositorBounties(depositor.clone()),
+        ] {
+            let mut ids: Vec<u64> = env
+                .storage()
+                .persistent()
+                .get(&key)
+                .unwrap_or(Vec::new(env));
+            ids.push_back(bounty_id);
+            env.storage().persistent().set(&key, &ids);
+        }
+    }
+
+    /// Rejects `additional` new locks that would take the depositor past
+    /// `MaxActivePerDepositor`.
+    fn check_active_limit(env: &Env, depositor: &Address, additional: u32) -> Result<(), Error> {
+        let max_active: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MaxActivePerDepositor)
+            .unwrap_or(0);
+        if max_active == 0 {
+            return Ok(());
+        }
+        let active = Self::active_bounty_count(env, depositor);
+        if active.saturating_add(additional) > max_active {
+            return Err(Error::TooManyActiveBounties);
+        }
+        Ok(())
+    }
+
+    fn active_bounty_count(env: &Env, depositor: &Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ActiveBounties(depositor.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Adjusts the depositor's active bounty count. Called when an escrow is
+    /// created and when it reaches a final state (released or fully refunded).
+    /// Creations also bump the lifetime `EscrowCount`.
+    fn adjust_active_bounties(env: &Env, depositor: &Address, opened: bool) {
+        if opened {
+            let created: u64 = env
+                .storage()
+                .instance()
+                .get(&DataKey::EscrowCount)
+                .unwrap_or(0);
+            env.storage()
+                .instance()
+                .set(&DataKey::EscrowCount, &(created + 1));
+        }
+
+        let count = Self::active_bounty_count(env, depositor);
+        let count = if opened {
+            count.saturating_add(1)
+        } else {
+            count.saturating_sub(1)
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::ActiveBounties(depositor.clone()), &count);
+
+        let active: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ActiveCount)
+            .unwrap_or(0);
+        let active = if opened {
+            active.saturating_add(1)
+        } else {
+            active.saturating_sub(1)
+        };
+        env.storage().instance().set(&DataKey::ActiveCount, &active);
+    }
+
+    /// Update fee configuration (admin only)
+    pub fn update_fee_config(
+        env: Env,
+        lock_fee_rate: Option<i128>,
+        release_fee_rate: Option<i128>,
+        fee_recipient: Option<Address>,
+        fee_enabled: Option<bool>,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut fee_config = Self::get_fee_config_internal(&env);
+
+        if let Some(rate) = lock_fee_rate {
+            if !(0..=MAX_FEE_RATE).contains(&rate) {
+                return Err(Error::InvalidFeeRate);
+            }
+            fee_config.lock_fee_rate = rate;
+        }
+
+        if let Some(rate) = release_fee_rate {
+            if !(0..=MAX_FEE_RATE).contains(&rate) {
+                return Err(Error::InvalidFeeRate);
+            }
+            fee_config.release_fee_rate = rate;
+        }
+
+        if let Some(recipient) = fee_recipient {
+            fee_config.fee_recipient = recipient;
+        }
+
+        if let Some(enabled) = fee_enabled {
+            fee_config.fee_enabled = enabled;
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::FeeConfig, &fee_config);
+
+        events::emit_fee_config_updated(
+            &env,
+            events::FeeConfigUpdated {
+                lock_fee_rate: fee_config.lock_fee_rate,
+                release_fee_rate: fee_config.release_fee_rate,
+                fee_recipient: fee_config.fee_recipient.clone(),
+                fee_enabled: fee_config.fee_enabled,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Get current fee configuration (view function)
+    pub fn get_fee_config(env: Env) -> FeeConfig {
+        Self::get_fee_config_internal(&env)
+    }
+
+    /// Update the refund penalty configuration (admin only).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `penalty_rate` - Basis points withheld from each refund (0 disables)
+    /// * `penalty_recipient` - Collector for withheld penalties
+    ///
+    /// # Returns
+    /// * `Ok(())` - Configuration updated
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::InvalidFeeRate)` - Rate is negative or above `MAX_FEE_RATE`
+    pub fn update_penalty_config(
+        env: Env,
+        penalty_rate: i128,
+        penalty_recipient: Address,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if !(0..=MAX_FEE_RATE).contains(&penalty_rate) {
+            return Err(Error::InvalidFeeRate);
+        }
+
+        env.storage().instance().set(
+            &DataKey::PenaltyConfig,
+            &PenaltyConfig {
+                penalty_rate,
+                penalty_recipient,
+            },
+        );
+        Ok(())
+    }
+
+    /// Get current penalty configuration
+    pub fn get_penalty_config(env: Env) -> PenaltyConfig {
+        Self::get_penalty_config_internal(&env)
+    }
+
+    /// Lifetime total of refund penalties withheld.
+    pub fn total_penalties_collected(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalPenalties)
+            .unwrap_or(0)
+    }
+
+    /// Lifetime total of lock and release fees collected.
+    pub fn total_fees_collected(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalFees)
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of escrows ever created, by any lock entrypoint.
+    ///
+    /// Unlike `active_count` in `get_contract_stats`, settled escrows still
+    /// count, so the value only grows.
+    pub fn get_escrow_count(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::EscrowCount)
+            .unwrap_or(0)
+    }
+
+    /// Returns locked, released and refunded totals, the number of active
+    /// escrows and the admin in one call. See [`ContractStats`].
+    ///
+    /// # Gas Cost
+    /// Very Low - Instance storage reads only
+    pub fn get_contract_stats(env: Env) -> ContractStats {
+        let instance = env.storage().instance();
+        ContractStats {
+            total_locked: instance.get(&DataKey::TotalLocked).unwrap_or(0),
+            total_released: instance.get(&DataKey::TotalReleased).unwrap_or(0),
+            total_refunded: instance.get(&DataKey::TotalRefunded).unwrap_or(0),
+            active_count: instance.get(&DataKey::ActiveCount).unwrap_or(0),
+            admin: instance.get(&DataKey::Admin),
+        }
+    }
+
+    /// Update the per-escrow amount limits (admin only).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `min_amount` - Smallest accepted lock amount (0 disables the floor)
+    /// * `max_amount` - Largest accepted lock amount (0 means no cap)
+    ///
+    /// # Returns
+    /// * `Ok(())` - Limits updated
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::InvalidAmount)` - Negative bound, or cap below the floor
+    pub fn update_limits(env: Env, min_amount: i128, max_amount: i128) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if min_amount < 0 || max_amount < 0 || (max_amount > 0 && max_amount < min_amount) {
+            return Err(Error::InvalidAmount);
+        }
+
+        env.storage().instance().set(
+            &DataKey::AmountLimits,
+            &AmountLimits {
+                min_amount,
+                max_amount,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Get current amount limits
+    pub fn get_amount_limits(env: Env) -> AmountLimits {
+        Self::get_amount_limits_internal(&env)
+    }
+
+    /// Update the maximum lock horizon (admin only).
+    ///
+    /// `lock_funds` rejects deadlines later than `now + max_deadline_seconds`
+    /// with `DeadlineTooFar`. Existing escrows are not affected.
+    ///
+    /// # Returns
+    /// * `Ok(())` - Horizon updated
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::InvalidDeadline)` - `max_deadline_seconds` is zero or
+    ///   not above `min_lock_duration`
+    pub fn update_max_deadline(env: Env, max_deadline_seconds: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if max_deadline_seconds == 0
+            || max_deadline_seconds <= Self::get_min_lock_duration(env.clone())
+        {
+            return Err(Error::InvalidDeadline);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxDeadlineSeconds, &max_deadline_seconds);
+        Ok(())
+    }
+
+    /// Update the minimum lock duration (admin only).
+    ///
+    /// `lock_funds` rejects deadlines earlier than `now + min_lock_duration`
+    /// with `DeadlineTooSoon`, so a bounty cannot be opened with a deadline
+    /// seconds away. 0 disables the check. Existing escrows are not affected.
+    ///
+    /// # Returns
+    /// * `Ok(())` - Minimum updated
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::InvalidDeadline)` - Not shorter than the maximum lock horizon
+    pub fn update_min_lock_duration(env: Env, min_lock_duration: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if min_lock_duration >= Self::get_max_deadline(env.clone()) {
+            return Err(Error::InvalidDeadline);
+        }
+
+        env.storage()
+            .instance()
+            .set(&ConfigKey::MinLockDuration, &min_lock_duration);
+        Ok(())
+    }
+
+    /// Get the minimum lock duration in seconds (0 = disabled)
+    pub fn get_min_lock_duration(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&ConfigKey::MinLockDuration)
+            .unwrap_or(0)
+    }
+
+    /// Update the cap on total value locked (admin only).
+    ///
+    /// Locks that would push `TotalLocked` above `max_tvl` fail with
+    /// `TvlCapExceeded`. A `max_tvl` of 0 disables the cap. Lowering the cap
+    /// below the current total only blocks new locks.
+    ///
+    /// # Returns
+    /// * `Ok(())` - Cap updated
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::InvalidAmount)` - `max_tvl` is negative
+    pub fn update_max_tvl(env: Env, max_tvl: i128) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if max_tvl < 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        env.storage().instance().set(&DataKey::MaxTvl, &max_tvl);
+        Ok(())
+    }
+
+    /// Get the cap on total value locked (0 = uncapped)
+    pub fn get_max_tvl(env: Env) -> i128 {
+        env.storage().instance().get(&DataKey::MaxTvl).unwrap_or(0)
+    }
+
+    /// Update how many unsettled bounties one depositor may have (admin only).
+    ///
+    /// A bounty counts as active from lock until it is released or fully
+    /// refunded. Locks past the limit fail with `TooManyActiveBounties`.
+    /// A limit of 0 disables the check.
+    pub fn update_max_active_per_depositor(env: Env, max_active: u32) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxActivePerDepositor, &max_active);
+        Ok(())
+    }
+
+    /// Get the per-depositor active bounty limit (0 = uncapped)
+    pub fn get_max_active_per_depositor(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxActivePerDepositor)
+            .unwrap_or(0)
+    }
+
+    /// Update the minimum seconds between two locks by the same depositor
+    /// (admin only).
+    ///
+    /// Complements `max_active_per_depositor` with a velocity limit: a lock
+    /// within this interval of the depositor's previous one fails with
+    /// `RateLimited`. A batch lock counts once per depositor. 0 disables it.
+    pub fn update_min_lock_interval(env: Env, seconds: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        anti_abuse::set_min_lock_interval(&env, seconds);
+        Ok(())
+    }
+
+    /// Get the minimum seconds between locks by one depositor (0 = disabled)
+    pub fn get_min_lock_interval(env: Env) -> u64 {
+        anti_abuse::get_min_lock_interval(&env)
+    }
+
+    /// Number of the depositor's bounties that are not yet released or
+    /// fully refunded.
+    pub fn get_active_bounty_count(env: Env, depositor: Address) -> u32 {
+        Self::active_bounty_count(&env, &depositor)
+    }
+
+    /// Get the maximum lock horizon in seconds
+    pub fn get_max_deadline(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxDeadlineSeconds)
+            .unwrap_or(DEFAULT_MAX_DEADLINE_SECONDS)
+    }
+
+    /// Set how long after a bounty's deadline refunds stay locked (admin only).
+    ///
+    /// During the grace window `release_funds` still works but deadline
+    /// refunds fail with `DeadlineNotPassed`, so a keeper cannot refund a
+    /// bounty the instant its deadline passes while the admin is finishing
+    /// a review. Applies to existing escrows too. 0 disables the grace.
+    pub fn update_refund_grace(env: Env, refund_grace_seconds: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::RefundGraceSeconds, &refund_grace_seconds);
+        Ok(())
+    }
+
+    /// Get the refund grace period in seconds (0 = refunds unlock at the deadline)
+    pub fn get_refund_grace(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::RefundGraceSeconds)
+            .unwrap_or(0)
+    }
+
+    /// Set the keeper fee rate in basis points for new opt-in locks (admin only).
+    ///
+    /// Only bounties locked with `lock_funds_with_keeper_fee` pay it, at the
+    /// rate in force when they were locked.
+    ///
+    /// # Returns
+    /// * `Err(Error::InvalidFeeRate)` - Rate is negative or above `MAX_FEE_RATE`
+    pub fn update_keeper_fee(env: Env, keeper_fee_bps: i128) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if !(0..=MAX_FEE_RATE).contains(&keeper_fee_bps) {
+            return Err(Error::InvalidFeeRate);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::KeeperFeeBps, &keeper_fee_bps);
+        Ok(())
+    }
+
+    /// Get the keeper fee rate in basis points offered to new locks
+    pub fn get_keeper_fee(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::KeeperFeeBps)
+            .unwrap_or(0)
+    }
+
+    /// Earliest time a deadline refund of `escrow` is allowed.
+    ///
+    /// Fails with `TimeOverflow` when the grace period raised after the lock
+    /// pushes the unlock time past `u64::MAX`.
+    fn refund_unlocks_at(env: &Env, escrow: &Escrow) -> Result<u64, Error> {
+        escrow
+            .deadline
+            .checked_add(Self::get_refund_grace(env.clone()))
+            .ok_or(Error::TimeOverflow)
+    }
+
+    /// Replaces the escrow token (admin only).
+    ///
+    /// Intended for recovering from a misconfigured token at `init`. The swap
+    /// is only allowed while no funds are held for unsettled escrows, so the
+    /// token can never change underneath locked balances.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `new_token` - Token contract address to use from now on
+    ///
+    /// # Returns
+    /// * `Ok(())` - Token updated
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::EscrowsOutstanding)` - `TotalLocked` is non-zero, or
+    ///   escrows are already locked in `new_token`
+    /// * `Err(Error::SchemaMismatch)` - A schema migration is unfinished,
+    ///   so `TotalLocked` cannot be trusted yet
+    ///
+    /// # Events
+    /// Emits: `TokenUpdated { old, new, timestamp }`
+    pub fn update_token(env: Env, new_token: Address) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Self::check_schema_current(&env)?;
+
+        // Escrows already locked in `new_token` would switch counters
+        if Self::get_total_locked(env.clone()) != 0
+            || Self::get_total_locked_for(env.clone(), new_token.clone()) != 0
+        {
+            return Err(Error::EscrowsOutstanding);
+        }
+
+        let old: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        env.storage().instance().set(&DataKey::Token, &new_token);
+
+        events::emit_token_updated(
+            &env,
+            events::TokenUpdated {
+                old,
+                new: new_token,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Adds `token` to the allowlist used by `lock_funds_token` (admin only).
+    ///
+    /// Only vetted assets should be allowed: fee-on-transfer or rebasing
+    /// tokens break the escrow's accounting.
+    pub fn allow_token(env: Env, token: Address) -> Result<(), Error> {
+        Self::set_token_allowed(&env, token, true)
+    }
+
+    /// Removes `token` from the allowlist (admin only). Escrows already
+    /// locked in it are unaffected and settle normally.
+    pub fn disallow_token(env: Env, token: Address) -> Result<(), Error> {
+        Self::set_token_allowed(&env, token, false)
+    }
+
+    /// Returns whether new escrows may be locked in `token`. The configured
+    /// token is always allowed.
+    pub fn is_token_allowed(env: Env, token: Address) -> bool {
+        if env.storage().instance().get(&DataKey::Token) == Some(token.clone()) {
+            return true;
+        }
+        env.storage()
+            .persistent()
+            .get(&DataKey::AllowedToken(token))
+            .unwrap_or(false)
+    }
+
+    fn set_token_allowed(env: &Env, token: Address, allowed: bool) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let key = DataKey::AllowedToken(token);
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        Ok(())
+    }
+
+    /// Adds `depositor` to the depositor allowlist (admin only).
+    ///
+    /// Only consulted when the contract was initialized with
+    /// `depositor_allowlist_enabled`; see [`InitOptions`].
+    pub fn allow_depositor(env: Env, depositor: Address) -> Result<(), Error> {
+        Self::set_depositor_allowed(&env, depositor, true)
+    }
+
+    /// Removes `depositor` from the allowlist (admin only). Their existing
+    /// escrows are unaffected and settle normally.
+    pub fn disallow_depositor(env: Env, depositor: Address) -> Result<(), Error> {
+        Self::set_depositor_allowed(&env, depositor, false)
+    }
+
+    /// Returns whether `depositor` may lock funds: always when the
+    /// allowlist is disabled, otherwise only if they were allowed.
+    pub fn is_depositor_allowed(env: Env, depositor: Address) -> bool {
+        let enabled: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::DepositorAllowlistEnabled)
+            .unwrap_or(false);
+        !enabled
+            || env
+                .storage()
+                .persistent()
+                .get(&DataKey::AllowedDepositor(depositor))
+                .unwrap_or(false)
+    }
+
+    fn set_depositor_allowed(env: &Env, depositor: Address, allowed: bool) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let key = DataKey::AllowedDepositor(depositor);
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        Ok(())
+    }
+
+    // ========================================================================
+    // Admin Management
+    // ========================================================================
+
+    /// Hands the admin role to `new_admin`.
+    ///
+    /// Both the current and the new admin must authorize the call, so the
+    /// role cannot be sent to an address nobody controls.
+    ///
+    /// # Returns
+    /// * `Ok(())` - Admin replaced
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    ///
+    /// # Events
+    /// Emits: `AdminTransferred { previous_admin, new_admin, timestamp }`
+    pub fn transfer_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        new_admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        if let Some(mut admins) = env
+            .storage()
+            .instance()
+            .get::<_, Vec<Address>>(&DataKey::Admins)
+        {
+            // The new primary takes the old one's place at the front
+            if let Some(index) = admins.first_index_of(&new_admin) {
+                admins.remove(index);
+            }
+            if let Some(index) = admins.first_index_of(&admin) {
+                admins.remove(index);
+            }
+            admins.push_front(new_admin.clone());
+            env.storage().instance().set(&DataKey::Admins, &admins);
+        }
+
+        events::emit_admin_transferred(
+            &env,
+            events::AdminTransferred {
+                previous_admin: admin,
+                new_admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Permanently gives up the admin role, leaving the contract refund-only.
+    ///
+    /// Once renounced, every admin-gated entrypoint (`release_funds`,
+    /// approvals, config updates, `upgrade`) fails with `NotInitialized`,
+    /// and `init` cannot be called again. Locks and deadline refunds keep
+    /// working, so depositors can still post bounties that simply return
+    /// to them at the deadline.
+    ///
+    /// # Returns
+    /// * `Ok(())` - Admin cleared
+    /// * `Err(Error::NotInitialized)` - No admin is set
+    /// * `Err(Error::FundsStillLocked)` - `TotalLocked` is non-zero; settle
+    ///   every escrow first so none is left waiting on a release
+    /// * `Err(Error::SchemaMismatch)` - A schema migration is unfinished
+    ///
+    /// # Events
+    /// Emits: `AdminRenounced { previous_admin, timestamp }`
+    pub fn renounce_admin(env: Env) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Self::check_schema_current(&env)?;
+
+        if Self::get_total_locked(env.clone()) != 0 {
+            return Err(Error::FundsStillLocked);
+        }
+
+        env.storage().instance().remove(&DataKey::Admin);
+        env.storage().instance().remove(&DataKey::Admins);
+
+        events::emit_admin_renounced(
+            &env,
+            events::AdminRenounced {
+                previous_admin: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Admins allowed to release funds. Falls back to just the primary admin
+    /// until `add_admin` is first called; empty once the role is renounced.
+    fn admin_set(env: &Env) -> Vec<Address> {
+        if let Some(admins) = env.storage().instance().get(&DataKey::Admins) {
+            return admins;
+        }
+        let mut admins = vec![env];
+        if let Some(admin) = env.storage().instance().get::<_, Address>(&DataKey::Admin) {
+            admins.push_back(admin);
+        }
+        admins
+    }
+
+    /// Lists every admin with release authority, primary admin first.
+    pub fn get_admins(env: Env) -> Vec<Address> {
+        Self::admin_set(&env)
+    }
+
+    /// Returns whether `address` is in the admin set.
+    pub fn is_admin(env: Env, address: Address) -> bool {
+        Self::admin_set(&env).contains(&address)
+    }
+
+    /// Adds `new_admin` to the admin set, letting it call `release_funds_as`
+    /// (primary admin only).
+    ///
+    /// Added admins only gain release authority; managing the set, config
+    /// and upgrade entrypoints stay with the primary admin. Adding an
+    /// existing admin is a no-op.
+    ///
+    /// # Returns
+    /// * `Ok(())` - `new_admin` is in the set
+    /// * `Err(Error::NotInitialized)` - No admin is set
+    ///
+    /// # Events
+    /// Emits: `AdminAdded { admin, added_by, timestamp }`
+    pub fn add_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        let primary: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        primary.require_auth();
+
+        let mut admins = Self::admin_set(&env);
+        if admins.contains(&new_admin) {
+            return Ok(());
+        }
+        admins.push_back(new_admin.clone());
+        env.storage().instance().set(&DataKey::Admins, &admins);
+
+        events::emit_admin_added(
+            &env,
+            events::AdminAdded {
+                admin: new_admin,
+                added_by: primary,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Removes an added admin from the admin set (primary admin only).
+    ///
+    /// The primary admin cannot be removed this way; hand the role over with
+    /// `transfer_admin` or give it up with `renounce_admin`.
+    ///
+    /// # Returns
+    /// * `Ok(())` - `admin` is no longer in the set
+    /// * `Err(Error::NotInitialized)` - No admin is set
+    /// * `Err(Error::Unauthorized)` - `admin` is the primary admin or not an admin
+    ///
+    /// # Events
+    /// Emits: `AdminRemoved { admin, removed_by, timestamp }`
+    pub fn remove_admin(env: Env, admin: Address) -> Result<(), Error> {
+        let primary: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        primary.require_auth();
+
+        if admin == primary {
+            return Err(Error::Unauthorized);
+        }
+        let mut admins = Self::admin_set(&env);
+        let index = admins.first_index_of(&admin).ok_or(Error::Unauthorized)?;
+        admins.remove(index);
+        env.storage().instance().set(&DataKey::Admins, &admins);
+
+        events::emit_admin_removed(
+            &env,
+            events::AdminRemoved {
+                admin,
+                removed_by: primary,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    // ========================================================================
+    // Upgrade Functions
+    // ========================================================================
+
+    /// Upgrades the contract to new WASM code (admin only).
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `new_wasm_hash` - Hash of the already-uploaded WASM code
+    ///
+    /// # Returns
+    /// * `Ok(())` - Contract code replaced
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    ///
+    /// # State Changes
+    /// - Replaces the contract's WASM; the new code runs from the next invocation
+    /// - All instance and persistent storage (escrows, balances, config) is kept
+    /// - The stored version is left untouched; new code is expected to bump it
+    ///
+    /// # Events
+    /// Emits: `ContractUpgraded { new_wasm_hash, upgraded_by, timestamp }`
+    ///
+    /// # Production Upgrade Process
+    /// ```bash
+    /// # 1. Upload the new WASM and note the returned hash
+    /// stellar contract install \
+    ///   --wasm target/wasm32-unknown-unknown/release/bounty_escrow.wasm \
+    ///   --source ADMIN_SECRET_KEY
+    ///
+    /// # 2. Point the escrow at the new code
+    /// stellar contract invoke \
+    ///   --id CONTRACT_ID \
+    ///   --source ADMIN_SECRET_KEY \
+    ///   -- upgrade \
+    ///   --new_wasm_hash WASM_HASH
+    /// ```
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        events::emit_contract_upgraded(
+            &env,
+            events::ContractUpgraded {
+                new_wasm_hash: new_wasm_hash.clone(),
+                upgraded_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+
+        Ok(())
+    }
+
+    /// Returns the version number of the running contract code.
+    ///
+    /// Contracts initialized before versioning was introduced report `1`.
+    pub fn version(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::Version).unwrap_or(1)
+    }
+
+    /// Returns the layout version of the contract's persisted records.
+    ///
+    /// Unlike `version`, this only changes when stored types change shape;
+    /// clients can use it to pick decoders. Contracts initialized before
+    /// schema versioning report `1`.
+    pub fn schema_version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::SchemaVersion)
+            .unwrap_or(1)
+    }
+
+    /// Brings persisted records up to the schema this code expects
+    /// (admin only). Run it after an `upgrade` that bumps the schema.
+    ///
+    /// Escrows from schema version 1 must first be converted with
+    /// `migrate_escrows`; this call then marks the migration finished.
+    /// Safe to call repeatedly: a contract already on the current schema
+    /// is left untouched.
+    ///
+    /// # Returns
+    /// * `Ok(u32)` - The schema version now in effect
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::SchemaMismatch)` - Stored data was written by newer code
+    ///
+    /// # Events
+    /// Emits: `SchemaMigrated { from_version, to_version, timestamp }` when
+    /// the version changes
+    pub fn migrate(env: Env) -> Result<u32, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let from_version = Self::schema_version(env.clone());
+        if from_version > SCHEMA_VERSION {
+            return Err(Error::SchemaMismatch);
+        }
+        if from_version == SCHEMA_VERSION {
+            return Ok(SCHEMA_VERSION);
+        }
+
+        // Per-version steps go here, oldest first. Version 1 escrows are
+        // converted in batches by `migrate_escrows`, as the contract cannot
+        // enumerate them.
+
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+        events::emit_schema_migrated(
+            &env,
+            events::SchemaMigrated {
+                from_version,
+                to_version: SCHEMA_VERSION,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(SCHEMA_VERSION)
+    }
+
+    /// Converts escrows stored by schema version 1 to the current layout
+    /// and backfills the counters version 1 did not keep (admin only).
+    ///
+    /// Version 1 kept no bounty index, so the admin supplies the IDs (from
+    /// the `FundsLocked` events, say), at most `MAX_BATCH_SIZE` per call.
+    /// IDs already in the current layout are skipped, so batches may
+    /// overlap. Converted escrows take the configured token and a
+    /// `created_at` of 0; past refunds get `DeadlinePassed` or
+    /// `DepositorCancelled` by their timestamp.
+    ///
+    /// Convert every escrow, then call `migrate`. Until then `update_token`,
+    /// `renounce_admin` and `sweep_unaccounted` refuse to run, as they rely
+    /// on `TotalLocked`.
+    ///
+    /// # Returns
+    /// * `Ok(u32)` - Number of escrows converted by this call
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::SchemaMismatch)` - No version 1 migration is pending
+    /// * `Err(Error::InvalidBatchSize)` - Empty batch or over `MAX_BATCH_SIZE`
+    /// * `Err(Error::BountyNotFound)` - No escrow under one of the IDs
+    pub fn migrate_escrows(env: Env, bounty_ids: Vec<u64>) -> Result<u32, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if Self::schema_version(env.clone()) != 1 {
+            return Err(Error::SchemaMismatch);
+        }
+        if bounty_ids.is_empty() || bounty_ids.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        let mut converted = 0u32;
+        for bounty_id in bounty_ids.iter() {
+            let key = DataKey::Escrow(bounty_id);
+            let fields: Map<Symbol, Val> = env
+                .storage()
+                .persistent()
+                .get(&key)
+                .ok_or(Error::BountyNotFound)?;
+            // Only the current layout records the token
+            if fields.contains_key(symbol_short!("token")) {
+                continue;
+            }
+            let legacy = EscrowV1::try_from_val(&env, &fields.to_val()).unwrap();
+
+            let escrow = Self::convert_escrow_v1(&env, legacy);
+            env.storage().persistent().set(&key, &escrow);
+            Self::index_bounty(&env, &escrow.depositor, bounty_id);
+            Self::adjust_active_bounties(&env, &escrow.depositor, true);
+            if escrow.status == EscrowStatus::Locked
+                || escrow.status == EscrowStatus::PartiallyRefunded
+            {
+                Self::adjust_total_locked(&env, &escrow.token, escrow.remaining_amount);
+            } else {
+                Self::adjust_active_bounties(&env, &escrow.depositor, false);
+            }
+            converted += 1;
+        }
+        Ok(converted)
+    }
+
+    /// Rewrites a schema version 1 escrow in the current layout.
+    fn convert_escrow_v1(env: &Env, legacy: EscrowV1) -> Escrow {
+        let mut refund_history = Vec::new(env);
+        for record in legacy.refund_history.iter() {
+            let reason = if record.timestamp < legacy.deadline {
+                RefundReason::DepositorCancelled
+            } else {
+                RefundReason::DeadlinePassed
+            };
+            refund_history.push_back(RefundRecord {
+                amount: record.amount,
+                recipient: record.recipient,
+                mode: record.mode,
+                reason,
+                timestamp: record.timestamp,
+            });
+        }
+        Escrow {
+            depositor: legacy.depositor,
+            amount: legacy.amount,
+            status: legacy.status,
+            deadline: legacy.deadline,
+            refund_history,
+            remaining_amount: legacy.remaining_amount,
+            created_at: 0,
+            token: Self::default_token(env),
+        }
+    }
+
+    /// Rejects operations that rely on counters rebuilt by a migration
+    /// until `migrate` has finished.
+    fn check_schema_current(env: &Env) -> Result<(), Error> {
+        if Self::schema_version(env.clone()) != SCHEMA_VERSION {
+            return Err(Error::SchemaMismatch);
+        }
+        Ok(())
+    }
+
+    /// Reports whether an optional feature is enabled on this instance.
+    ///
+    /// Config-driven features (`fees`, `penalty`, `ref_swap`, `limits`,
+    /// `elig`) reflect the current admin configuration. Per-bounty features
+    /// (`dispute`, `oracle`) are always available. Unknown symbols return
+    /// `false`.
+    pub fn supports(env: Env, feature: Symbol) -> bool {
+        if feature == FEATURE_FEES {
+            let config = Self::get_fee_config_internal(&env);
+            config.fee_enabled && (config.lock_fee_rate > 0 || config.release_fee_rate > 0)
+        } else if feature == FEATURE_PENALTIES {
+            env.storage()
+                .instance()
+                .get::<_, PenaltyConfig>(&DataKey::PenaltyConfig)
+                .is_some_and(|config| config.penalty_rate > 0)
+        } else if feature == FEATURE_REFUND_SWAP {
+            env.storage().instance().has(&DataKey::Amm)
+        } else if feature == FEATURE_AMOUNT_LIMITS {
+            let limits = Self::get_amount_limits_internal(&env);
+            limits.min_amount > 0 || limits.max_amount > 0
+        } else if feature == FEATURE_ELIGIBILITY {
+            let config = Self::get_eligibility_config_internal(&env);
+            config.require_registration || config.require_approval || config.terms_version > 0
+        } else {
+            feature == FEATURE_DISPUTES || feature == FEATURE_ORACLES
+        }
+    }
+
+    // ========================================================================
+    // Monitoring & Analytics Functions
+    // ========================================================================
+
+    /// Health check - returns contract health status.
+    ///
+    /// Also verifies solvency: if the contract's token balance is below
+    /// `TotalLocked`, `insolvent` is set and the contract is reported
+    /// unhealthy. This surfaces accounting drift or funds moved out of band.
+    ///
+    /// `reentrancy_locked` reports a reentrancy guard left set outside of a
+    /// call, which blocks every guarded entrypoint until
+    /// `force_clear_reentrancy_guard` is used.
+    pub fn health_check(env: Env) -> monitoring::HealthStatus {
+        let mut status = monitoring::health_check(&env);
+
+        if let Ok(balance) = Self::get_balance(env.clone()) {
+            status.insolvent = balance < Self::get_total_locked(env.clone());
+        }
+        status.reentrancy_locked = env.storage().temporary().has(&DataKey::ReentrancyGuard);
+        status.is_healthy = !status.insolvent && !status.reentrancy_locked;
+
+        status
+    }
+
+    /// Clears a stuck reentrancy guard (admin only).
+    ///
+    /// Recovery tool for a contract whose guarded entrypoints all fail with
+    /// "Reentrancy detected". The guard lives in temporary storage and also
+    /// lapses on its own once its TTL expires; this clears it immediately.
+    /// Returns whether a guard was present.
+    pub fn force_clear_reentrancy_guard(env: Env) -> Result<bool, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let was_locked = env.storage().temporary().has(&DataKey::ReentrancyGuard);
+        env.storage().temporary().remove(&DataKey::ReentrancyGuard);
+        Ok(was_locked)
+    }
+
+    /// Get analytics - returns usage analytics.
+    ///
+    /// `error_count` is the aggregate of failures across all entrypoints.
+    pub fn get_analytics(env: Env) -> monitoring::Analytics {
+        monitoring::get_analytics(&env)
+    }
+
+    /// Get state snapshot - returns current state
+    pub fn get_state_snapshot(env: Env) -> monitoring::StateSnapshot {
+        monitoring::get_state_snapshot(&env)
+    }
+
+    /// Returns the change in operation, user and error counts since the
+    /// previous call, with the ledger time elapsed, and records the current
+    /// snapshot as the new baseline (admin only).
+    ///
+    /// The baseline is shared, so only the admin's collector may advance
+    /// it. The first call diffs against an empty snapshot at time 0.
+    ///
+    /// # Errors
+    /// * `NotInitialized` - Contract not initialized
+    pub fn take_snapshot_delta(env: Env) -> Result<monitoring::SnapshotDelta, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Ok(monitoring::take_snapshot_delta(&env))
+    }
+
+    /// Get performance stats for a function, including its failure count
+    pub fn get_performance_stats(env: Env, function_name: Symbol) -> monitoring::PerformanceStats {
+        monitoring::get_performance_stats(&env, function_name)
+    }
+
+    /// Get `get_performance_stats` for every tracked function in one call,
+    /// in the order each was first called. Functions last called before
+    /// the contract started keeping this list are missing until called again.
+    pub fn get_all_performance_stats(env: Env) -> Vec<(Symbol, monitoring::PerformanceStats)> {
+        monitoring::get_all_performance_stats(&env)
+    }
+
+    // ========================================================================
+    // Pause and Emergency Functions
+    // ========================================================================
+
+    /// Check if contract is paused (internal helper)
+    fn is_paused_internal(env: &Env) -> bool {
+        env.storage()
+            .persistent()
+            .get::<_, bool>(&DataKey::IsPaused)
+            .unwrap_or(false)
+    }
+
+    /// Get pause status (view function)
+    pub fn is_paused(env: Env) -> bool {
+        Self::is_paused_internal(&env)
+    }
+
+    /// Pause the contract (admin only)
+    /// Prevents new fund locks, releases, and refunds
+    pub fn pause(env: Env) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if Self::is_paused_internal(&env) {
+            return Ok(()); // Already paused, idempotent
+        }
+
+        env.storage().persistent().set(&DataKey::IsPaused, &true);
+
+        emit_contract_paused(
+            &env,
+            ContractPaused {
+                paused_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Unpause the contract (admin only)
+    /// Resumes normal operations
+    pub fn unpause(env: Env) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !Self::is_paused_internal(&env) {
+            return Ok(()); // Already unpaused, idempotent
+        }
+
+        env.storage().persistent().set(&DataKey::IsPaused, &false);
+
+        emit_contract_unpaused(
+            &env,
+            ContractUnpaused {
+                unpaused_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Emergency withdrawal for all contract funds (admin only, only when paused)
+    /// This function allows admins to recover all contract funds in case of critical
+    /// security issues or unrecoverable bugs. It can only be called when the contract
+    /// is paused to prevent misuse.
+    pub fn emergency_withdraw(env: Env, recipient: Address) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        // Only allow emergency withdrawal when contract is paused
+        if !Self::is_paused_internal(&env) {
+            return Err(Error::Unauthorized);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+
+        // Get contract balance
+        let balance = client.balance(&env.current_contract_address());
+
+        if balance <= 0 {
+            return Ok(()); // No funds to withdraw
+        }
+
+        // Transfer all funds to recipient
+        client.transfer(&env.current_contract_address(), &recipient, &balance);
+
+        emit_emergency_withdrawal(
+            &env,
+            EmergencyWithdrawal {
+                withdrawn_by: admin.clone(),
+                amount: balance,
+                recipient: recipient.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Lock funds for a specific bounty.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `depositor` - Address depositing the funds (must authorize)
+    /// * `bounty_id` - Unique identifier for this bounty
+    /// * `amount` - Token amount to lock (in smallest denomination)
+    /// * `deadline` - Unix timestamp after which refund is allowed
+    ///
+    /// # Returns
+    /// * `Ok(())` - Funds successfully locked
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::BountyExists)` - Bounty ID already in use
+    /// * `Err(Error::DepositorNotAllowed)` - Depositor allowlist enabled and depositor not on it
+    /// * `Err(Error::RateLimited)` - Depositor locked within `min_lock_interval`
+    ///
+    /// # State Changes
+    /// - Transfers `amount` tokens from depositor to contract
+    /// - Creates Escrow record in persistent storage for the amount actually
+    ///   received, which is less than `amount` for fee-on-transfer tokens
+    /// - Emits FundsLocked event
+    ///
+    /// # Authorization
+    /// - Depositor must authorize the transaction, which covers the token
+    ///   transfer; no separate approval is needed (see `lock_funds_from`
+    ///   for the allowance-based variant)
+    /// - Depositor must have sufficient token balance
+    ///
+    /// # Security Considerations
+    /// - Bounty ID must be unique (prevents overwrites)
+    /// - Amount must be positive (enforced by token contract)
+    /// - Deadline should be reasonable (recommended: 7-90 days)
+    /// - Token transfer is atomic with state update
+    ///
+    /// # Events
+    /// Emits: `FundsLocked { bounty_id, amount, depositor, deadline }`
+    ///
+    /// # Example
+    /// ```rust
+    /// let depositor = Address::from_string("GDEPOSIT...");
+    /// let amount = 1000_0000000; // 1000 USDC
+    /// let deadline = env.ledger().timestamp() + (30 * 24 * 60 * 60); // 30 days
+    ///
+    /// escrow_client.lock_funds(&depositor, &42, &amount, &deadline)?;
+    /// // Funds are now locked and can be released or refunded
+    /// ```
+    ///
+    /// # Gas Cost
+    /// Medium - Token transfer + storage write + event emission
+    ///
+    /// # Common Pitfalls
+    /// - Using a bounty ID that already exists
+    /// - Setting deadline in the past or too far in the future
+    pub fn lock_funds(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        Self::lock_funds_in(env, depositor, bounty_id, None, amount, deadline, false)
+    }
+
+    /// Locks funds for a bounty from an allowance the depositor granted this
+    /// contract with the token's `approve`, so a relayer can submit the
+    /// transaction and pay its fees.
+    ///
+    /// The depositor still authorizes this call, binding the bounty ID,
+    /// amount and deadline; only the token movement is drawn from the
+    /// allowance, so the signed payload need not include a token transfer.
+    /// Any lock fee is drawn from the same allowance, on top of what the
+    /// escrow receives.
+    ///
+    /// # Errors
+    /// Same as [`lock_funds`](Self::lock_funds). A missing or insufficient
+    /// allowance fails inside the token contract and reverts the call.
+    pub fn lock_funds_from(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        Self::lock_funds_in(env, depositor, bounty_id, None, amount, deadline, true)
+    }
+
+    /// Locks funds for a bounty in `token` instead of the configured token.
+    ///
+    /// Behaves like [`lock_funds`](Self::lock_funds); the token is stored on
+    /// the escrow, and its release or refund pays out in that same token.
+    /// Lets one deployment hold, say, USDC and XLM bounties side by side.
+    ///
+    /// Funds in tokens other than the configured one are tracked separately
+    /// (see `get_total_locked_for`) and are not counted against the TVL cap.
+    ///
+    /// # Errors
+    /// * `TokenNotAllowed` - `token` is neither the configured token nor on
+    ///   the allowlist (see `allow_token`)
+    /// * Otherwise the same as [`lock_funds`](Self::lock_funds).
+    pub fn lock_funds_token(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        token: Address,
+        amount: i128,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        Self::lock_funds_in(
+            env,
+            depositor,
+            bounty_id,
+            Some(token),
+            amount,
+            deadline,
+            false,
+        )
+    }
+
+    /// Shared body of `lock_funds`, `lock_funds_token` and `lock_funds_from`;
+    /// `None` locks in the configured token. With `from_allowance` the
+    /// deposit is pulled from the depositor's allowance rather than
+    /// transferred directly.
+    fn lock_funds_in(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        token: Option<Address>,
+        amount: i128,
+        deadline: u64,
+        from_allowance: bool,
+    ) -> Result<(), Error> {
+        // Apply rate limiting
+        anti_abuse::check_rate_limit(&env, depositor.clone());
+
+        let start = env.ledger().timestamp();
+        let caller = depositor.clone();
+
+        // Check if contract is paused
+        if Self::is_paused_internal(&env) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            return Err(Error::ContractPaused);
+        }
+
+        // Verify depositor authorization
+        depositor.require_auth();
+
+        // Checks
+        if let Err(err) = Self::validate_lock(&env, amount, deadline) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            return Err(err);
+        }
+
+        // Prevent duplicate bounty IDs
+        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            return Err(Error::BountyExists);
+        }
+
+        if !Self::is_depositor_allowed(env.clone(), depositor.clone()) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            return Err(Error::DepositorNotAllowed);
+        }
+
+        if let Err(err) = Self::check_active_limit(&env, &depositor, 1) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            return Err(err);
+        }
+
+        if !anti_abuse::record_lock(&env, &depositor) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            return Err(Error::RateLimited);
+        }
+
+        let token = token.unwrap_or_else(|| Self::default_token(&env));
+        if !Self::is_token_allowed(env.clone(), token.clone()) {
+            monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+            return Err(Error::TokenNotAllowed);
+        }
+
+        let (net_amount, fee_amount) = match Self::prepare_deposit(&env, &token, amount) {
+            Ok(split) => split,
+            Err(err) => {
+                monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+                return Err(err);
+            }
+        };
+
+        // Effects: the escrow is stored before any token call, so the
+        // bounty ID is taken even if the token re-enters
+        let mut escrow = Escrow {
+            depositor: depositor.clone(),
+            amount: net_amount,
+            status: EscrowStatus::Locked,
+            deadline,
+            refund_history: vec![&env],
+            remaining_amount: net_amount,
+            created_at: env.ledger().timestamp(),
+            token: token.clone(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::adjust_total_locked(&env, &token, net_amount);
+        Self::index_bounty(&env, &depositor, bounty_id);
+        Self::adjust_active_bounties(&env, &depositor, true);
+
+        // Interactions: pull the deposit (net of the lock fee)
+        let guarded = Self::enter_token_guard(&env, &token);
+        let transferred = Self::transfer_deposit(
+            &env,
+            &token,
+            &depositor,
+            net_amount,
+            fee_amount,
+            from_allowance,
+        );
+        Self::exit_token_guard(&env, guarded);
+        let received = match transferred {
+            Ok(received) => received,
+            Err(err) => {
+                monitoring::track_operation(&env, symbol_short!("lock"), caller, false);
+                return Err(err);
+            }
+        };
+
+        // Tokens that charge a transfer fee deliver less than was sent;
+        // only what actually arrived is held for release or refund
+        if received != net_amount {
+            escrow.amount = received;
+            escrow.remaining_amount = received;
+            env.storage()
+                .persistent()
+                .set(&DataKey::Escrow(bounty_id), &escrow);
+            Self::adjust_total_locked(&env, &token, received - net_amount);
+        }
+
+        // Emit event for off-chain indexing
+        emit_funds_locked(
+            &env,
+            FundsLocked {
+                bounty_id,
+                amount: received, // Emit received amount (after fees)
+                depositor: depositor.clone(),
+                deadline,
+            },
+        );
+        emit_escrow_created(&env, bounty_id, &escrow);
+
+        // Track successful operation
+        monitoring::track_operation(&env, symbol_short!("lock"), caller, true);
+
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("lock"), duration);
+
+        Ok(())
+    }
+
+    /// Releases escrowed funds to a contributor.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to release funds for
+    /// * `contributor` - Address to receive the funds
+    ///
+    /// # Returns
+    /// * `Ok(())` - Funds successfully released
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::Unauthorized)` - Caller is not the admin
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Funds not in LOCKED state
+    ///
+    /// # State Changes
+    /// - Updates escrow status to Released, then transfers tokens from
+    ///   contract to contributor; a failed transfer reverts both
+    /// - Emits FundsReleased event
+    ///
+    /// # Authorization
+    /// - **CRITICAL**: Only admin can call this function
+    /// - Admin address must match initialization value
+    ///
+    /// # Security Considerations
+    /// - This is the most security-critical function
+    /// - Admin should verify task completion off-chain before calling
+    /// - Once released, funds cannot be retrieved
+    /// - Recipient address should be verified carefully
+    /// - Consider implementing multi-sig for admin
+    ///
+    /// # Events
+    /// Emits: `FundsReleased { bounty_id, amount, recipient, timestamp }`
+    ///
+    /// # Example
+    /// ```rust
+    /// // After verifying task completion off-chain:
+    /// let contributor = Address::from_string("GCONTRIB...");
+    ///
+    /// // Admin calls release
+    /// escrow_client.release_funds(&42, &contributor)?;
+    /// // Funds transferred to contributor, escrow marked as Released
+    /// ```
+    ///
+    /// # Gas Cost
+    /// Medium - Token transfer + storage update + event emission
+    ///
+    /// # Best Practices
+    /// 1. Verify contributor identity off-chain
+    /// 2. Confirm task completion before release
+    /// 3. Log release decisions in backend system
+    /// 4. Monitor release events for anomalies
+    /// 5. Consider implementing release delays for high-value bounties
+    pub fn release_funds(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
+        // Ensure contract is initialized
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
 
         // Verify admin authorization
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        Self::release_funds_by(&env, admin, bounty_id, contributor)
+    }
+
+    /// Releases a bounty on behalf of any admin in the admin set.
+    ///
+    /// Behaves exactly like `release_funds`, but authorization, rate
+    /// limiting and monitoring are attributed to `admin` instead of the
+    /// primary admin, so each backend service can sign with its own key.
+    ///
+    /// # Returns
+    /// * `Ok(())` - Funds released
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::Unauthorized)` - `admin` is not in the admin set
+    /// * Otherwise the same errors as `release_funds`
+    pub fn release_funds_as(
+        env: Env,
+        admin: Address,
+        bounty_id: u64,
+        contributor: Address,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        if !Self::is_admin(env.clone(), admin.clone()) {
+            return Err(Error::Unauthorized);
+        }
+        Self::release_funds_by(&env, admin, bounty_id, contributor)
+    }
+
+    /// Shared body of `release_funds` and `release_funds_as`; `admin` must
+    /// already be known to be in the admin set.
+    fn release_funds_by(
+        env: &Env,
+        admin: Address,
+        bounty_id: u64,
+        contributor: Address,
+    ) -> Result<(), Error> {
+        let env = env.clone();
+        let start = env.ledger().timestamp();
+
+        // Check if contract is paused
+        if Self::is_paused_internal(&env) {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            return Err(Error::ContractPaused);
+        }
+
+        // Apply rate limiting
+        anti_abuse::check_rate_limit(&env, admin.clone());
+
+        admin.require_auth();
+
+        // Verify bounty exists
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            return Err(Error::BountyNotFound);
+        }
+
+        // Get and verify escrow state
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        if escrow.status != EscrowStatus::Locked {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            return Err(Error::FundsNotLocked);
+        }
+
+        if let Err(err) = Self::check_multisig_not_required(&env, &escrow) {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            return Err(err);
+        }
+
+        if let Err(err) = Self::check_contributor_eligibility(&env, bounty_id, &contributor) {
+            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
+            return Err(err);
+        }
+
+        // Settle the escrow, then transfer funds to contributor
+        let guarded = Self::enter_token_guard(&env, &escrow.token);
+        Self::release_escrow(&env, bounty_id, &mut escrow, &contributor);
+        Self::exit_token_guard(&env, guarded);
+
+        // Track successful operation
+        monitoring::track_operation(&env, symbol_short!("release"), admin, true);
+
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("release"), duration);
+        Ok(())
+    }
+
+    /// Preflights `release_funds` without changing state.
+    ///
+    /// Runs the release preconditions in the same order as `release_funds`
+    /// and returns the first error it would hit. Contributor gates are not
+    /// checked, since they depend on the recipient.
+    ///
+    /// # Returns
+    /// * `Ok(())` - A release would pass these checks
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::ContractPaused)` - Contract is paused
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Escrow is not in `Locked` state
+    /// * `Err(Error::MultisigRequired)` - Escrow must go through `confirm_release`
+    pub fn can_release(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+        let escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::check_multisig_not_required(&env, &escrow)
+    }
+
+    /// Retries a release that previously failed at the token transfer, for
+    /// example because the token was frozen or the recipient could not
+    /// receive it.
+    ///
+    /// A failed release reverts entirely, leaving the escrow `Locked`, so
+    /// this runs the same checks as `release_funds` and additionally emits
+    /// `ReleaseRetried` so operators can tell retries from first attempts.
+    ///
+    /// # Errors
+    /// * `FundsNotLocked` - Escrow was already released or refunded
+    /// * Otherwise the same as `release_funds`
+    pub fn retry_release(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
+        Self::release_funds(env.clone(), bounty_id, contributor.clone())?;
+        events::emit_release_retried(
+            &env,
+            events::ReleaseRetried {
+                bounty_id,
+                contributor,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Approve a release to `contributor` without paying it out yet (admin only).
+    ///
+    /// The escrowed amount is counted as committed in `total_pending_releases`
+    /// until the approval is executed via `release_funds`, revoked, or the
+    /// escrow is refunded. Re-approving replaces the previous approval.
+    ///
+    /// # Returns
+    /// * `Ok(())` - Approval recorded
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Escrow is not in `Locked` state
+    pub fn approve_release(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+
+        Self::clear_release_approval(&env, bounty_id);
+
+        let approval = ReleaseApproval {
+            bounty_id,
+            amount: escrow.remaining_amount,
+            contributor,
+            approved_by: admin,
+            approved_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReleaseApproval(bounty_id), &approval);
+
+        let pending: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingReleases)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingReleases, &(pending + approval.amount));
+
+        Ok(())
+    }
+
+    /// Revoke a pending release approval (admin only).
+    ///
+    /// # Returns
+    /// * `Ok(())` - Approval removed
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::ApprovalNotFound)` - No pending approval for this bounty
+    pub fn revoke_release_approval(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::ReleaseApproval(bounty_id))
+        {
+            return Err(Error::ApprovalNotFound);
+        }
+        Self::clear_release_approval(&env, bounty_id);
+
+        Ok(())
+    }
+
+    /// Lets `contributor` pull the bounty with `claim` until
+    /// `now + claim_window` (admin only).
+    ///
+    /// Runs the same checks as `release_funds` up front; the escrow stays
+    /// `Locked` until claimed. Re-authorizing replaces the previous claim.
+    ///
+    /// # Errors
+    /// * `InvalidDeadline` - `claim_window` is zero
+    /// * `TimeOverflow` - The claim deadline does not fit in a `u64`
+    /// * Otherwise the same as `release_funds`
+    pub fn authorize_claim(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        claim_window: u64,
+    ) -> Result<(), Error> {
+        Self::can_release(env.clone(), bounty_id)?;
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if claim_window == 0 {
+            return Err(Error::InvalidDeadline);
+        }
+        let claim_deadline = env
+            .ledger()
+            .timestamp()
+            .checked_add(claim_window)
+            .ok_or(Error::TimeOverflow)?;
+        Self::check_contributor_eligibility(&env, bounty_id, &contributor)?;
+
+        let escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        env.storage().persistent().set(
+            &ClaimKey::PendingClaim(bounty_id),
+            &PendingClaim {
+                bounty_id,
+                contributor,
+                amount: escrow.remaining_amount,
+                authorized_by: admin,
+                claim_deadline,
+            },
+        );
+        Ok(())
+    }
+
+    /// Get the pending claim for a bounty, if any.
+    pub fn get_pending_claim(env: Env, bounty_id: u64) -> Option<PendingClaim> {
+        env.storage()
+            .persistent()
+            .get(&ClaimKey::PendingClaim(bounty_id))
+    }
+
+    /// Pays a bounty out to the contributor of its pending claim, who must
+    /// authorize. Returns the net amount received.
+    ///
+    /// The multisig and contributor eligibility gates are checked again at
+    /// claim time, so a denylisting or new multisig config after
+    /// `authorize_claim` still blocks the payout.
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is paused
+    /// * `BountyNotFound` - No pending claim for this bounty
+    /// * `ClaimExpired` - The claim deadline has passed
+    /// * `FundsNotLocked` - Escrow is no longer `Locked`
+    /// * `MultisigRequired` - The escrow now needs multisig confirmation
+    /// * Any contributor eligibility error (see `release_funds`)
+    pub fn claim(env: Env, bounty_id: u64) -> Result<i128, Error> {
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+        let pending =
+            Self::get_pending_claim(env.clone(), bounty_id).ok_or(Error::BountyNotFound)?;
+        pending.contributor.require_auth();
+        if env.ledger().timestamp() > pending.claim_deadline {
+            return Err(Error::ClaimExpired);
+        }
+
+        let mut escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::check_multisig_not_required(&env, &escrow)?;
+        Self::check_contributor_eligibility(&env, bounty_id, &pending.contributor)?;
+
+        let guarded = Self::enter_token_guard(&env, &escrow.token);
+        let net_amount = Self::release_escrow(&env, bounty_id, &mut escrow, &pending.contributor);
+        Self::exit_token_guard(&env, guarded);
+        Ok(net_amount)
+    }
+
+    /// Returns a bounty whose claim expired unclaimed to its depositor and
+    /// marks it `Refunded`. Anyone may call; the funds can only go to the
+    /// depositor, and no refund penalty is withheld.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - No pending claim for this bounty
+    /// * `DeadlineNotPassed` - The claim deadline has not passed yet
+    /// * `FundsNotLocked` - Escrow is no longer `Locked`
+    pub fn reclaim_unclaimed(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let pending =
+            Self::get_pending_claim(env.clone(), bounty_id).ok_or(Error::BountyNotFound)?;
+        if env.ledger().timestamp() <= pending.claim_deadline {
+            return Err(Error::DeadlineNotPassed);
+        }
+
+        let mut escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let guarded = Self::enter_token_guard(&env, &escrow.token);
+        let depositor = escrow.depositor.clone();
+        let remaining = escrow.remaining_amount;
+        let result = Self::refund_escrow(
+            &env,
+            &EscrowId::Num(bounty_id),
+            &mut escrow,
+            remaining,
+            &depositor,
+            RefundMode::Full,
+            RefundReason::ClaimExpired,
+        );
+        Self::exit_token_guard(&env, guarded);
+        result
+    }
+
+    /// Configures the M-of-N multisig for high-value releases (admin only).
+    ///
+    /// Once set, escrows holding at least `min_amount` can no longer be
+    /// released by a single key (`release_funds`, batch and auto-release
+    /// paths fail with `MultisigRequired`) and must instead collect
+    /// `threshold` confirmations. A `threshold` of 0 removes the multisig.
+    /// Replacing the config leaves open proposals in place; their existing
+    /// confirmations still count if the signer remains in the set.
+    ///
+    /// # Returns
+    /// * `Ok(())` - Config stored or cleared
+    /// * `Err(Error::NotInitialized)` - Contract not initialized
+    /// * `Err(Error::InvalidThreshold)` - More confirmations than signers,
+    ///   or duplicate signers
+    /// * `Err(Error::InvalidAmount)` - `min_amount` is negative
+    pub fn set_release_multisig(
+        env: Env,
+        signers: Vec<Address>,
+        threshold: u32,
+        min_amount: i128,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if threshold == 0 {
+            env.storage()
+                .instance()
+                .remove(&MultisigKey::ReleaseMultisig);
+            return Ok(());
+        }
+        if threshold > signers.len() {
+            return Err(Error::InvalidThreshold);
+        }
+        for (i, signer) in signers.iter().enumerate() {
+            if signers.first_index_of(&signer) != Some(i as u32) {
+                return Err(Error::InvalidThreshold);
+            }
+        }
+        if min_amount < 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        env.storage().instance().set(
+            &MultisigKey::ReleaseMultisig,
+            &ReleaseMultisig {
+                signers,
+                threshold,
+                min_amount,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the release multisig config, if one is set.
+    pub fn get_release_multisig(env: Env) -> Option<ReleaseMultisig> {
+        env.storage().instance().get(&MultisigKey::ReleaseMultisig)
+    }
+
+    /// Opens a multisig release of `bounty_id` to `contributor`.
+    ///
+    /// Proposing does not count as a confirmation; the proposer confirms
+    /// separately like any other signer. Re-proposing replaces the previous
+    /// proposal and discards its confirmations.
+    ///
+    /// # Returns
+    /// * `Ok(())` - Proposal recorded
+    /// * `Err(Error::Unauthorized)` - `proposer` is not a signer, or no
+    ///   multisig is configured
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Escrow is not in `Locked` state
+    ///
+    /// # Events
+    /// Emits: `ReleaseProposed { bounty_id, contributor, proposed_by, threshold, timestamp }`
+    pub fn propose_release(
+        env: Env,
+        proposer: Address,
+        bounty_id: u64,
+        contributor: Address,
+    ) -> Result<(), Error> {
+        let config: ReleaseMultisig = env
+            .storage()
+            .instance()
+            .get(&MultisigKey::ReleaseMultisig)
+            .ok_or(Error::Unauthorized)?;
+        if !config.signers.contains(&proposer) {
+            return Err(Error::Unauthorized);
+        }
+        proposer.require_auth();
+
+        let escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::check_recipient(&env, &contributor)?;
+
+        let timestamp = env.ledger().timestamp();
+        let proposal = ReleaseProposal {
+            bounty_id,
+            contributor: contributor.clone(),
+            proposed_by: proposer.clone(),
+            proposed_at: timestamp,
+            confirmations: vec![&env],
+        };
+        env.storage()
+            .persistent()
+            .set(&MultisigKey::ReleaseProposal(bounty_id), &proposal);
+
+        events::emit_release_proposed(
+            &env,
+            events::ReleaseProposed {
+                bounty_id,
+                contributor,
+                proposed_by: proposer,
+                threshold: config.threshold,
+                timestamp,
+            },
+        );
+        Ok(())
+    }
+
+    /// Confirms a pending multisig release, executing it once the
+    /// threshold of distinct signers is reached.
+    ///
+    /// The executing confirmation runs the same pause and contributor
+    /// eligibility checks as `release_funds`.
+    ///
+    /// # Returns
+    /// * `Ok(true)` - This confirmation met the threshold and funds were released
+    /// * `Ok(false)` - Confirmation recorded, more are needed
+    /// * `Err(Error::Unauthorized)` - `signer` is not a signer, or no
+    ///   multisig is configured
+    /// * `Err(Error::ReleaseNotProposed)` - No open proposal for the bounty
+    /// * `Err(Error::AlreadyConfirmed)` - `signer` already confirmed it
+    /// * `Err(Error::FundsNotLocked)` - Escrow settled since the proposal
+    ///
+    /// # Events
+    /// Emits: `ReleaseConfirmed { bounty_id, signer, confirmations, threshold, timestamp }`,
+    /// followed by `FundsReleased` when the threshold is met
+    pub fn confirm_release(env: Env, signer: Address, bounty_id: u64) -> Result<bool, Error> {
+        let config: ReleaseMultisig = env
+            .storage()
+            .instance()
+            .get(&MultisigKey::ReleaseMultisig)
+            .ok_or(Error::Unauthorized)?;
+        if !config.signers.contains(&signer) {
+            return Err(Error::Unauthorized);
+        }
+        signer.require_auth();
+
+        let key = MultisigKey::ReleaseProposal(bounty_id);
+        let mut proposal: ReleaseProposal = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::ReleaseNotProposed)?;
+        if proposal.confirmations.contains(&signer) {
+            return Err(Error::AlreadyConfirmed);
+        }
+        proposal.confirmations.push_back(signer.clone());
+
+        // Signers dropped from the set since confirming no longer count
+        let confirmations = proposal
+            .confirmations
+            .iter()
+            .filter(|confirmed| config.signers.contains(confirmed))
+            .count() as u32;
+
+        events::emit_release_confirmed(
+            &env,
+            events::ReleaseConfirmed {
+                bounty_id,
+                signer,
+                confirmations,
+                threshold: config.threshold,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        if confirmations < config.threshold {
+            env.storage().persistent().set(&key, &proposal);
+            return Ok(false);
+        }
+
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+        let mut escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::check_contributor_eligibility(&env, bounty_id, &proposal.contributor)?;
+
+        env.storage().persistent().remove(&key);
+        let guarded = Self::enter_token_guard(&env, &escrow.token);
+        Self::release_escrow(&env, bounty_id, &mut escrow, &proposal.contributor);
+        Self::exit_token_guard(&env, guarded);
+        Ok(true)
+    }
+
+    /// Returns the open multisig release proposal for a bounty, if any.
+    pub fn get_release_proposal(env: Env, bounty_id: u64) -> Option<ReleaseProposal> {
+        env.storage()
+            .persistent()
+            .get(&MultisigKey::ReleaseProposal(bounty_id))
+    }
+
+    /// Approve a refund before deadline (admin only).
+    /// This allows early refunds with admin approval.
+    pub fn approve_refund(
+        env: Env,
+        bounty_id: u64,
+        amount: i128,
+        recipient: Address,
+        mode: RefundMode,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+
+        if amount <= 0 || amount > escrow.remaining_amount {
+            return Err(Error::InvalidAmount);
+        }
+
+        let approval = RefundApproval {
+            bounty_id,
+            amount,
+            recipient: recipient.clone(),
+            mode: mode.clone(),
+            approved_by: admin.clone(),
+            approved_at: env.ledger().timestamp(),
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::RefundApproval(bounty_id), &approval);
+
+        Ok(())
+    }
+
+    /// Refund funds with support for Full, Partial, and Custom refunds.
+    /// - Full: refunds all remaining funds to depositor
+    /// - Partial: refunds specified amount to depositor
+    /// - Custom: refunds specified amount to specified recipient (requires admin approval if before deadline)
+    ///
+    /// "Deadline" here includes the refund grace period, if one is set
+    /// (see `update_refund_grace`).
+    ///
+    /// On contracts initialized with `permissionless_refund = false` only
+    /// admin-approved early `Custom` refunds go through here; anything else
+    /// fails with `PermissionlessRefundDisabled` (see `refund_by_depositor`).
+    ///
+    /// An early attempt emits `RefundNotYetAvailable` before failing, but the
+    /// event is rolled back with the call; use `check_refund_available` to
+    /// record it on-chain.
+    pub fn refund(
+        env: Env,
+        bounty_id: u64,
+        amount: Option<i128>,
+        recipient: Option<Address>,
+        mode: RefundMode,
+    ) -> Result<(), Error> {
+        let start = env.ledger().timestamp();
+
+        // Check if contract is paused
+        if Self::is_paused_internal(&env) {
+            let caller = env.current_contract_address();
+            monitoring::track_operation(&env, symbol_short!("refund"), caller, false);
+            return Err(Error::ContractPaused);
+        }
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            let caller = env.current_contract_address();
+            monitoring::track_operation(&env, symbol_short!("refund"), caller, false);
+            return Err(Error::BountyNotFound);
+        }
+
+        // Get and verify escrow state
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        let caller = escrow.depositor.clone();
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+
+        // Verify deadline (plus any grace period) has passed
+        let now = env.ledger().timestamp();
+        let is_before_deadline = match Self::refund_unlocks_at(&env, &escrow) {
+            Ok(unlocks_at) => now < unlocks_at,
+            // An admin-approved custom refund does not wait for the deadline
+            Err(_) if mode == RefundMode::Custom => true,
+            Err(err) => return Err(err),
+        };
+
+        // Admin-approved early refunds are not permissionless, so they stay
+        let approved_early = mode == RefundMode::Custom && is_before_deadline;
+        if !approved_early && !Self::is_permissionless_refund(env.clone()) {
+            return Err(Error::PermissionlessRefundDisabled);
+        }
+
+        // Determine refund amount and recipient
+        let refund_amount: i128;
+        let refund_recipient: Address;
+
+        match mode {
+            RefundMode::Full => {
+                refund_amount = escrow.remaining_amount;
+                refund_recipient = escrow.depositor.clone();
+                if is_before_deadline {
+                    events::emit_refund_not_yet_available(
+                        &env,
+                        events::RefundNotYetAvailable {
+                            bounty_id,
+                            deadline: escrow.deadline,
+                            now,
+                        },
+                    );
+                    return Err(Error::DeadlineNotPassed);
+                }
+            }
+            RefundMode::Partial => {
+                refund_amount = amount.unwrap_or(escrow.remaining_amount);
+                refund_recipient = escrow.depositor.clone();
+                if is_before_deadline {
+                    events::emit_refund_not_yet_available(
+                        &env,
+                        events::RefundNotYetAvailable {
+                            bounty_id,
+                            deadline: escrow.deadline,
+                            now,
+                        },
+                    );
+                    return Err(Error::DeadlineNotPassed);
+                }
+            }
+            RefundMode::Custom => {
+                refund_amount = amount.ok_or(Error::InvalidAmount)?;
+                refund_recipient = recipient.ok_or(Error::InvalidAmount)?;
+                Self::check_recipient(&env, &refund_recipient)?;
+
+                // Custom refunds before deadline require admin approval
+                if is_before_deadline {
+                    if !env
+                        .storage()
+                        .persistent()
+                        .has(&DataKey::RefundApproval(bounty_id))
+                    {
+                        return Err(Error::RefundNotApproved);
+                    }
+                    let approval: RefundApproval = env
+                        .storage()
+                        .persistent()
+                        .get(&DataKey::RefundApproval(bounty_id))
+                        .unwrap();
 
-        // Check if contract is paused
+                    // Verify approval matches request
+                    if approval.amount != refund_amount
+                        || approval.recipient != refund_recipient
+                        || approval.mode != mode
+                    {
+                        return Err(Error::RefundNotApproved);
+                    }
+
+                    // Clear approval after use
+                    env.storage()
+                        .persistent()
+                        .remove(&DataKey::RefundApproval(bounty_id));
+                }
+            }
+        }
+
+        // Validate amount
+        if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
+            return Err(Error::InvalidAmount);
+        }
+
+        let reason = if now < escrow.deadline {
+            RefundReason::DepositorCancelled
+        } else {
+            RefundReason::DeadlinePassed
+        };
+
+        // Transfer funds back to depositor
+        let client = token::Client::new(&env, &escrow.token);
+
+        // Check contract balance
+        let contract_balance = client.balance(&env.current_contract_address());
+        if contract_balance < refund_amount {
+            return Err(Error::InsufficientFunds);
+        }
+
+        // Record the refund, then transfer funds
+        let guarded = Self::enter_token_guard(&env, &escrow.token);
+        let result = Self::refund_escrow(
+            &env,
+            &EscrowId::Num(bounty_id),
+            &mut escrow,
+            refund_amount,
+            &refund_recipient,
+            mode,
+            reason,
+        );
+        Self::exit_token_guard(&env, guarded);
+        result?;
+
+        // Track successful operation
+        monitoring::track_operation(&env, symbol_short!("refund"), caller, true);
+
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("refund"), duration);
+
+        Ok(())
+    }
+
+    /// Refunds an expired bounty in full on behalf of its depositor and pays
+    /// `keeper` the keeper fee the depositor opted into at lock time.
+    ///
+    /// Lets refund bots earn a small reward for running the permissionless
+    /// refund. The keeper's share is recorded in the refund history; the
+    /// refund penalty, if any, applies to the depositor's share only.
+    ///
+    /// # Returns
+    /// * `Ok(i128)` - Keeper fee paid (0 if the bounty did not opt in)
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Bounty already settled
+    /// * `Err(Error::DeadlineNotPassed)` - Deadline plus grace period not reached
+    /// * `Err(Error::PermissionlessRefundDisabled)` - Only depositors may refund
+    ///
+    /// # Events
+    /// Emits: `KeeperFeePaid` (when a fee is paid) and `FundsRefunded`
+    pub fn refund_as_keeper(env: Env, bounty_id: u64, keeper: Address) -> Result<i128, Error> {
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+        if !Self::is_permissionless_refund(env.clone()) {
+            return Err(Error::PermissionlessRefundDisabled);
+        }
+        keeper.require_auth();
+
+        let mut escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+        if env.ledger().timestamp() < Self::refund_unlocks_at(&env, &escrow)? {
+            return Err(Error::DeadlineNotPassed);
+        }
+        Self::check_recipient(&env, &keeper)?;
+
+        if env.storage().temporary().has(&DataKey::ReentrancyGuard) {
+            panic!("Reentrancy detected");
+        }
+        env.storage()
+            .temporary()
+            .set(&DataKey::ReentrancyGuard, &true);
+
+        let keeper_fee_bps: i128 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::KeeperFee(bounty_id))
+            .unwrap_or(0);
+        let keeper_fee = Self::calculate_fee(escrow.remaining_amount, keeper_fee_bps);
+        if keeper_fee > 0 {
+            Self::record_refund(
+                &env,
+                &mut escrow,
+                keeper_fee,
+                &keeper,
+                &RefundMode::Partial,
+                &RefundReason::DeadlinePassed,
+            );
+            events::emit_keeper_fee_paid(
+                &env,
+                events::KeeperFeePaid {
+                    bounty_id,
+                    keeper: keeper.clone(),
+                    amount: keeper_fee,
+                    keeper_fee_bps,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+
+        // refund_escrow stores the escrow, keeper share included, before
+        // its own token calls; the keeper is paid last
+        let depositor = escrow.depositor.clone();
+        let remaining = escrow.remaining_amount;
+        let result = Self::refund_escrow(
+            &env,
+            &EscrowId::Num(bounty_id),
+            &mut escrow,
+            remaining,
+            &depositor,
+            RefundMode::Full,
+            RefundReason::DeadlinePassed,
+        );
+        if result.is_ok() && keeper_fee > 0 {
+            token::Client::new(&env, &escrow.token).transfer(
+                &env.current_contract_address(),
+                &keeper,
+                &keeper_fee,
+            );
+        }
+
+        env.storage().temporary().remove(&DataKey::ReentrancyGuard);
+        result.map(|()| keeper_fee)
+    }
+
+    /// Refunds an expired bounty in full to its depositor, with the
+    /// depositor's authorization.
+    ///
+    /// Works in either refund mode, and is the only way to reclaim an
+    /// expired bounty when the contract was initialized with
+    /// `permissionless_refund = false`.
+    ///
+    /// # Returns
+    /// * `Ok(())` - Remaining funds returned to the depositor
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Bounty already settled
+    /// * `Err(Error::DeadlineNotPassed)` - Deadline plus grace period not reached
+    ///
+    /// # Events
+    /// Emits: `FundsRefunded`
+    pub fn refund_by_depositor(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        let mut escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        escrow.depositor.require_auth();
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+        if env.ledger().timestamp() < Self::refund_unlocks_at(&env, &escrow)? {
+            return Err(Error::DeadlineNotPassed);
+        }
+
+        if env.storage().temporary().has(&DataKey::ReentrancyGuard) {
+            panic!("Reentrancy detected");
+        }
+        env.storage()
+            .temporary()
+            .set(&DataKey::ReentrancyGuard, &true);
+
+        let depositor = escrow.depositor.clone();
+        let remaining = escrow.remaining_amount;
+        let result = Self::refund_escrow(
+            &env,
+            &EscrowId::Num(bounty_id),
+            &mut escrow,
+            remaining,
+            &depositor,
+            RefundMode::Full,
+            RefundReason::DeadlinePassed,
+        );
+
+        env.storage().temporary().remove(&DataKey::ReentrancyGuard);
+        result
+    }
+
+    /// Refunds an unsettled bounty in full to its depositor before its
+    /// deadline (admin only).
+    ///
+    /// Escape hatch for emergencies such as a token migration or a
+    /// compromised recipient. The admin can cancel any bounty at will with
+    /// this, so depositors are trusting the admin not to; contributors lose
+    /// any expectation of payment the moment it is called. Funds only ever
+    /// go back to the recorded depositor, no refund penalty is withheld, and
+    /// it works while the contract is paused. Released and fully refunded
+    /// escrows cannot be touched.
+    ///
+    /// # Returns
+    /// * `Ok(())` - Remaining funds returned to the depositor
+    /// * `Err(Error::NotInitialized)` - No admin is set
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Bounty already released or refunded
+    ///
+    /// # Events
+    /// Emits: `FundsRefunded`, then `AdminRefund { bounty_id, admin, amount, timestamp }`
+    pub fn admin_refund(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let mut escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+
+        if env.storage().temporary().has(&DataKey::ReentrancyGuard) {
+            panic!("Reentrancy detected");
+        }
+        env.storage()
+            .temporary()
+            .set(&DataKey::ReentrancyGuard, &true);
+
+        let depositor = escrow.depositor.clone();
+        let remaining = escrow.remaining_amount;
+        let result = Self::refund_escrow(
+            &env,
+            &EscrowId::Num(bounty_id),
+            &mut escrow,
+            remaining,
+            &depositor,
+            RefundMode::Full,
+            RefundReason::AdminForced,
+        );
+
+        env.storage().temporary().remove(&DataKey::ReentrancyGuard);
+        result?;
+
+        events::emit_admin_refund(
+            &env,
+            events::AdminRefund {
+                bounty_id,
+                admin,
+                amount: remaining,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    // ========================================================================
+    // Contributor Eligibility
+    // ========================================================================
+
+    /// Configure which contributor gates `release_funds` enforces (admin only).
+    pub fn update_eligibility_config(
+        env: Env,
+        require_registration: bool,
+        require_approval: bool,
+        terms_version: u32,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        env.storage().instance().set(
+            &DataKey::EligibilityConfig,
+            &EligibilityConfig {
+                require_registration,
+                require_approval,
+                terms_version,
+            },
+        );
+        Ok(())
+    }
+
+    /// Get current eligibility configuration
+    pub fn get_eligibility_config(env: Env) -> EligibilityConfig {
+        Self::get_eligibility_config_internal(&env)
+    }
+
+    /// Register the caller as a contributor.
+    pub fn register_contributor(env: Env, contributor: Address) {
+        contributor.require_auth();
+        env.storage()
+            .persistent()
+            .set(&DataKey::ContributorRegistered(contributor), &true);
+    }
+
+    /// Accept the current terms version on behalf of the caller.
+    ///
+    /// # Returns
+    /// * `Ok(())` - Acceptance recorded
+    /// * `Err(Error::TermsNotAccepted)` - `version` is not the current terms version
+    pub fn accept_terms(env: Env, contributor: Address, version: u32) -> Result<(), Error> {
+        contributor.require_auth();
+
+        let config = Self::get_eligibility_config_internal(&env);
+        if version == 0 || version != config.terms_version {
+            return Err(Error::TermsNotAccepted);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::TermsAccepted(contributor), &version);
+        Ok(())
+    }
+
+    /// Add or remove a contributor from the payout denylist (admin only).
+    pub fn set_contributor_denied(
+        env: Env,
+        contributor: Address,
+        denied: bool,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let key = DataKey::ContributorDenied(contributor);
+        if denied {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        Ok(())
+    }
+
+    /// Records who is working on a locked bounty (admin only).
+    ///
+    /// With `strict` set, `release_funds` rejects any other recipient with
+    /// `ContributorMismatch`. Reassigning replaces the previous assignment,
+    /// restarts the auto-release review window and clears any veto.
+    ///
+    /// # Returns
+    /// * `Ok(())` - Assignment stored
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::FundsNotLocked)` - Bounty is no longer locked
+    ///
+    /// # Events
+    /// Emits: `ContributorAssigned { bounty_id, contributor, strict, timestamp }`
+    pub fn assign_contributor(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        strict: bool,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let timestamp = env.ledger().timestamp();
+        env.storage()
+            .persistent()
+            .remove(&DataKey::AutoReleaseVetoed(bounty_id));
+        env.storage().persistent().set(
+            &DataKey::AssignedContributor(bounty_id),
+            &ContributorAssignment {
+                contributor: contributor.clone(),
+                strict,
+                assigned_at: timestamp,
+            },
+        );
+
+        events::emit_contributor_assigned(
+            &env,
+            events::ContributorAssigned {
+                bounty_id,
+                contributor,
+                strict,
+                timestamp,
+            },
+        );
+        Ok(())
+    }
+
+    /// Returns the contributor assigned to a bounty, if any.
+    pub fn get_assigned_contributor(env: Env, bounty_id: u64) -> Option<ContributorAssignment> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AssignedContributor(bounty_id))
+    }
+
+    /// Releases a bounty to its assigned contributor (admin only).
+    ///
+    /// # Returns
+    /// * `Err(Error::NoContributorAssigned)` - Bounty has no assignment
+    /// * Otherwise as for `release_funds`
+    pub fn release_to_assigned(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let assignment = Self::get_assigned_contributor(env.clone(), bounty_id)
+            .ok_or(Error::NoContributorAssigned)?;
+        Self::release_funds(env, bounty_id, assignment.contributor)
+    }
+
+    /// Set how long after assignment a bounty becomes auto-releasable
+    /// (admin only). A window of 0 disables auto-release.
+    pub fn update_review_window(env: Env, review_window: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::ReviewWindow, &review_window);
+        Ok(())
+    }
+
+    /// Get the auto-release review window in seconds (0 = disabled)
+    pub fn get_review_window(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ReviewWindow)
+            .unwrap_or(0)
+    }
+
+    /// Blocks auto-release of a bounty until it is reassigned (admin only).
+    ///
+    /// The admin can still release manually with `release_funds`.
+    pub fn veto(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        Self::get_escrow_info(env.clone(), bounty_id)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::AutoReleaseVetoed(bounty_id), &true);
+        Ok(())
+    }
+
+    /// Releases a bounty to its assigned contributor once the review window
+    /// has passed without a veto. Anyone may call this.
+    ///
+    /// # Returns
+    /// * `Ok(())` - Funds released to the assigned contributor
+    /// * `Err(Error::NoContributorAssigned)` - Bounty has no assignment
+    /// * `Err(Error::AutoReleaseNotConfigured)` - Review window is 0
+    /// * `Err(Error::AutoReleaseVetoed)` - Admin vetoed the auto-release
+    /// * `Err(Error::ReviewWindowOpen)` - `assigned_at + review_window` not reached
+    /// * `Err(Error::TimeOverflow)` - `assigned_at + review_window` overflows
+    /// * `Err(Error::FundsNotLocked)` - Bounty already settled
+    /// * Eligibility errors as for `release_funds`
+    pub fn finalize_auto_release(env: Env, bounty_id: u64) -> Result<(), Error> {
         if Self::is_paused_internal(&env) {
-            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
             return Err(Error::ContractPaused);
         }
 
-        // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, admin.clone());
+        let assignment = Self::get_assigned_contributor(env.clone(), bounty_id)
+            .ok_or(Error::NoContributorAssigned)?;
+        let review_window = Self::get_review_window(env.clone());
+        if review_window == 0 {
+            return Err(Error::AutoReleaseNotConfigured);
+        }
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::AutoReleaseVetoed(bounty_id))
+        {
+            return Err(Error::AutoReleaseVetoed);
+        }
+        let review_ends = assignment
+            .assigned_at
+            .checked_add(review_window)
+            .ok_or(Error::TimeOverflow)?;
+        if env.ledger().timestamp() < review_ends {
+            return Err(Error::ReviewWindowOpen);
+        }
 
-        admin.require_auth();
+        let mut escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::check_multisig_not_required(&env, &escrow)?;
+        Self::check_contributor_eligibility(&env, bounty_id, &assignment.contributor)?;
 
-        // Verify bounty exists
+        if env.storage().temporary().has(&DataKey::ReentrancyGuard) {
+            panic!("Reentrancy detected");
+        }
+        env.storage()
+            .temporary()
+            .set(&DataKey::ReentrancyGuard, &true);
+
+        Self::release_escrow(&env, bounty_id, &mut escrow, &assignment.contributor);
+
+        env.storage().temporary().remove(&DataKey::ReentrancyGuard);
+        Ok(())
+    }
+
+    /// Checks whether `contributor` could be paid for `bounty_id` right now.
+    ///
+    /// Runs the same gates as `release_funds`: denylist, strict assignment,
+    /// registration, terms acceptance and release approval, as enabled by
+    /// `EligibilityConfig`.
+    ///
+    /// # Returns
+    /// * `Ok(true)` - Every enabled gate passes
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::ContributorDenied)` - Contributor is denylisted
+    /// * `Err(Error::ContributorMismatch)` - Bounty is strictly assigned to someone else
+    /// * `Err(Error::ContributorNotRegistered)` - Registration required but missing
+    /// * `Err(Error::TermsNotAccepted)` - Current terms not accepted
+    /// * `Err(Error::ReleaseNotApproved)` - Approval required but missing or for someone else
+    pub fn contributor_eligible(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+    ) -> Result<bool, Error> {
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
             return Err(Error::BountyNotFound);
         }
+        Self::check_contributor_eligibility(&env, bounty_id, &contributor)?;
+        Ok(true)
+    }
+
+    /// Number of bounties fully paid out to `contributor`, across every
+    /// release path. A bounty paid in milestones counts once, when its last
+    /// milestone is released.
+    pub fn get_contributor_payout_count(env: Env, contributor: Address) -> u64 {
+        Self::get_contributor_stats(&env, &contributor).payout_count
+    }
+
+    /// Lifetime net amount (after release fees) paid to `contributor` in
+    /// the configured token. Payouts in other allowed tokens are not summed.
+    pub fn get_contributor_total_received(env: Env, contributor: Address) -> i128 {
+        Self::get_contributor_stats(&env, &contributor).total_received
+    }
+
+    // ========================================================================
+    // Dispute Resolution
+    // ========================================================================
+
+    /// Lock funds for a bounty and link it to an external dispute resolver.
+    ///
+    /// Behaves exactly like [`lock_funds`](Self::lock_funds) and additionally
+    /// records `resolver` as the arbitration contract for this bounty. See the
+    /// `dispute` module for the interface the resolver must implement.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `depositor` - Address depositing the funds (must authorize)
+    /// * `bounty_id` - Unique identifier for this bounty
+    /// * `amount` - Token amount to lock
+    /// * `deadline` - Unix timestamp after which refund is allowed
+    /// * `resolver` - Contract implementing `resolve(bounty_id) -> ResolutionOutcome`
+    ///
+    /// # Returns
+    /// Same as `lock_funds`.
+    pub fn lock_funds_with_resolver(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        resolver: Address,
+    ) -> Result<(), Error> {
+        Self::lock_funds(env.clone(), depositor, bounty_id, amount, deadline)?;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::DisputeResolver(bounty_id), &resolver);
+
+        Ok(())
+    }
+
+    /// Lock funds for a bounty and opt in to paying a keeper for its refund.
+    ///
+    /// Behaves exactly like [`lock_funds`](Self::lock_funds) and additionally
+    /// fixes the current `get_keeper_fee` rate for this bounty. Once refunds
+    /// unlock, whoever calls `refund_as_keeper` receives that share of the
+    /// remaining balance and the depositor the rest. Bounties locked any
+    /// other way are never charged a keeper fee.
+    ///
+    /// # Returns
+    /// Same as `lock_funds`.
+    pub fn lock_funds_with_keeper_fee(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        Self::lock_funds(env.clone(), depositor, bounty_id, amount, deadline)?;
+
+        let keeper_fee_bps = Self::get_keeper_fee(env.clone());
+        if keeper_fee_bps > 0 {
+            env.storage()
+                .persistent()
+                .set(&DataKey::KeeperFee(bounty_id), &keeper_fee_bps);
+        }
+
+        Ok(())
+    }
+
+    /// Raise a dispute on a locked bounty and settle it via its resolver.
+    ///
+    /// The linked resolver is asked for a verdict; a `Release(contributor)`
+    /// outcome pays the escrow out to `contributor` (release fee applies),
+    /// while `Refund` returns the remaining funds to the depositor.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The disputed bounty
+    /// * `caller` - Depositor or admin raising the dispute (must authorize)
+    ///
+    /// # Returns
+    /// * `Ok(ResolutionOutcome)` - The verdict that was applied
+    /// * `Err(Error::ContractPaused)` - Contract is paused
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::Unauthorized)` - Caller is neither depositor nor admin
+    /// * `Err(Error::FundsNotLocked)` - Escrow already settled
+    /// * `Err(Error::ResolverNotSet)` - Bounty was locked without a resolver
+    /// * Any contributor eligibility error (see `release_funds`) when the
+    ///   verdict releases to an ineligible contributor
+    ///
+    /// # Events
+    /// Emits `FundsReleased` or `FundsRefunded`, followed by `DisputeResolved`.
+    pub fn raise_dispute(
+        env: Env,
+        bounty_id: u64,
+        caller: Address,
+    ) -> Result<ResolutionOutcome, Error> {
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        caller.require_auth();
 
-        // Get and verify escrow state
         let mut escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+            .ok_or(Error::BountyNotFound)?;
 
-        if escrow.status != EscrowStatus::Locked {
-            monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        if caller != escrow.depositor && caller != admin {
+            return Err(Error::Unauthorized);
+        }
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
             return Err(Error::FundsNotLocked);
         }
 
-        // Transfer funds to contributor
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        escrow.status = EscrowStatus::Released;
+        let resolver: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DisputeResolver(bounty_id))
+            .ok_or(Error::ResolverNotSet)?;
+
+        if env.storage().temporary().has(&DataKey::ReentrancyGuard) {
+            panic!("Reentrancy detected");
+        }
+        env.storage()
+            .temporary()
+            .set(&DataKey::ReentrancyGuard, &true);
+
+        let outcome = DisputeResolverClient::new(&env, &resolver).resolve(&bounty_id);
+
+        let settled = match &outcome {
+            ResolutionOutcome::Release(contributor) => {
+                Self::check_contributor_eligibility(&env, bounty_id, contributor).map(|()| {
+                    Self::release_escrow(&env, bounty_id, &mut escrow, contributor);
+                })
+            }
+            ResolutionOutcome::Refund => {
+                let depositor = escrow.depositor.clone();
+                let amount = escrow.remaining_amount;
+                Self::refund_escrow(
+                    &env,
+                    &EscrowId::Num(bounty_id),
+                    &mut escrow,
+                    amount,
+                    &depositor,
+                    RefundMode::Full,
+                    RefundReason::DisputeResolved,
+                )
+            }
+        };
+        if let Err(err) = settled {
+            env.storage().temporary().remove(&DataKey::ReentrancyGuard);
+            return Err(err);
+        }
+
         env.storage()
             .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+            .set(&DataKey::DisputeOutcome(bounty_id), &outcome);
 
-        // Calculate and collect fee if enabled
-        let fee_config = Self::get_fee_config_internal(&env);
-        let fee_amount = if fee_config.fee_enabled && fee_config.release_fee_rate > 0 {
-            Self::calculate_fee(escrow.amount, fee_config.release_fee_rate)
-        } else {
-            0
+        events::emit_dispute_resolved(
+            &env,
+            events::DisputeResolved {
+                bounty_id,
+                resolver,
+                raised_by: caller,
+                outcome: outcome.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        env.storage().temporary().remove(&DataKey::ReentrancyGuard);
+
+        Ok(outcome)
+    }
+
+    /// Returns the dispute resolver linked to a bounty, if any.
+    pub fn get_dispute_resolver(env: Env, bounty_id: u64) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DisputeResolver(bounty_id))
+    }
+
+    /// Returns the full context of a bounty for dispute dashboards.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to query
+    ///
+    /// # Returns
+    /// * `Ok(DisputeContext)` - Escrow fields plus contributor and dispute state
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    pub fn get_dispute_context(env: Env, bounty_id: u64) -> Result<DisputeContext, Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        let resolver = Self::get_dispute_resolver(env.clone(), bounty_id);
+        let outcome: Option<ResolutionOutcome> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DisputeOutcome(bounty_id));
+        let contributor = match &outcome {
+            Some(ResolutionOutcome::Release(contributor)) => Some(contributor.clone()),
+            _ => Self::get_release_approval(env.clone(), bounty_id).map(|a| a.contributor),
+        };
+        let dispute_state = match (outcome, &resolver) {
+            (Some(outcome), _) => DisputeState::Resolved(outcome),
+            (None, Some(_)) => DisputeState::Open,
+            (None, None) => DisputeState::NoResolver,
         };
-        let net_amount = escrow.amount - fee_amount;
 
-        // Transfer net amount to contributor
-        client.transfer(&env.current_contract_address(), &contributor, &net_amount);
+        Ok(DisputeContext {
+            bounty_id,
+            depositor: escrow.depositor,
+            amount: escrow.amount,
+            remaining_amount: escrow.remaining_amount,
+            status: escrow.status,
+            deadline: escrow.deadline,
+            created_at: escrow.created_at,
+            contributor,
+            resolver,
+            dispute_state,
+            refund_history: escrow.refund_history,
+        })
+    }
 
-        // Transfer fee to fee recipient if applicable
-        if fee_amount > 0 {
-            client.transfer(
-                &env.current_contract_address(),
-                &fee_config.fee_recipient,
-                &fee_amount,
-            );
-            events::emit_fee_collected(
-                &env,
-                events::FeeCollected {
-                    operation_type: events::FeeOperationType::Release,
-                    amount: fee_amount,
-                    fee_rate: fee_config.release_fee_rate,
-                    recipient: fee_config.fee_recipient.clone(),
-                    timestamp: env.ledger().timestamp(),
-                },
-            );
+    // ========================================================================
+    // Refund Token Swaps
+    // ========================================================================
+
+    /// Set the AMM used to convert refunds into depositor-chosen tokens (admin only).
+    ///
+    /// See the `amm` module for the interface the AMM must implement.
+    pub fn set_amm(env: Env, amm: Address) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Amm, &amm);
+        Ok(())
+    }
+
+    /// Lock funds and have any refund to the depositor paid in `refund_token`.
+    ///
+    /// Behaves like [`lock_funds`](Self::lock_funds). On refund, the escrowed
+    /// tokens are swapped to `refund_token` through the configured AMM, which
+    /// must deliver at least `min_amount_out` for a full refund (pro rata for
+    /// partial ones) or the refund fails with `SlippageExceeded`. If the AMM
+    /// is gone or the swap reverts, the refund is paid in the escrowed token.
+    ///
+    /// # Returns
+    /// * Same as `lock_funds`, plus:
+    /// * `Err(Error::AmmNotSet)` - No AMM has been configured
+    /// * `Err(Error::InvalidAmount)` - `min_amount_out` is negative
+    pub fn lock_funds_with_refund_token(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        refund_token: Address,
+        min_amount_out: i128,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Amm) {
+            return Err(Error::AmmNotSet);
+        }
+        if min_amount_out < 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        Self::lock_funds(env.clone(), depositor, bounty_id, amount, deadline)?;
+
+        env.storage().persistent().set(
+            &DataKey::RefundSwap(bounty_id),
+            &RefundSwapConfig {
+                refund_token,
+                min_amount_out,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Returns the refund swap configuration for a bounty, if any.
+    pub fn get_refund_swap_config(env: Env, bounty_id: u64) -> Option<RefundSwapConfig> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RefundSwap(bounty_id))
+    }
+
+    // ========================================================================
+    // Oracle Milestones
+    // ========================================================================
+
+    /// Lock funds for a performance-based bounty paid out in oracle-verified tranches.
+    ///
+    /// The locked amount is the sum of all milestone amounts. Each tranche is
+    /// released to `contributor` once `check_milestone` finds its metric met.
+    /// Anything left unreleased can still be refunded after the deadline or
+    /// released early by the admin via `release_funds`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `depositor` - Address depositing the funds (must authorize)
+    /// * `contributor` - Recipient of released tranches
+    /// * `bounty_id` - Unique identifier for this bounty
+    /// * `milestones` - `(amount, metric)` pairs, one per tranche
+    /// * `oracle` - Contract implementing `is_met(bounty_id, metric) -> bool`
+    /// * `deadline` - Unix timestamp after which refund is allowed
+    ///
+    /// # Returns
+    /// * Same as `lock_funds`, plus:
+    /// * `Err(Error::InvalidAmount)` - No milestones, or a non-positive tranche
+    pub fn lock_with_milestones_oracle(
+        env: Env,
+        depositor: Address,
+        contributor: Address,
+        bounty_id: u64,
+        milestones: Vec<(i128, Symbol)>,
+        oracle: Address,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        if milestones.is_empty() {
+            return Err(Error::InvalidAmount);
+        }
+        Self::check_recipient(&env, &contributor)?;
+
+        let mut total: i128 = 0;
+        let mut plan = Vec::new(&env);
+        for (amount, metric) in milestones.iter() {
+            if amount <= 0 {
+                return Err(Error::InvalidAmount);
+            }
+            total = total.checked_add(amount).ok_or(Error::InvalidAmount)?;
+            plan.push_back(Milestone {
+                amount,
+                metric,
+                released: false,
+            });
+        }
+
+        Self::lock_funds(env.clone(), depositor, bounty_id, total, deadline)?;
+
+        env.storage().persistent().set(
+            &DataKey::Milestones(bounty_id),
+            &MilestonePlan {
+                oracle,
+                contributor,
+                milestones: plan,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Ask the oracle whether a milestone is met and release its tranche if so.
+    ///
+    /// Callable by anyone; the oracle is the authority on whether funds move.
+    /// The final tranche marks the escrow `Released`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The milestone bounty
+    /// * `index` - Position of the milestone in the plan
+    ///
+    /// # Returns
+    /// * `Ok(true)` - Milestone met; tranche paid to the contributor
+    /// * `Ok(false)` - Oracle reports the milestone as not yet met
+    /// * `Err(Error::ContractPaused)` - Contract is paused
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::MilestoneNotFound)` - No plan, or `index` out of range
+    /// * `Err(Error::MilestoneAlreadyReleased)` - Tranche already paid
+    /// * `Err(Error::FundsNotLocked)` - Escrow already settled
+    /// * Any contributor eligibility error (see `release_funds`)
+    ///
+    /// # Events
+    /// Emits: `MilestoneReleased { bounty_id, index, amount, recipient, timestamp }`
+    pub fn check_milestone(env: Env, bounty_id: u64, index: u32) -> Result<bool, Error> {
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        let mut plan: MilestonePlan = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Milestones(bounty_id))
+            .ok_or(Error::MilestoneNotFound)?;
+        let mut milestone = plan.milestones.get(index).ok_or(Error::MilestoneNotFound)?;
+
+        if milestone.released {
+            return Err(Error::MilestoneAlreadyReleased);
         }
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::check_contributor_eligibility(&env, bounty_id, &plan.contributor)?;
 
-        // Update escrow state - mark as released and set remaining_amount to 0
-        escrow.status = EscrowStatus::Released;
-        escrow.remaining_amount = 0;
+        if env.storage().temporary().has(&DataKey::ReentrancyGuard) {
+            panic!("Reentrancy detected");
+        }
+        env.storage()
+            .temporary()
+            .set(&DataKey::ReentrancyGuard, &true);
+
+        let met =
+            MilestoneOracleClient::new(&env, &plan.oracle).is_met(&bounty_id, &milestone.metric);
+        if !met {
+            env.storage().temporary().remove(&DataKey::ReentrancyGuard);
+            return Ok(false);
+        }
+
+        // A lock fee can leave slightly less than the plan total in escrow
+        let amount = milestone.amount.min(escrow.remaining_amount);
+
+        // Effects: mark the tranche and update the escrow before paying it
+        milestone.released = true;
+        plan.milestones.set(index, milestone);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Milestones(bounty_id), &plan);
+
+        escrow.remaining_amount -= amount;
+        Self::adjust_total_locked(&env, &escrow.token, -amount);
+        Self::add_to_settled(&env, &escrow.token, DataKey::TotalReleased, amount);
+        if plan.milestones.iter().all(|m| m.released) || escrow.remaining_amount == 0 {
+            Self::clear_release_approval(&env, bounty_id);
+            Self::adjust_total_locked(&env, &escrow.token, -escrow.remaining_amount);
+            Self::adjust_active_bounties(&env, &escrow.depositor, false);
+            escrow.remaining_amount = 0;
+            escrow.status = EscrowStatus::Released;
+        }
+        Self::record_contributor_payout(
+            &env,
+            &escrow.token,
+            &plan.contributor,
+            amount,
+            escrow.status == EscrowStatus::Released,
+        );
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
 
-        // Emit release event
-        emit_funds_released(
+        // Interactions
+        token::Client::new(&env, &escrow.token).transfer(
+            &env.current_contract_address(),
+            &plan.contributor,
+            &amount,
+        );
+
+        events::emit_milestone_released(
             &env,
-            FundsReleased {
+            events::MilestoneReleased {
                 bounty_id,
-                amount: net_amount, // Emit net amount (after fee)
-                recipient: contributor.clone(),
+                index,
+                amount,
+                recipient: plan.contributor,
                 timestamp: env.ledger().timestamp(),
             },
         );
 
-        env.storage().instance().remove(&DataKey::ReentrancyGuard);
+        env.storage().temporary().remove(&DataKey::ReentrancyGuard);
 
-        // Track successful operation
-        monitoring::track_operation(&env, symbol_short!("release"), admin, true);
+        Ok(true)
+    }
 
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("release"), duration);
-        Ok(())
+    /// Returns the milestone plan for a bounty, if it was locked with one.
+    pub fn get_milestones(env: Env, bounty_id: u64) -> Option<MilestonePlan> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Milestones(bounty_id))
     }
 
-    /// Approve a refund before deadline (admin only).
-    /// This allows early refunds with admin approval.
-    pub fn approve_refund(
+    // ========================================================================
+    // String-keyed Bounties
+    // ========================================================================
+
+    /// Locks funds for a bounty keyed by a string, such as an issue URL,
+    /// `"repo#42"` or a UUID.
+    ///
+    /// String IDs live in their own namespace, so `"42"` and `42` are
+    /// different bounties. Validation, fees, caps and rate limits match
+    /// `lock_funds`.
+    ///
+    /// # Errors
+    /// * Same as `lock_funds`
+    pub fn lock_funds_str(
         env: Env,
-        bounty_id: u64,
+        depositor: Address,
+        bounty_id: String,
         amount: i128,
-        recipient: Address,
-        mode: RefundMode,
+        deadline: u64,
     ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+        anti_abuse::check_rate_limit(&env, depositor.clone());
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
         }
+        depositor.require_auth();
 
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+        Self::validate_lock(&env, amount, deadline)?;
+        let key = DataKey::EscrowStr(bounty_id.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(Error::BountyExists);
+        }
+        if !Self::is_depositor_allowed(env.clone(), depositor.clone()) {
+            return Err(Error::DepositorNotAllowed);
+        }
+        Self::check_active_limit(&env, &depositor, 1)?;
+        if !anti_abuse::record_lock(&env, &depositor) {
+            return Err(Error::RateLimited);
+        }
 
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+        if env.storage().temporary().has(&DataKey::ReentrancyGuard) {
+            panic!("Reentrancy detected");
         }
+        env.storage()
+            .temporary()
+            .set(&DataKey::ReentrancyGuard, &true);
 
-        let escrow: Escrow = env
+        let token = Self::default_token(&env);
+        let received = match Self::collect_deposit(&env, &token, &depositor, amount) {
+            Ok(received) => received,
+            Err(err) => {
+                env.storage().temporary().remove(&DataKey::ReentrancyGuard);
+                return Err(err);
+            }
+        };
+
+        let escrow = Escrow {
+            depositor: depositor.clone(),
+            amount: received,
+            status: EscrowStatus::Locked,
+            deadline,
+            refund_history: vec![&env],
+            remaining_amount: received,
+            created_at: env.ledger().timestamp(),
+            token: token.clone(),
+        };
+        env.storage().persistent().set(&key, &escrow);
+        Self::adjust_total_locked(&env, &token, received);
+        Self::adjust_active_bounties(&env, &depositor, true);
+
+        events::emit_funds_locked_str(
+            &env,
+            events::FundsLockedStr {
+                bounty_id,
+                amount: received,
+                depositor,
+                deadline,
+            },
+        );
+
+        env.storage().temporary().remove(&DataKey::ReentrancyGuard);
+        Ok(())
+    }
+
+    /// Releases a string-keyed bounty to `contributor` (admin only).
+    ///
+    /// Contributor denylist, registration and terms gates apply as for
+    /// `release_funds`. Release approvals and assignments are numeric-only,
+    /// so with `require_approval` enabled string bounties cannot be released.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - No escrow under this string ID
+    /// * `FundsNotLocked` - Escrow already released or refunded
+    /// * `ReleaseNotApproved` - Release approvals are required
+    /// * Eligibility errors as for `release_funds`
+    pub fn release_funds_str(
+        env: Env,
+        bounty_id: String,
+        contributor: Address,
+    ) -> Result<(), Error> {
+        let admin: Address = env
             .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+        anti_abuse::check_rate_limit(&env, admin.clone());
+        admin.require_auth();
 
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
+        let key = DataKey::EscrowStr(bounty_id.clone());
+        let mut escrow = Self::get_escrow_info_str(env.clone(), bounty_id.clone())?;
+        if escrow.status != EscrowStatus::Locked {
             return Err(Error::FundsNotLocked);
         }
+        Self::check_multisig_not_required(&env, &escrow)?;
+        Self::check_contributor_standing(&env, &contributor)?;
+        if Self::get_eligibility_config_internal(&env).require_approval {
+            return Err(Error::ReleaseNotApproved);
+        }
 
-        if amount <= 0 || amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
+        if env.storage().temporary().has(&DataKey::ReentrancyGuard) {
+            panic!("Reentrancy detected");
         }
+        env.storage()
+            .temporary()
+            .set(&DataKey::ReentrancyGuard, &true);
 
-        let approval = RefundApproval {
-            bounty_id,
-            amount,
-            recipient: recipient.clone(),
-            mode: mode.clone(),
-            approved_by: admin.clone(),
-            approved_at: env.ledger().timestamp(),
-        };
+        let net_amount = Self::pay_out_escrow(&env, &mut escrow, &contributor);
+        env.storage().persistent().set(&key, &escrow);
 
-        env.storage()
-            .persistent()
-            .set(&DataKey::RefundApproval(bounty_id), &approval);
+        events::emit_funds_released_str(
+            &env,
+            events::FundsReleasedStr {
+                bounty_id,
+                amount: net_amount,
+                recipient: contributor,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
 
+        env.storage().temporary().remove(&DataKey::ReentrancyGuard);
         Ok(())
     }
 
-    /// Refund funds with support for Full, Partial, and Custom refunds.
-    /// - Full: refunds all remaining funds to depositor
-    /// - Partial: refunds specified amount to depositor
-    /// - Custom: refunds specified amount to specified recipient (requires admin approval if before deadline)
-    pub fn refund(
+    /// Refunds a string-keyed bounty to its depositor after the deadline.
+    ///
+    /// Supports `Full` and `Partial` modes; the refund penalty applies as
+    /// for `refund`. Early `Custom` refunds need an admin approval, which
+    /// only exists for numeric bounties, so they are rejected.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - No escrow under this string ID
+    /// * `FundsNotLocked` - Escrow already released or fully refunded
+    /// * `DeadlineNotPassed` - Deadline not yet reached
+    /// * `RefundNotApproved` - `Custom` mode requested
+    /// * `InvalidAmount` - Amount is not positive or exceeds what remains
+    /// * `PermissionlessRefundDisabled` - Only depositors may refund
+    pub fn refund_str(
         env: Env,
-        bounty_id: u64,
+        bounty_id: String,
         amount: Option<i128>,
-        recipient: Option<Address>,
         mode: RefundMode,
     ) -> Result<(), Error> {
-        let start = env.ledger().timestamp();
-
-        // Check if contract is paused
         if Self::is_paused_internal(&env) {
-            let caller = env.current_contract_address();
-            monitoring::track_operation(&env, symbol_short!("refund"), caller, false);
             return Err(Error::ContractPaused);
         }
-
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            let caller = env.current_contract_address();
-            monitoring::track_operation(&env, symbol_short!("refund"), caller, false);
-            env.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(Error::BountyNotFound);
+        if !Self::is_permissionless_refund(env.clone()) {
+            return Err(Error::PermissionlessRefundDisabled);
         }
 
-        // Get and verify escrow state
-        let mut escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        let caller = escrow.depositor.clone();
-
+        let mut escrow = Self::get_escrow_info_str(env.clone(), bounty_id.clone())?;
         if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
         {
             return Err(Error::FundsNotLocked);
         }
-
-        // Verify deadline has passed
-        let now = env.ledger().timestamp();
-        let is_before_deadline = now < escrow.deadline;
-
-        // Determine refund amount and recipient
-        let refund_amount: i128;
-        let refund_recipient: Address;
-
-        match mode {
-            RefundMode::Full => {
-                refund_amount = escrow.remaining_amount;
-                refund_recipient = escrow.depositor.clone();
-                if is_before_deadline {
-                    return Err(Error::DeadlineNotPassed);
-                }
-            }
-            RefundMode::Partial => {
-                refund_amount = amount.unwrap_or(escrow.remaining_amount);
-                refund_recipient = escrow.depositor.clone();
-                if is_before_deadline {
-                    return Err(Error::DeadlineNotPassed);
-                }
-            }
-            RefundMode::Custom => {
-                refund_amount = amount.ok_or(Error::InvalidAmount)?;
-                refund_recipient = recipient.ok_or(Error::InvalidAmount)?;
-
-                // Custom refunds before deadline require admin approval
-                if is_before_deadline {
-                    if !env
-                        .storage()
-                        .persistent()
-                        .has(&DataKey::RefundApproval(bounty_id))
-                    {
-                        return Err(Error::RefundNotApproved);
-                    }
-                    let approval: RefundApproval = env
-                        .storage()
-                        .persistent()
-                        .get(&DataKey::RefundApproval(bounty_id))
-                        .unwrap();
-
-                    // Verify approval matches request
-                    if approval.amount != refund_amount
-                        || approval.recipient != refund_recipient
-                        || approval.mode != mode
-                    {
-                        return Err(Error::RefundNotApproved);
-                    }
-
-                    // Clear approval after use
-                    env.storage()
-                        .persistent()
-                        .remove(&DataKey::RefundApproval(bounty_id));
-                }
-            }
+        let refund_amount = match mode {
+            RefundMode::Full => escrow.remaining_amount,
+            RefundMode::Partial => amount.unwrap_or(escrow.remaining_amount),
+            RefundMode::Custom => return Err(Error::RefundNotApproved),
+        };
+        if env.ledger().timestamp() < Self::refund_unlocks_at(&env, &escrow)? {
+            return Err(Error::DeadlineNotPassed);
         }
-
-        // Validate amount
         if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
             return Err(Error::InvalidAmount);
         }
 
-        // Transfer funds back to depositor
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-
-        // Check contract balance
-        let contract_balance = client.balance(&env.current_contract_address());
-        if contract_balance < refund_amount {
-            return Err(Error::InsufficientFunds);
-        }
-
-        // Transfer funds
-        client.transfer(
-            &env.current_contract_address(),
-            &refund_recipient,
-            &refund_amount,
-        );
-
-        // Update escrow state
-        escrow.remaining_amount -= refund_amount;
-
-        // Add to refund history
-        let refund_record = RefundRecord {
-            amount: refund_amount,
-            recipient: refund_recipient.clone(),
-            mode: mode.clone(),
-            timestamp: env.ledger().timestamp(),
-        };
-        escrow.refund_history.push_back(refund_record);
-
-        // Update status
-        if escrow.remaining_amount == 0 {
-            escrow.status = EscrowStatus::Refunded;
-        } else {
-            escrow.status = EscrowStatus::PartiallyRefunded;
-        }
-
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
-
-        // Emit refund event
-        emit_funds_refunded(
+        let guarded = Self::enter_token_guard(&env, &escrow.token);
+        let depositor = escrow.depositor.clone();
+        let result = Self::refund_escrow(
             &env,
-            FundsRefunded {
-                bounty_id,
-                amount: refund_amount,
-                refund_to: refund_recipient,
-                timestamp: env.ledger().timestamp(),
-                refund_mode: mode.clone(),
-                remaining_amount: escrow.remaining_amount,
-            },
-        );
-
-        env.storage().instance().remove(&DataKey::ReentrancyGuard);
-
-        // Track successful operation
-        monitoring::track_operation(&env, symbol_short!("refund"), caller, true);
-
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("refund"), duration);
+            &EscrowId::Str(bounty_id),
+            &mut escrow,
+            refund_amount,
+            &depositor,
+            mode,
+            RefundReason::DeadlinePassed,
+        );
+        Self::exit_token_guard(&env, guarded);
+        result
+    }
 
-        Ok(())
+    /// Retrieves escrow information for a string-keyed bounty.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` - No escrow under this string ID
+    pub fn get_escrow_info_str(env: Env, bounty_id: String) -> Result<Escrow, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EscrowStr(bounty_id))
+            .ok_or(Error::BountyNotFound)
     }
 
     // ========================================================================
@@ -1512,6 +5910,81 @@ impl BountyEscrowContract {
             .unwrap())
     }
 
+    /// Returns how many seconds ago the escrow was locked.
+    ///
+    /// Meant for flagging stale bounties. The age keeps growing after the
+    /// escrow settles, so check its status alongside.
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - Seconds since `created_at`
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    pub fn get_escrow_age(env: Env, bounty_id: u64) -> Result<u64, Error> {
+        let escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        Ok(env.ledger().timestamp().saturating_sub(escrow.created_at))
+    }
+
+    /// Returns the escrow record together with its computed deadline fields.
+    ///
+    /// # Returns
+    /// * `Ok(EscrowDetails)` - Record and fields as of the current ledger time
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    pub fn get_escrow_details(env: Env, bounty_id: u64) -> Result<EscrowDetails, Error> {
+        let escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        let now = env.ledger().timestamp();
+        let is_refundable = escrow.status == EscrowStatus::Locked
+            && Self::refund_unlocks_at(&env, &escrow).is_ok_and(|unlocks_at| now >= unlocks_at);
+
+        Ok(EscrowDetails {
+            is_expired: now >= escrow.deadline,
+            remaining_time: escrow.deadline.saturating_sub(now),
+            is_refundable,
+            escrow,
+        })
+    }
+
+    /// Returns whether an escrow record exists for `bounty_id`.
+    ///
+    /// Cheaper than `get_escrow_info` for clients that pick their own IDs
+    /// and only need to know whether one is taken.
+    ///
+    /// # Gas Cost
+    /// Very Low - Single storage existence check
+    pub fn bounty_exists(env: Env, bounty_id: u64) -> bool {
+        env.storage().persistent().has(&DataKey::Escrow(bounty_id))
+    }
+
+    /// Retrieves several escrow records in one call.
+    ///
+    /// The result lines up positionally with `bounty_ids`: `None` for IDs
+    /// with no escrow, `Some(escrow)` otherwise.
+    ///
+    /// # Errors
+    /// * `InvalidBatchSize` - More than `MAX_BATCH_SIZE` IDs requested
+    pub fn get_escrows(env: Env, bounty_ids: Vec<u64>) -> Result<Vec<Option<Escrow>>, Error> {
+        if bounty_ids.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        let mut escrows = Vec::new(&env);
+        for bounty_id in bounty_ids.iter() {
+            escrows.push_back(env.storage().persistent().get(&DataKey::Escrow(bounty_id)));
+        }
+        Ok(escrows)
+    }
+
+    /// Returns the seconds left until a bounty's deadline, or 0 once passed.
+    ///
+    /// Uses the ledger timestamp so countdowns do not drift from the time
+    /// the contract enforces.
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - Seconds remaining
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    pub fn get_remaining_time(env: Env, bounty_id: u64) -> Result<u64, Error> {
+        let escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        Ok(escrow.deadline.saturating_sub(env.ledger().timestamp()))
+    }
+
     /// Returns the current token balance held by the contract.
     ///
     /// # Arguments
@@ -1543,6 +6016,134 @@ impl BountyEscrowContract {
         Ok(client.balance(&env.current_contract_address()))
     }
 
+    /// Lists bounty IDs posted by a depositor, oldest first.
+    ///
+    /// Released and refunded bounties stay in the list; use `get_escrow_info`
+    /// to check their current status.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `depositor` - Depositor to list bounties for
+    /// * `start` - Index of the first entry to return
+    /// * `limit` - Maximum number of entries to return
+    pub fn get_bounties_by_depositor(
+        env: Env,
+        depositor: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        let ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DepositorBounties(depositor))
+            .unwrap_or(Vec::new(&env));
+
+        let end = start.saturating_add(limit).min(ids.len());
+        if start >= end {
+            return Vec::new(&env);
+        }
+        ids.slice(start..end)
+    }
+
+    /// Lists bounties that can be refunded right now: still `Locked` with the
+    /// deadline and refund grace period passed.
+    ///
+    /// Scans the window `[start, start + limit)` of the bounty index (all
+    /// bounties in lock order) and returns the matching IDs from it, so a
+    /// page may hold fewer than `limit` entries, including none. Keepers page
+    /// through by advancing `start` by `limit` until it passes the number of
+    /// bounties ever locked.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `start` - Index position to start scanning from
+    /// * `limit` - Number of index entries to scan, at most `MAX_BATCH_SIZE`
+    ///
+    /// # Errors
+    /// * `InvalidBatchSize` - `limit` exceeds `MAX_BATCH_SIZE`
+    pub fn list_refundable(env: Env, start: u32, limit: u32) -> Result<Vec<u64>, Error> {
+        let now = env.ledger().timestamp();
+        Self::scan_bounty_index(&env, start, limit, |escrow| {
+            escrow.status == EscrowStatus::Locked
+                && Self::refund_unlocks_at(&env, escrow).is_ok_and(|unlocks_at| now >= unlocks_at)
+        })
+    }
+
+    /// Lists `Locked` bounties whose deadline is still ahead but no more than
+    /// `window_seconds` away, for deadline reminders.
+    ///
+    /// Pages the same way as `list_refundable`. Bounties whose deadline has
+    /// already passed are not included.
+    ///
+    /// # Errors
+    /// * `InvalidBatchSize` - `limit` exceeds `MAX_BATCH_SIZE`
+    pub fn list_expiring_within(
+        env: Env,
+        window_seconds: u64,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<u64>, Error> {
+        let now = env.ledger().timestamp();
+        let horizon = now.saturating_add(window_seconds);
+        Self::scan_bounty_index(&env, start, limit, |escrow| {
+            escrow.status == EscrowStatus::Locked
+                && escrow.deadline > now
+                && escrow.deadline <= horizon
+        })
+    }
+
+    /// Lists bounties currently in `status`, oldest first.
+    ///
+    /// Pages the same way as `list_refundable`: the window
+    /// `[start, start + limit)` of the bounty index is scanned and the
+    /// matching IDs from it returned, so a page may hold fewer than `limit`
+    /// entries. Counting a status means walking every page, at one escrow
+    /// read per scanned entry; callers that need counts often should keep
+    /// them off-chain from the escrow events.
+    ///
+    /// String-keyed bounties are not in the index and are never returned.
+    ///
+    /// # Errors
+    /// * `InvalidBatchSize` - `limit` exceeds `MAX_BATCH_SIZE`
+    pub fn get_bounties_by_status(
+        env: Env,
+        status: EscrowStatus,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<u64>, Error> {
+        Self::scan_bounty_index(&env, start, limit, |escrow| escrow.status == status)
+    }
+
+    /// Returns the IDs in the `[start, start + limit)` window of the bounty
+    /// index whose escrow satisfies `matches`.
+    fn scan_bounty_index(
+        env: &Env,
+        start: u32,
+        limit: u32,
+        matches: impl Fn(&Escrow) -> bool,
+    ) -> Result<Vec<u64>, Error> {
+        if limit > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+        let ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BountyIndex)
+            .unwrap_or(Vec::new(env));
+
+        let end = start.saturating_add(limit).min(ids.len());
+        let mut found = Vec::new(env);
+        for i in start..end {
+            let bounty_id = ids.get(i).unwrap();
+            let escrow: Option<Escrow> =
+                env.storage().persistent().get(&DataKey::Escrow(bounty_id));
+            if escrow.is_some_and(|escrow| matches(&escrow)) {
+                found.push_back(bounty_id);
+            }
+        }
+        Ok(found)
+    }
+
     /// Retrieves the refund history for a specific bounty.
     ///
     /// # Arguments
@@ -1592,6 +6193,9 @@ impl BountyEscrowContract {
 
         let now = env.ledger().timestamp();
         let deadline_passed = now >= escrow.deadline;
+        // An approval still applies when the unlock time overflows
+        let refund_unlocked =
+            Self::refund_unlocks_at(&env, &escrow).is_ok_and(|unlocks_at| now >= unlocks_at);
 
         let approval = if env
             .storage()
@@ -1610,10 +6214,10 @@ impl BountyEscrowContract {
 
         // can_refund is true if:
         // 1. Status is Locked or PartiallyRefunded AND
-        // 2. (deadline has passed OR there's an approval)
+        // 2. (deadline and grace period have passed OR there's an approval)
         let can_refund = (escrow.status == EscrowStatus::Locked
             || escrow.status == EscrowStatus::PartiallyRefunded)
-            && (deadline_passed || approval.is_some());
+            && (refund_unlocked || approval.is_some());
 
         Ok((
             can_refund,
@@ -1623,6 +6227,182 @@ impl BountyEscrowContract {
         ))
     }
 
+    /// Checks whether a bounty can currently be refunded after its deadline.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `bounty_id` - The bounty to query
+    ///
+    /// # Returns
+    /// * `Ok(true)` - Escrow is still `Locked` and the deadline plus the refund
+    ///   grace period has passed
+    /// * `Ok(false)` - Refunds not unlocked yet, or funds already released/refunded
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    ///
+    /// # Gas Cost
+    /// Very Low - Single storage read
+    pub fn is_refundable(env: Env, bounty_id: u64) -> Result<bool, Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        Ok(escrow.status == EscrowStatus::Locked
+            && env.ledger().timestamp() >= Self::refund_unlocks_at(&env, &escrow)?)
+    }
+
+    /// Checks whether a deadline refund is open yet, emitting
+    /// `RefundNotYetAvailable` when it is not.
+    ///
+    /// `refund` emits the same event before failing with `DeadlineNotPassed`,
+    /// but the events of a failed call are discarded on-chain. Keepers that
+    /// want the breadcrumb recorded call this instead, which never reverts on
+    /// an early attempt.
+    ///
+    /// # Returns
+    /// * `Ok(true)` - Funds are still held and the deadline plus the refund
+    ///   grace period has passed
+    /// * `Ok(false)` - Refunds not unlocked yet (event emitted), or nothing
+    ///   left to refund (no event)
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    /// * `Err(Error::TimeOverflow)` - The unlock time overflows
+    ///
+    /// # Events
+    /// * `RefundNotYetAvailable` - When the refund is still time-locked
+    pub fn check_refund_available(env: Env, bounty_id: u64) -> Result<bool, Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Ok(false);
+        }
+
+        let now = env.ledger().timestamp();
+        if now >= Self::refund_unlocks_at(&env, &escrow)? {
+            return Ok(true);
+        }
+        events::emit_refund_not_yet_available(
+            &env,
+            events::RefundNotYetAvailable {
+                bounty_id,
+                deadline: escrow.deadline,
+                now,
+            },
+        );
+        Ok(false)
+    }
+
+    /// Returns the pending release approval for a bounty, if any.
+    pub fn get_release_approval(env: Env, bounty_id: u64) -> Option<ReleaseApproval> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReleaseApproval(bounty_id))
+    }
+
+    /// Returns the sum of all approved-but-unreleased amounts.
+    ///
+    /// This is the portion of the contract balance already committed to
+    /// contributors, as opposed to funds that are merely locked.
+    ///
+    /// # Gas Cost
+    /// Very Low - Single instance storage read
+    pub fn total_pending_releases(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::PendingReleases)
+            .unwrap_or(0)
+    }
+
+    /// Returns the total amount held for escrows that are not yet settled
+    /// (locked or partially refunded) in the configured token.
+    ///
+    /// Escrows locked in other tokens via `lock_funds_token` are reported by
+    /// `get_total_locked_for`.
+    pub fn get_total_locked(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalLocked)
+            .unwrap_or(0)
+    }
+
+    /// Returns the total amount held for unsettled escrows in `token`.
+    pub fn get_total_locked_for(env: Env, token: Address) -> i128 {
+        if !env.storage().instance().has(&DataKey::Token) {
+            return 0;
+        }
+        env.storage()
+            .instance()
+            .get(&Self::locked_key(&env, &token))
+            .unwrap_or(0)
+    }
+
+    /// Configured-token balance not owed to any escrow: `get_balance()`
+    /// minus `get_total_locked()`.
+    ///
+    /// A positive value means tokens reached the contract outside
+    /// `lock_funds` (a direct transfer, say) and can be recovered with
+    /// `sweep_unaccounted`. A negative value means the contract holds less
+    /// than it owes and should be investigated.
+    ///
+    /// # Errors
+    /// * `NotInitialized` - Contract not initialized
+    /// * `SchemaMismatch` - A schema migration is unfinished, so
+    ///   `TotalLocked` does not yet cover every escrow
+    pub fn get_unaccounted_balance(env: Env) -> Result<i128, Error> {
+        let balance = Self::get_balance(env.clone())?;
+        Self::check_schema_current(&env)?;
+        Ok(balance - Self::get_total_locked(env))
+    }
+
+    /// Sends the unaccounted configured-token balance to `to` (admin only).
+    /// Escrowed amounts are never touched. Works while paused.
+    ///
+    /// # Returns
+    /// The amount swept
+    ///
+    /// # Errors
+    /// * `NotInitialized` - Contract not initialized
+    /// * `InvalidRecipient` - `to` is the contract itself
+    /// * `InsufficientFunds` - Nothing to sweep
+    /// * `SchemaMismatch` - A schema migration is unfinished
+    ///
+    /// # Events
+    /// Emits `UnaccountedSwept`
+    pub fn sweep_unaccounted(env: Env, to: Address) -> Result<i128, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Self::check_recipient(&env, &to)?;
+
+        let amount = Self::get_unaccounted_balance(env.clone())?;
+        if amount <= 0 {
+            return Err(Error::InsufficientFunds);
+        }
+        token::Client::new(&env, &Self::default_token(&env)).transfer(
+            &env.current_contract_address(),
+            &to,
+            &amount,
+        );
+
+        events::emit_unaccounted_swept(
+            &env,
+            events::UnaccountedSwept {
+                amount,
+                to,
+                admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(amount)
+    }
+
     /// Batch lock funds for multiple bounties in a single transaction.
     /// This improves gas efficiency by reducing transaction overhead.
     ///
@@ -1636,9 +6416,14 @@ impl BountyEscrowContract {
     /// * InvalidBatchSize - if batch size exceeds MAX_BATCH_SIZE or is zero
     /// * BountyExists - if any bounty_id already exists
     /// * NotInitialized - if contract is not initialized
+    /// * DepositorNotAllowed - if a depositor is not on an enabled allowlist
+    /// * RateLimited - if a depositor locked within `min_lock_interval`
+    /// * Otherwise the same per-item checks as [`lock_funds`](Self::lock_funds)
     ///
     /// # Note
     /// This operation is atomic - if any item fails, the entire transaction reverts.
+    /// Each item is charged the lock fee and records only the amount that
+    /// actually arrived, exactly as `lock_funds` would.
     pub fn batch_lock_funds(env: Env, items: Vec<LockFundsItem>) -> Result<u32, Error> {
         // Validate batch size
         let batch_size = items.len();
@@ -1654,13 +6439,11 @@ impl BountyEscrowContract {
             return Err(Error::ContractPaused);
         }
 
-        if !env.storage().instance().has(&DataKey::Admin) {
+        if !env.storage().instance().has(&DataKey::Token) {
             return Err(Error::NotInitialized);
         }
 
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        let contract_address = env.current_contract_address();
         let timestamp = env.ledger().timestamp();
 
         // Validate all items before processing (all-or-nothing approach)
@@ -1674,10 +6457,7 @@ impl BountyEscrowContract {
                 return Err(Error::BountyExists);
             }
 
-            // Validate amount
-            if item.amount <= 0 {
-                return Err(Error::InvalidAmount);
-            }
+            Self::validate_lock(&env, item.amount, item.deadline)?;
 
             // Check for duplicate bounty_ids in the batch
             let mut count = 0u32;
@@ -1708,37 +6488,75 @@ impl BountyEscrowContract {
             }
         }
 
+        for depositor in seen_depositors.iter() {
+            if !Self::is_depositor_allowed(env.clone(), depositor.clone()) {
+                return Err(Error::DepositorNotAllowed);
+            }
+            let new_locks = items.iter().filter(|i| i.depositor == depositor).count() as u32;
+            Self::check_active_limit(&env, &depositor, new_locks)?;
+            if !anti_abuse::record_lock(&env, &depositor) {
+                return Err(Error::RateLimited);
+            }
+        }
+
         // Process all items (atomic - all succeed or all fail)
         let mut locked_count = 0u32;
+        let mut total_received: i128 = 0;
         for item in items.iter() {
-            // Transfer funds from depositor to contract
-            client.transfer(&item.depositor, &contract_address, &item.amount);
+            // The TVL cap is checked against TotalLocked, which already
+            // includes the earlier items of this batch
+            let (net_amount, fee_amount) = Self::prepare_deposit(&env, &token_addr, item.amount)?;
 
-            // Create escrow record
-            let escrow = Escrow {
+            // Effects: store the escrow before pulling the deposit
+            let mut escrow = Escrow {
                 depositor: item.depositor.clone(),
-                amount: item.amount,
+                amount: net_amount,
                 status: EscrowStatus::Locked,
                 deadline: item.deadline,
                 refund_history: vec![&env],
-                remaining_amount: item.amount,
+                remaining_amount: net_amount,
+                created_at: timestamp,
+                token: token_addr.clone(),
             };
-
-            // Store escrow
             env.storage()
                 .persistent()
                 .set(&DataKey::Escrow(item.bounty_id), &escrow);
+            Self::adjust_total_locked(&env, &token_addr, net_amount);
+            Self::index_bounty(&env, &item.depositor, item.bounty_id);
+            Self::adjust_active_bounties(&env, &item.depositor, true);
+
+            // Interactions: pull the deposit (net of the lock fee)
+            let received = Self::transfer_deposit(
+                &env,
+                &token_addr,
+                &item.depositor,
+                net_amount,
+                fee_amount,
+                false,
+            )?;
+
+            // Only what actually arrived is held for release or refund
+            if received != net_amount {
+                escrow.amount = received;
+                escrow.remaining_amount = received;
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::Escrow(item.bounty_id), &escrow);
+                Self::adjust_total_locked(&env, &token_addr, received - net_amount);
+            }
+            total_received += received;
 
             // Emit individual event for each locked bounty
             emit_funds_locked(
                 &env,
                 FundsLocked {
                     bounty_id: item.bounty_id,
-                    amount: item.amount,
+                    amount: received,
                     depositor: item.depositor.clone(),
                     deadline: item.deadline,
                 },
             );
+            emit_escrow_created(&env, item.bounty_id, &escrow);
 
             locked_count += 1;
         }
@@ -1748,7 +6566,7 @@ impl BountyEscrowContract {
             &env,
             BatchFundsLocked {
                 count: locked_count,
-                total_amount: items.iter().map(|i| i.amount).sum(),
+                total_amount: total_received,
                 timestamp,
             },
         );
@@ -1770,6 +6588,7 @@ impl BountyEscrowContract {
     /// * BountyNotFound - if any bounty_id doesn't exist
     /// * FundsNotLocked - if any bounty is not in Locked status
     /// * Unauthorized - if caller is not admin
+    /// * Any contributor eligibility error (see `release_funds`)
     ///
     /// # Note
     /// This operation is atomic - if any item fails, the entire transaction reverts.
@@ -1795,8 +6614,6 @@ impl BountyEscrowContract {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
         let contract_address = env.current_contract_address();
         let timestamp = env.ledger().timestamp();
 
@@ -1822,6 +6639,7 @@ impl BountyEscrowContract {
             if escrow.status != EscrowStatus::Locked {
                 return Err(Error::FundsNotLocked);
             }
+            Self::check_multisig_not_required(&env, &escrow)?;
 
             // Check for duplicate bounty_ids in the batch
             let mut count = 0u32;
@@ -1834,8 +6652,10 @@ impl BountyEscrowContract {
                 return Err(Error::DuplicateBountyId);
             }
 
+            Self::check_contributor_eligibility(&env, item.bounty_id, &item.contributor)?;
+
             total_amount = total_amount
-                .checked_add(escrow.amount)
+                .checked_add(escrow.remaining_amount)
                 .ok_or(Error::InvalidAmount)?;
         }
 
@@ -1848,21 +6668,33 @@ impl BountyEscrowContract {
                 .get(&DataKey::Escrow(item.bounty_id))
                 .unwrap();
 
-            // Transfer funds to contributor
-            client.transfer(&contract_address, &item.contributor, &escrow.amount);
+            Self::clear_release_approval(&env, item.bounty_id);
 
-            // Update escrow status
+            // Update escrow status before the transfer
+            let amount = escrow.remaining_amount;
+            Self::add_to_settled(&env, &escrow.token, DataKey::TotalReleased, amount);
+            Self::record_contributor_payout(&env, &escrow.token, &item.contributor, amount, true);
+            Self::adjust_total_locked(&env, &escrow.token, -escrow.remaining_amount);
+            Self::adjust_active_bounties(&env, &escrow.depositor, false);
             escrow.status = EscrowStatus::Released;
+            escrow.remaining_amount = 0;
             env.storage()
                 .persistent()
                 .set(&DataKey::Escrow(item.bounty_id), &escrow);
 
+            // Transfer funds to contributor
+            token::Client::new(&env, &escrow.token).transfer(
+                &contract_address,
+                &item.contributor,
+                &amount,
+            );
+
             // Emit individual event for each released bounty
             emit_funds_released(
                 &env,
                 FundsReleased {
                     bounty_id: item.bounty_id,
-                    amount: escrow.amount,
+                    amount,
                     recipient: item.contributor.clone(),
                     timestamp,
                 },
@@ -1883,6 +6715,139 @@ impl BountyEscrowContract {
 
         Ok(released_count)
     }
+
+    /// Releases several bounties to one contributor (admin only), paying
+    /// the total in a single transfer per token.
+    ///
+    /// Each bounty goes through the same checks as `release_funds`
+    /// (status, contributor gates, release fee). With `strict` set the
+    /// first bounty that cannot be released fails the whole call;
+    /// otherwise such bounties are skipped and left untouched.
+    ///
+    /// # Arguments
+    /// * `bounty_ids` - Bounties to release, at most `MAX_BATCH_SIZE`
+    /// * `contributor` - Recipient of every released bounty
+    /// * `strict` - Reject instead of skipping bounties that can't be released
+    ///
+    /// # Returns
+    /// One `BatchResult` per input bounty, in order, saying whether it was
+    /// released or why it was skipped
+    ///
+    /// # Errors
+    /// * InvalidBatchSize - if `bounty_ids` is empty or too long
+    /// * NotInitialized - if contract is not initialized
+    /// * InvalidRecipient - if `contributor` is the contract itself
+    /// * In strict mode: BountyNotFound, FundsNotLocked, DuplicateBountyId,
+    ///   or the contributor gate errors of `release_funds`
+    ///
+    /// # Events
+    /// Emits one `FundsReleased` per bounty with its net amount, then
+    /// `BatchFundsReleased`
+    pub fn batch_release(
+        env: Env,
+        bounty_ids: Vec<u64>,
+        contributor: Address,
+        strict: bool,
+    ) -> Result<Vec<BatchResult>, Error> {
+        if bounty_ids.is_empty() || bounty_ids.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+        if Self::is_paused_internal(&env) {
+            return Err(Error::ContractPaused);
+        }
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Self::check_recipient(&env, &contributor)?;
+
+        let timestamp = env.ledger().timestamp();
+        let mut released_count = 0u32;
+        let mut total_amount: i128 = 0;
+        let mut results: Vec<BatchResult> = Vec::new(&env);
+        // (token, net, fee) owed per token, paid out after all escrows settle
+        let mut payouts: Vec<(Address, i128, i128)> = Vec::new(&env);
+
+        for (i, bounty_id) in bounty_ids.iter().enumerate() {
+            let check = if bounty_ids.first_index_of(bounty_id) != Some(i as u32) {
+                Err(Error::DuplicateBountyId)
+            } else {
+                Self::get_escrow_info(env.clone(), bounty_id).and_then(|escrow| {
+                    if escrow.status != EscrowStatus::Locked {
+                        return Err(Error::FundsNotLocked);
+                    }
+                    Self::check_multisig_not_required(&env, &escrow)?;
+                    Self::check_contributor_eligibility(&env, bounty_id, &contributor)?;
+                    Ok(escrow)
+                })
+            };
+            let mut escrow = match check {
+                Ok(escrow) => escrow,
+                Err(err) if strict => return Err(err),
+                Err(err) => {
+                    results.push_back(BatchResult {
+                        id: bounty_id,
+                        outcome: BatchOutcome::Skipped(err as u32),
+                    });
+                    continue;
+                }
+            };
+
+            Self::clear_release_approval(&env, bounty_id);
+            let amount = escrow.remaining_amount;
+            let (net_amount, fee_amount) = Self::settle_release(&env, &mut escrow);
+            Self::record_contributor_payout(&env, &escrow.token, &contributor, net_amount, true);
+            env.storage()
+                .persistent()
+                .set(&DataKey::Escrow(bounty_id), &escrow);
+
+            match payouts
+                .iter()
+                .position(|(token, _, _)| token == escrow.token)
+            {
+                Some(pos) => {
+                    let (token, net, fee) = payouts.get(pos as u32).unwrap();
+                    payouts.set(pos as u32, (token, net + net_amount, fee + fee_amount));
+                }
+                None => payouts.push_back((escrow.token.clone(), net_amount, fee_amount)),
+            }
+
+            emit_funds_released(
+                &env,
+                FundsReleased {
+                    bounty_id,
+                    amount: net_amount,
+                    recipient: contributor.clone(),
+                    timestamp,
+                },
+            );
+            released_count += 1;
+            total_amount = total_amount
+                .checked_add(amount)
+                .ok_or(Error::InvalidAmount)?;
+            results.push_back(BatchResult {
+                id: bounty_id,
+                outcome: BatchOutcome::Ok,
+            });
+        }
+
+        for (token, net, fee) in payouts.iter() {
+            Self::transfer_release(&env, &token, &contributor, net, fee);
+        }
+
+        emit_batch_funds_released(
+            &env,
+            BatchFundsReleased {
+                count: released_count,
+                total_amount,
+                timestamp,
+            },
+        );
+
+        Ok(results)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,37 @@
+//! # Schema Migration
+//!
+//! Record layouts written by earlier schema versions, kept so
+//! [`migrate_escrows`](crate::BountyEscrowContract::migrate_escrows) can
+//! decode them and rewrite them in the current layout.
+//!
+//! ## Version 1
+//!
+//! The original release stored `Escrow` without `created_at` or `token`,
+//! and `RefundRecord` without `reason`. It also kept none of the running
+//! counters (`TotalLocked`, active counts, bounty indexes), so they are
+//! rebuilt as each escrow is converted.
+
+use crate::{EscrowStatus, RefundMode};
+use soroban_sdk::{contracttype, Address, Vec};
+
+/// `RefundRecord` as stored by schema version 1.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundRecordV1 {
+    pub amount: i128,
+    pub recipient: Address,
+    pub mode: RefundMode,
+    pub timestamp: u64,
+}
+
+/// `Escrow` as stored by schema version 1.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowV1 {
+    pub depositor: Address,
+    pub amount: i128,
+    pub status: EscrowStatus,
+    pub deadline: u64,
+    pub refund_history: Vec<RefundRecordV1>,
+    pub remaining_amount: i128,
+}
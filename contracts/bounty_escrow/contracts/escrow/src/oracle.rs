@@ -0,0 +1,25 @@
+//! # Milestone Oracle Interface
+//!
+//! Performance-based bounties locked with
+//! [`lock_with_milestones_oracle`](crate::BountyEscrowContract::lock_with_milestones_oracle)
+//! pay out in tranches. Each tranche is tied to a metric symbol, and anyone may
+//! call `check_milestone` to have the escrow ask the linked oracle whether
+//! that metric has been met.
+//!
+//! ## Oracle Contract Requirements
+//!
+//! ```text
+//! fn is_met(env: Env, bounty_id: u64, metric: Symbol) -> bool
+//! ```
+//!
+//! The oracle is fully trusted for the bounty it is linked to: returning
+//! `true` releases the tranche immediately and irreversibly.
+
+use soroban_sdk::{contractclient, Env, Symbol};
+
+/// Interface that milestone oracles must implement.
+#[contractclient(name = "MilestoneOracleClient")]
+pub trait MilestoneOracle {
+    /// Whether `metric` has been achieved for `bounty_id`.
+    fn is_met(env: Env, bounty_id: u64, metric: Symbol) -> bool;
+}
@@ -2,8 +2,9 @@
 
 use super::*;
 use soroban_sdk::{
-    testutils::{Address as _, Ledger},
-    token, vec, Address, Env, Vec,
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address, Bytes, Env, IntoVal, Symbol, Vec,
 };
 
 fn create_token_contract<'a>(
@@ -264,6 +265,65 @@ fn test_refund_full_before_deadline() {
     );
 }
 
+#[test]
+fn test_premature_refund_emits_not_yet_available() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let now = setup.env.ledger().timestamp();
+    let deadline = now + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &1000, &deadline);
+
+    let result = setup.escrow.try_refund(
+        &bounty_id,
+        &None::<i128>,
+        &None::<Address>,
+        &RefundMode::Full,
+    );
+    assert_eq!(result, Err(Ok(Error::DeadlineNotPassed)));
+
+    let last = setup.env.events().all().last().unwrap();
+    assert_eq!(last.0, setup.escrow_address);
+    assert_eq!(
+        last.1,
+        (symbol_short!("ref_early"), bounty_id).into_val(&setup.env)
+    );
+    let event: events::RefundNotYetAvailable = last.2.into_val(&setup.env);
+    assert_eq!(event.deadline, deadline);
+    assert_eq!(event.now, now);
+}
+
+#[test]
+fn test_check_refund_available_emits_without_reverting() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let now = setup.env.ledger().timestamp();
+    let deadline = now + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &1000, &deadline);
+
+    assert!(!setup.escrow.check_refund_available(&bounty_id));
+    let last = setup.env.events().all().last().unwrap();
+    assert_eq!(
+        last.1,
+        (symbol_short!("ref_early"), bounty_id).into_val(&setup.env)
+    );
+    let event: events::RefundNotYetAvailable = last.2.into_val(&setup.env);
+    assert_eq!(event.deadline, deadline);
+    assert_eq!(event.now, now);
+
+    setup.env.ledger().set_timestamp(deadline);
+    assert!(setup.escrow.check_refund_available(&bounty_id));
+    assert_eq!(
+        setup.escrow.try_check_refund_available(&99),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
 // ============================================================================
 // REFUND TESTS - Partial Refund
 // ============================================================================
@@ -834,6 +894,53 @@ fn test_get_refund_eligibility() {
     assert!(approval.is_some());
 }
 
+#[test]
+fn test_is_refundable_before_and_after_deadline() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let amount = 1000;
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+
+    // Before deadline
+    assert!(!setup.escrow.is_refundable(&bounty_id));
+
+    // Exactly at deadline
+    setup.env.ledger().set_timestamp(deadline);
+    assert!(setup.escrow.is_refundable(&bounty_id));
+
+    // After deadline
+    setup.env.ledger().set_timestamp(deadline + 1);
+    assert!(setup.escrow.is_refundable(&bounty_id));
+}
+
+#[test]
+fn test_is_refundable_after_release() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let amount = 1000;
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.release_funds(&bounty_id, &setup.contributor);
+
+    // Released escrows are never refundable, even past the deadline
+    setup.env.ledger().set_timestamp(deadline + 1);
+    assert!(!setup.escrow.is_refundable(&bounty_id));
+}
+
+#[test]
+fn test_is_refundable_not_found() {
+    let setup = TestSetup::new();
+    let result = setup.escrow.try_is_refundable(&999);
+    assert_eq!(result, Err(Ok(Error::BountyNotFound)));
+}
+
 #[test]
 fn test_get_balance() {
     let setup = TestSetup::new();
@@ -1182,3 +1289,4381 @@ fn test_batch_operations_large_batch() {
     let release_count = setup.escrow.batch_release_funds(&release_items);
     assert_eq!(release_count, 10);
 }
+
+// ============================================================================
+// DISPUTE RESOLVER TESTS
+// ============================================================================
+
+#[contract]
+struct MockResolver;
+
+#[contractimpl]
+impl MockResolver {
+    pub fn set_outcome(env: Env, outcome: ResolutionOutcome) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("outcome"), &outcome);
+    }
+
+    pub fn resolve(env: Env, _bounty_id: u64) -> ResolutionOutcome {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("outcome"))
+            .unwrap()
+    }
+}
+
+fn create_mock_resolver<'a>(env: &Env) -> MockResolverClient<'a> {
+    let resolver_id = env.register_contract(None, MockResolver);
+    MockResolverClient::new(env, &resolver_id)
+}
+
+#[test]
+fn test_dispute_resolved_with_release() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let amount = 1000;
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    let resolver = create_mock_resolver(&setup.env);
+    resolver.set_outcome(&ResolutionOutcome::Release(setup.contributor.clone()));
+
+    setup.escrow.lock_funds_with_resolver(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &resolver.address,
+    );
+    assert_eq!(
+        setup.escrow.get_dispute_resolver(&bounty_id),
+        Some(resolver.address.clone())
+    );
+
+    let outcome = setup.escrow.raise_dispute(&bounty_id, &setup.depositor);
+    assert_eq!(
+        outcome,
+        ResolutionOutcome::Release(setup.contributor.clone())
+    );
+
+    let escrow = setup.escrow.get_escrow_info(&bounty_id);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(setup.token.balance(&setup.contributor), amount);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 0);
+}
+
+#[test]
+fn test_dispute_resolved_with_refund() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let amount = 1000;
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    let resolver = create_mock_resolver(&setup.env);
+    resolver.set_outcome(&ResolutionOutcome::Refund);
+
+    setup.escrow.lock_funds_with_resolver(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &resolver.address,
+    );
+
+    // Disputes can be settled before the deadline
+    let outcome = setup.escrow.raise_dispute(&bounty_id, &setup.admin);
+    assert_eq!(outcome, ResolutionOutcome::Refund);
+
+    let escrow = setup.escrow.get_escrow_info(&bounty_id);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(escrow.refund_history.len(), 1);
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 0);
+}
+
+#[test]
+fn test_dispute_release_after_partial_refund() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    let resolver = create_mock_resolver(&setup.env);
+    resolver.set_outcome(&ResolutionOutcome::Release(setup.contributor.clone()));
+
+    setup.escrow.lock_funds_with_resolver(
+        &setup.depositor,
+        &bounty_id,
+        &1000,
+        &deadline,
+        &resolver.address,
+    );
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup.escrow.refund(
+        &bounty_id,
+        &Some(300),
+        &None::<Address>,
+        &RefundMode::Partial,
+    );
+
+    // Only what is left after the partial refund is paid out
+    setup.escrow.raise_dispute(&bounty_id, &setup.depositor);
+    let escrow = setup.escrow.get_escrow_info(&bounty_id);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(setup.token.balance(&setup.contributor), 700);
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000 - 700);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 0);
+}
+
+#[test]
+fn test_dispute_without_resolver() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &1000, &deadline);
+
+    let result = setup.escrow.try_raise_dispute(&bounty_id, &setup.depositor);
+    assert_eq!(result, Err(Ok(Error::ResolverNotSet)));
+}
+
+#[test]
+fn test_dispute_raised_by_outsider() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    let resolver = create_mock_resolver(&setup.env);
+    resolver.set_outcome(&ResolutionOutcome::Refund);
+    setup.escrow.lock_funds_with_resolver(
+        &setup.depositor,
+        &bounty_id,
+        &1000,
+        &deadline,
+        &resolver.address,
+    );
+
+    let outsider = Address::generate(&setup.env);
+    let result = setup.escrow.try_raise_dispute(&bounty_id, &outsider);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_get_dispute_context() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let amount = 1000;
+    setup.env.ledger().set_timestamp(500);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    let resolver = create_mock_resolver(&setup.env);
+    resolver.set_outcome(&ResolutionOutcome::Release(setup.contributor.clone()));
+    setup.escrow.lock_funds_with_resolver(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &resolver.address,
+    );
+
+    let context = setup.escrow.get_dispute_context(&bounty_id);
+    assert_eq!(context.status, EscrowStatus::Locked);
+    assert_eq!(context.created_at, 500);
+    assert_eq!(context.contributor, None);
+    assert_eq!(context.dispute_state, DisputeState::Open);
+
+    setup.env.ledger().set_timestamp(700);
+    setup.escrow.raise_dispute(&bounty_id, &setup.depositor);
+
+    let context = setup.escrow.get_dispute_context(&bounty_id);
+    assert_eq!(context.bounty_id, bounty_id);
+    assert_eq!(context.depositor, setup.depositor);
+    assert_eq!(context.amount, amount);
+    assert_eq!(context.status, EscrowStatus::Released);
+    assert_eq!(context.deadline, deadline);
+    assert_eq!(context.created_at, 500);
+    assert_eq!(context.contributor, Some(setup.contributor.clone()));
+    assert_eq!(context.resolver, Some(resolver.address.clone()));
+    assert_eq!(
+        context.dispute_state,
+        DisputeState::Resolved(ResolutionOutcome::Release(setup.contributor.clone()))
+    );
+    assert_eq!(context.refund_history.len(), 0);
+}
+
+#[test]
+fn test_get_dispute_context_not_found() {
+    let setup = TestSetup::new();
+    let result = setup.escrow.try_get_dispute_context(&999);
+    assert_eq!(result, Err(Ok(Error::BountyNotFound)));
+}
+
+// ============================================================================
+// PENDING RELEASE TESTS
+// ============================================================================
+
+#[test]
+fn test_total_pending_releases() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &2500, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &3, &4000, &deadline);
+    assert_eq!(setup.escrow.total_pending_releases(), 0);
+
+    setup.escrow.approve_release(&1, &setup.contributor);
+    setup.escrow.approve_release(&2, &setup.contributor);
+    assert_eq!(setup.escrow.total_pending_releases(), 3500);
+
+    // Executing an approved release removes it from the pending total
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.escrow.total_pending_releases(), 2500);
+    assert!(setup.escrow.get_release_approval(&1).is_none());
+
+    // Revoking removes the remaining commitment
+    setup.escrow.revoke_release_approval(&2);
+    assert_eq!(setup.escrow.total_pending_releases(), 0);
+}
+
+#[test]
+fn test_revoke_release_approval_errors() {
+    let setup = TestSetup::new();
+    assert_eq!(
+        setup.escrow.try_revoke_release_approval(&1),
+        Err(Ok(Error::BountyNotFound))
+    );
+
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    assert_eq!(
+        setup.escrow.try_revoke_release_approval(&1),
+        Err(Ok(Error::ApprovalNotFound))
+    );
+
+    setup.escrow.approve_release(&1, &setup.contributor);
+    setup.escrow.revoke_release_approval(&1);
+    assert_eq!(
+        setup.escrow.try_revoke_release_approval(&1),
+        Err(Ok(Error::ApprovalNotFound))
+    );
+}
+
+#[test]
+fn test_reapprove_release_does_not_double_count() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let other_contributor = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+
+    setup.escrow.approve_release(&1, &setup.contributor);
+    setup.escrow.approve_release(&1, &other_contributor);
+    assert_eq!(setup.escrow.total_pending_releases(), 1000);
+    assert_eq!(
+        setup.escrow.get_release_approval(&1).unwrap().contributor,
+        other_contributor
+    );
+}
+
+#[test]
+fn test_refund_clears_pending_release() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup.escrow.approve_release(&1, &setup.contributor);
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup
+        .escrow
+        .refund(&1, &None::<i128>, &None::<Address>, &RefundMode::Full);
+
+    assert_eq!(setup.escrow.total_pending_releases(), 0);
+}
+
+// ============================================================================
+// UPGRADE TESTS
+// ============================================================================
+
+/// Minimal replacement contract used to exercise `upgrade`. Equivalent WAT:
+///
+/// ```text
+/// (module
+///   (func (export "version") (result i64)
+///     i64.const 0x2_0000_0004)) ;; U32Val(2)
+/// ```
+///
+/// plus a `contractenvmetav0` custom section declaring protocol 21.
+const UPGRADED_WASM: &[u8] = &[
+    0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x05, 0x01, 0x60, 0x00, 0x01, 0x7e, 0x03,
+    0x02, 0x01, 0x00, 0x07, 0x0b, 0x01, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x00, 0x00,
+    0x0a, 0x0a, 0x01, 0x08, 0x00, 0x42, 0x84, 0x80, 0x80, 0x80, 0x20, 0x0b, 0x00, 0x1e, 0x11, 0x63,
+    0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x65, 0x6e, 0x76, 0x6d, 0x65, 0x74, 0x61, 0x76, 0x30,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00,
+];
+
+#[test]
+fn test_version_after_init() {
+    let setup = TestSetup::new();
+    assert_eq!(setup.escrow.version(), 1);
+}
+
+#[test]
+fn test_upgrade_preserves_state() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let amount = 1000;
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+
+    let wasm_hash = setup
+        .env
+        .deployer()
+        .upload_contract_wasm(Bytes::from_slice(&setup.env, UPGRADED_WASM));
+    setup.escrow.upgrade(&wasm_hash);
+
+    // The new code is live
+    assert_eq!(setup.escrow.version(), 2);
+
+    // Storage and balances written by the old code survive the upgrade
+    let escrow: Escrow = setup.env.as_contract(&setup.escrow_address, || {
+        setup
+            .env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap()
+    });
+    assert_eq!(escrow.depositor, setup.depositor);
+    assert_eq!(escrow.amount, amount);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+
+    let admin: Address = setup.env.as_contract(&setup.escrow_address, || {
+        setup.env.storage().instance().get(&DataKey::Admin).unwrap()
+    });
+    assert_eq!(admin, setup.admin);
+    assert_eq!(setup.token.balance(&setup.escrow_address), amount);
+}
+
+#[test]
+fn test_upgrade_requires_admin() {
+    let setup = TestSetup::new();
+    let wasm_hash = setup
+        .env
+        .deployer()
+        .upload_contract_wasm(Bytes::from_slice(&setup.env, UPGRADED_WASM));
+
+    setup.env.mock_auths(&[]);
+    assert!(setup.escrow.try_upgrade(&wasm_hash).is_err());
+    assert_eq!(setup.escrow.version(), 1);
+}
+
+// ============================================================================
+// REFUND SWAP TESTS
+// ============================================================================
+
+/// Fixed-rate AMM that can be told to under-deliver on swaps.
+#[contract]
+struct MockAmm;
+
+#[contractimpl]
+impl MockAmm {
+    pub fn configure(env: Env, rate: i128, delivered_bps: i128) {
+        env.storage().instance().set(&symbol_short!("rate"), &rate);
+        env.storage()
+            .instance()
+            .set(&symbol_short!("deliver"), &delivered_bps);
+    }
+
+    pub fn quote(env: Env, _token_in: Address, _token_out: Address, amount_in: i128) -> i128 {
+        let rate: i128 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("rate"))
+            .unwrap();
+        amount_in * rate
+    }
+
+    pub fn swap(
+        env: Env,
+        from: Address,
+        token_in: Address,
+        token_out: Address,
+        amount_in: i128,
+        _min_amount_out: i128,
+        to: Address,
+    ) -> i128 {
+        let delivered_bps: i128 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("deliver"))
+            .unwrap();
+        let amm = env.current_contract_address();
+        token::Client::new(&env, &token_in).transfer_from(&amm, &from, &amm, &amount_in);
+        let out = Self::quote(env.clone(), token_in, token_out.clone(), amount_in) * delivered_bps
+            / 10_000;
+        token::Client::new(&env, &token_out).transfer(&amm, &to, &out);
+        out
+    }
+}
+
+/// Registers a mock AMM holding a reserve of a fresh "stablecoin".
+fn setup_refund_swap<'a>(
+    setup: &TestSetup<'a>,
+    delivered_bps: i128,
+) -> (MockAmmClient<'a>, token::Client<'a>) {
+    let amm_id = setup.env.register_contract(None, MockAmm);
+    let amm = MockAmmClient::new(&setup.env, &amm_id);
+    amm.configure(&2, &delivered_bps);
+
+    let (stable, stable_admin) = create_token_contract(&setup.env, &setup.admin);
+    stable_admin.mint(&amm_id, &1_000_000);
+
+    setup.escrow.set_amm(&amm_id);
+    (amm, stable)
+}
+
+#[test]
+fn test_refund_arrives_in_chosen_token() {
+    let setup = TestSetup::new();
+    let (amm, stable) = setup_refund_swap(&setup, 10_000);
+    let bounty_id = 1;
+    let amount = 1000;
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup.escrow.lock_funds_with_refund_token(
+        &setup.depositor,
+        &bounty_id,
+        &amount,
+        &deadline,
+        &stable.address,
+        &1_980,
+    );
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup.escrow.refund(
+        &bounty_id,
+        &None::<i128>,
+        &None::<Address>,
+        &RefundMode::Full,
+    );
+
+    // Depositor receives the swapped token, not the escrowed one
+    assert_eq!(stable.balance(&setup.depositor), amount * 2);
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000 - amount);
+    assert_eq!(setup.token.balance(&amm.address), amount);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 0);
+
+    let escrow = setup.escrow.get_escrow_info(&bounty_id);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+}
+
+#[test]
+fn test_refund_swap_slippage_exceeded() {
+    let setup = TestSetup::new();
+    // AMM delivers 95% of the rate, depositor accepts no less than 99%
+    let (_amm, stable) = setup_refund_swap(&setup, 9_500);
+    let bounty_id = 1;
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup.escrow.lock_funds_with_refund_token(
+        &setup.depositor,
+        &bounty_id,
+        &1000,
+        &deadline,
+        &stable.address,
+        &1_980,
+    );
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    let result = setup.escrow.try_refund(
+        &bounty_id,
+        &None::<i128>,
+        &None::<Address>,
+        &RefundMode::Full,
+    );
+    assert_eq!(result, Err(Ok(Error::SlippageExceeded)));
+
+    // Nothing moved
+    assert_eq!(stable.balance(&setup.depositor), 0);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 1000);
+}
+
+#[test]
+fn test_lock_with_refund_token_requires_amm() {
+    let setup = TestSetup::new();
+    let stable = Address::generate(&setup.env);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    let result = setup.escrow.try_lock_funds_with_refund_token(
+        &setup.depositor,
+        &1,
+        &1000,
+        &deadline,
+        &stable,
+        &1_980,
+    );
+    assert_eq!(result, Err(Ok(Error::AmmNotSet)));
+}
+
+#[test]
+fn test_refund_falls_back_when_swap_fails() {
+    let setup = TestSetup::new();
+    // The AMM's reserve cannot cover the swap, so it reverts
+    let (amm, stable) = setup_refund_swap(&setup, 10_000_000);
+    let bounty_id = 1;
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup.escrow.lock_funds_with_refund_token(
+        &setup.depositor,
+        &bounty_id,
+        &1000,
+        &deadline,
+        &stable.address,
+        &1_980,
+    );
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup.escrow.refund(
+        &bounty_id,
+        &None::<i128>,
+        &None::<Address>,
+        &RefundMode::Full,
+    );
+
+    // Paid in the escrowed token, with no allowance left for the AMM
+    assert_eq!(stable.balance(&setup.depositor), 0);
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000);
+    assert_eq!(setup.token.balance(&amm.address), 0);
+    assert_eq!(
+        setup.token.allowance(&setup.escrow_address, &amm.address),
+        0
+    );
+    assert_eq!(
+        setup.escrow.get_escrow_info(&bounty_id).status,
+        EscrowStatus::Refunded
+    );
+}
+
+// ============================================================================
+// TOKEN UPDATE TESTS
+// ============================================================================
+
+#[test]
+fn test_total_locked_tracks_escrows() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &500, &deadline);
+    assert_eq!(setup.escrow.get_total_locked(), 1500);
+
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.escrow.get_total_locked(), 500);
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup
+        .escrow
+        .refund(&2, &Some(200), &None::<Address>, &RefundMode::Partial);
+    assert_eq!(setup.escrow.get_total_locked(), 300);
+}
+
+#[test]
+fn test_update_token_rejected_while_funds_locked() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let (new_token, _) = create_token_contract(&setup.env, &setup.admin);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+
+    let result = setup.escrow.try_update_token(&new_token.address);
+    assert_eq!(result, Err(Ok(Error::EscrowsOutstanding)));
+}
+
+#[test]
+fn test_update_token_when_nothing_outstanding() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let (new_token, new_token_admin) = create_token_contract(&setup.env, &setup.admin);
+
+    // Lock and settle so the counter returns to zero
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.escrow.get_total_locked(), 0);
+
+    setup.escrow.update_token(&new_token.address);
+
+    // New escrows use the new token
+    new_token_admin.mint(&setup.depositor, &5000);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &5000, &deadline);
+    assert_eq!(new_token.balance(&setup.escrow_address), 5000);
+    assert_eq!(setup.escrow.get_balance(), 5000);
+}
+
+// ============================================================================
+// ORACLE MILESTONE TESTS
+// ============================================================================
+
+#[contract]
+struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn set_met(env: Env, metric: Symbol, met: bool) {
+        env.storage().instance().set(&metric, &met);
+    }
+
+    pub fn is_met(env: Env, _bounty_id: u64, metric: Symbol) -> bool {
+        env.storage().instance().get(&metric).unwrap_or(false)
+    }
+}
+
+fn lock_milestone_bounty<'a>(setup: &TestSetup<'a>, bounty_id: u64) -> MockOracleClient<'a> {
+    let oracle_id = setup.env.register_contract(None, MockOracle);
+    let oracle = MockOracleClient::new(&setup.env, &oracle_id);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup.escrow.lock_with_milestones_oracle(
+        &setup.depositor,
+        &setup.contributor,
+        &bounty_id,
+        &vec![
+            &setup.env,
+            (300_i128, symbol_short!("stars")),
+            (700_i128, symbol_short!("users")),
+        ],
+        &oracle_id,
+        &deadline,
+    );
+    oracle
+}
+
+#[test]
+fn test_milestones_released_as_metrics_are_met() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let oracle = lock_milestone_bounty(&setup, bounty_id);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 1000);
+
+    // Nothing met yet
+    assert!(!setup.escrow.check_milestone(&bounty_id, &0));
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+
+    // First metric met
+    oracle.set_met(&symbol_short!("stars"), &true);
+    assert!(setup.escrow.check_milestone(&bounty_id, &0));
+    assert_eq!(setup.token.balance(&setup.contributor), 300);
+    let escrow = setup.escrow.get_escrow_info(&bounty_id);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(escrow.remaining_amount, 700);
+
+    // Second metric met completes the bounty
+    oracle.set_met(&symbol_short!("users"), &true);
+    assert!(setup.escrow.check_milestone(&bounty_id, &1));
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+    let escrow = setup.escrow.get_escrow_info(&bounty_id);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(setup.escrow.get_total_locked(), 0);
+}
+
+#[test]
+fn test_milestone_cannot_be_released_twice() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let oracle = lock_milestone_bounty(&setup, bounty_id);
+
+    oracle.set_met(&symbol_short!("stars"), &true);
+    setup.escrow.check_milestone(&bounty_id, &0);
+
+    let result = setup.escrow.try_check_milestone(&bounty_id, &0);
+    assert_eq!(result, Err(Ok(Error::MilestoneAlreadyReleased)));
+    let result = setup.escrow.try_check_milestone(&bounty_id, &5);
+    assert_eq!(result, Err(Ok(Error::MilestoneNotFound)));
+}
+
+#[test]
+fn test_release_after_partial_milestones_pays_remainder() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let oracle = lock_milestone_bounty(&setup, bounty_id);
+
+    oracle.set_met(&symbol_short!("stars"), &true);
+    setup.escrow.check_milestone(&bounty_id, &0);
+
+    setup.escrow.release_funds(&bounty_id, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 0);
+}
+
+// ============================================================================
+// AMOUNT LIMIT TESTS
+// ============================================================================
+
+#[test]
+fn test_lock_amount_limits_boundaries() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup.escrow.update_limits(&100, &1000);
+
+    let result = setup
+        .escrow
+        .try_lock_funds(&setup.depositor, &1, &99, &deadline);
+    assert_eq!(result, Err(Ok(Error::AmountTooSmall)));
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &100, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &3, &1000, &deadline);
+
+    let result = setup
+        .escrow
+        .try_lock_funds(&setup.depositor, &4, &1001, &deadline);
+    assert_eq!(result, Err(Ok(Error::AmountTooLarge)));
+}
+
+#[test]
+fn test_lock_amount_zero_max_means_no_cap() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup.escrow.update_limits(&10, &0);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000_000, &deadline);
+    assert_eq!(setup.escrow.get_escrow_info(&1).amount, 1_000_000);
+}
+
+#[test]
+fn test_batch_lock_respects_amount_limits() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup.escrow.update_limits(&100, &1000);
+
+    let items = vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: setup.depositor.clone(),
+            amount: 500,
+            deadline,
+        },
+        LockFundsItem {
+            bounty_id: 2,
+            depositor: setup.depositor.clone(),
+            amount: 5000,
+            deadline,
+        },
+    ];
+    let result = setup.escrow.try_batch_lock_funds(&items);
+    assert_eq!(result, Err(Ok(Error::AmountTooLarge)));
+}
+
+#[test]
+fn test_update_limits_rejects_inverted_range() {
+    let setup = TestSetup::new();
+    let result = setup.escrow.try_update_limits(&1000, &100);
+    assert_eq!(result, Err(Ok(Error::InvalidAmount)));
+    assert_eq!(
+        setup.escrow.get_amount_limits(),
+        AmountLimits {
+            min_amount: 0,
+            max_amount: 0
+        }
+    );
+}
+
+// ============================================================================
+// DEADLINE HORIZON TESTS
+// ============================================================================
+
+#[test]
+fn test_deadline_horizon_boundaries() {
+    let setup = TestSetup::new();
+    setup.escrow.update_max_deadline(&3600);
+    let now = setup.env.ledger().timestamp();
+
+    // Exactly at the horizon is accepted
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &(now + 3600));
+
+    // One second beyond is rejected
+    let result = setup
+        .escrow
+        .try_lock_funds(&setup.depositor, &2, &1000, &(now + 3601));
+    assert_eq!(result, Err(Ok(Error::DeadlineTooFar)));
+}
+
+#[test]
+fn test_default_deadline_horizon() {
+    let setup = TestSetup::new();
+    let year = 365 * 24 * 60 * 60;
+    assert_eq!(setup.escrow.get_max_deadline(), year);
+
+    let now = setup.env.ledger().timestamp();
+    let result = setup
+        .escrow
+        .try_lock_funds(&setup.depositor, &1, &1000, &(now + year + 1));
+    assert_eq!(result, Err(Ok(Error::DeadlineTooFar)));
+}
+
+// ============================================================================
+// CONTRIBUTOR ELIGIBILITY TESTS
+// ============================================================================
+
+/// Locks bounty 1 with every eligibility gate switched on.
+fn setup_gated_bounty<'a>() -> TestSetup<'a> {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+    setup.escrow.update_eligibility_config(&true, &true, &1);
+    setup
+}
+
+#[test]
+fn test_contributor_fully_eligible() {
+    let setup = setup_gated_bounty();
+
+    setup.escrow.register_contributor(&setup.contributor);
+    setup.escrow.accept_terms(&setup.contributor, &1);
+    setup.escrow.approve_release(&1, &setup.contributor);
+
+    assert!(setup.escrow.contributor_eligible(&1, &setup.contributor));
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+}
+
+#[test]
+fn test_contributor_not_registered() {
+    let setup = setup_gated_bounty();
+    let result = setup
+        .escrow
+        .try_contributor_eligible(&1, &setup.contributor);
+    assert_eq!(result, Err(Ok(Error::ContributorNotRegistered)));
+}
+
+#[test]
+fn test_contributor_terms_not_accepted() {
+    let setup = setup_gated_bounty();
+    setup.escrow.register_contributor(&setup.contributor);
+
+    let result = setup
+        .escrow
+        .try_contributor_eligible(&1, &setup.contributor);
+    assert_eq!(result, Err(Ok(Error::TermsNotAccepted)));
+
+    // Accepting an outdated version doesn't count
+    let result = setup.escrow.try_accept_terms(&setup.contributor, &2);
+    assert_eq!(result, Err(Ok(Error::TermsNotAccepted)));
+}
+
+#[test]
+fn test_contributor_not_approved() {
+    let setup = setup_gated_bounty();
+    let other = Address::generate(&setup.env);
+    setup.escrow.register_contributor(&setup.contributor);
+    setup.escrow.accept_terms(&setup.contributor, &1);
+
+    let result = setup
+        .escrow
+        .try_contributor_eligible(&1, &setup.contributor);
+    assert_eq!(result, Err(Ok(Error::ReleaseNotApproved)));
+
+    // Approval for someone else doesn't count either
+    setup.escrow.approve_release(&1, &other);
+    let result = setup
+        .escrow
+        .try_contributor_eligible(&1, &setup.contributor);
+    assert_eq!(result, Err(Ok(Error::ReleaseNotApproved)));
+}
+
+#[test]
+fn test_contributor_denied_blocks_release() {
+    let setup = setup_gated_bounty();
+    setup.escrow.register_contributor(&setup.contributor);
+    setup.escrow.accept_terms(&setup.contributor, &1);
+    setup.escrow.approve_release(&1, &setup.contributor);
+    setup
+        .escrow
+        .set_contributor_denied(&setup.contributor, &true);
+
+    let result = setup
+        .escrow
+        .try_contributor_eligible(&1, &setup.contributor);
+    assert_eq!(result, Err(Ok(Error::ContributorDenied)));
+
+    let result = setup.escrow.try_release_funds(&1, &setup.contributor);
+    assert_eq!(result, Err(Ok(Error::ContributorDenied)));
+}
+
+#[test]
+fn test_batch_release_enforces_eligibility() {
+    let setup = setup_gated_bounty();
+    let items = vec![
+        &setup.env,
+        ReleaseFundsItem {
+            bounty_id: 1,
+            contributor: setup.contributor.clone(),
+        },
+    ];
+    setup.escrow.register_contributor(&setup.contributor);
+    setup.escrow.accept_terms(&setup.contributor, &1);
+
+    let result = setup.escrow.try_batch_release_funds(&items);
+    assert_eq!(result, Err(Ok(Error::ReleaseNotApproved)));
+
+    setup.escrow.approve_release(&1, &setup.contributor);
+    setup
+        .escrow
+        .set_contributor_denied(&setup.contributor, &true);
+    let result = setup.escrow.try_batch_release_funds(&items);
+    assert_eq!(result, Err(Ok(Error::ContributorDenied)));
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+
+    setup
+        .escrow
+        .set_contributor_denied(&setup.contributor, &false);
+    assert_eq!(setup.escrow.batch_release_funds(&items), 1);
+    assert_eq!(setup.token.balance(&setup.contributor), 1000);
+}
+
+#[test]
+fn test_contributor_eligible_bounty_not_found() {
+    let setup = TestSetup::new();
+    let result = setup
+        .escrow
+        .try_contributor_eligible(&42, &setup.contributor);
+    assert_eq!(result, Err(Ok(Error::BountyNotFound)));
+}
+
+// ============================================================================
+// DEPOSITOR INDEX TESTS
+// ============================================================================
+
+#[test]
+fn test_get_bounties_by_depositor() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let other_depositor = Address::generate(&setup.env);
+    setup.token_admin.mint(&other_depositor, &1_000_000);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &100, &deadline);
+    setup
+        .escrow
+        .lock_funds(&other_depositor, &2, &100, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &3, &100, &deadline);
+
+    assert_eq!(
+        setup
+            .escrow
+            .get_bounties_by_depositor(&setup.depositor, &0, &10),
+        vec![&setup.env, 1_u64, 3]
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .get_bounties_by_depositor(&other_depositor, &0, &10),
+        vec![&setup.env, 2_u64]
+    );
+
+    // Settled bounties stay in the index
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(
+        setup
+            .escrow
+            .get_bounties_by_depositor(&setup.depositor, &0, &10),
+        vec![&setup.env, 1_u64, 3]
+    );
+}
+
+#[test]
+fn test_get_bounties_by_depositor_pagination() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    for bounty_id in 1..=5_u64 {
+        setup
+            .escrow
+            .lock_funds(&setup.depositor, &bounty_id, &100, &deadline);
+    }
+
+    assert_eq!(
+        setup
+            .escrow
+            .get_bounties_by_depositor(&setup.depositor, &1, &2),
+        vec![&setup.env, 2_u64, 3]
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .get_bounties_by_depositor(&setup.depositor, &4, &10),
+        vec![&setup.env, 5_u64]
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .get_bounties_by_depositor(&setup.depositor, &10, &10)
+            .len(),
+        0
+    );
+}
+
+// ============================================================================
+// REFUND PENALTY TESTS
+// ============================================================================
+
+#[test]
+fn test_refund_penalty_tracked_separately_from_fees() {
+    let setup = TestSetup::new();
+    let bounty_id = 1;
+    let amount = 10_000;
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let collector = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+
+    // 5% refund penalty
+    setup.escrow.update_penalty_config(&500, &collector);
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup.escrow.refund(
+        &bounty_id,
+        &None::<i128>,
+        &None::<Address>,
+        &RefundMode::Full,
+    );
+
+    assert_eq!(setup.escrow.total_penalties_collected(), 500);
+    assert_eq!(setup.escrow.total_fees_collected(), 0);
+    assert_eq!(setup.token.balance(&collector), 500);
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000 - 500);
+
+    // The full amount left escrow
+    let escrow = setup.escrow.get_escrow_info(&bounty_id);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 0);
+}
+
+#[test]
+fn test_fees_do_not_count_as_penalties() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let fee_recipient = Address::generate(&setup.env);
+
+    setup.escrow.update_fee_config(
+        &Some(100),
+        &Some(0),
+        &Some(fee_recipient.clone()),
+        &Some(true),
+    );
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &10_000, &deadline);
+
+    assert_eq!(setup.escrow.total_fees_collected(), 100);
+    assert_eq!(setup.escrow.total_penalties_collected(), 0);
+}
+
+// ============================================================================
+// FEATURE SUPPORT TESTS
+// ============================================================================
+
+#[test]
+fn test_supports_reflects_configuration() {
+    let setup = TestSetup::new();
+
+    assert!(!setup.escrow.supports(&FEATURE_FEES));
+
+    setup
+        .escrow
+        .update_fee_config(&Some(100), &None::<i128>, &None::<Address>, &Some(true));
+
+    assert!(setup.escrow.supports(&FEATURE_FEES));
+    assert!(!setup.escrow.supports(&FEATURE_PENALTIES));
+    assert!(!setup.escrow.supports(&FEATURE_REFUND_SWAP));
+    assert!(setup.escrow.supports(&FEATURE_DISPUTES));
+    assert!(!setup.escrow.supports(&symbol_short!("multisig")));
+}
+
+// ============================================================================
+// MONITORING TESTS
+// ============================================================================
+
+#[test]
+fn test_performance_stats_count_calls_and_errors() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+
+    let stats = setup.escrow.get_performance_stats(&symbol_short!("lock"));
+    assert_eq!(stats.call_count, 1);
+    assert_eq!(stats.error_count, 0);
+    assert_eq!(setup.escrow.get_analytics().error_count, 0);
+
+    // A failing top-level call is rolled back, counters included, so drive
+    // the failure from inside the contract frame.
+    setup.env.as_contract(&setup.escrow_address, || {
+        let result = BountyEscrowContract::lock_funds(
+            setup.env.clone(),
+            setup.depositor.clone(),
+            1,
+            1_000,
+            deadline,
+        );
+        assert_eq!(result, Err(Error::BountyExists));
+    });
+
+    let stats = setup.escrow.get_performance_stats(&symbol_short!("lock"));
+    assert_eq!(stats.call_count, 1);
+    assert_eq!(stats.error_count, 1);
+    assert_eq!(setup.escrow.get_analytics().error_count, 1);
+}
+
+#[test]
+fn test_all_performance_stats_lists_every_tracked_function() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    for bounty_id in 1..=2u64 {
+        setup
+            .escrow
+            .lock_funds(&setup.depositor, &bounty_id, &1_000, &deadline);
+    }
+    setup.escrow.release_funds(&1, &setup.contributor);
+
+    let all = setup.escrow.get_all_performance_stats();
+    let expected = [
+        (symbol_short!("init"), 1u64),
+        (symbol_short!("lock"), 2),
+        (symbol_short!("release"), 1),
+    ];
+    assert_eq!(all.len(), expected.len() as u32);
+    for ((function, stats), (name, calls)) in all.iter().zip(expected) {
+        assert_eq!(function, name);
+        assert_eq!(stats.function_name, name);
+        assert_eq!(stats.call_count, calls);
+        assert_eq!(
+            stats.call_count,
+            setup.escrow.get_performance_stats(&name).call_count
+        );
+    }
+}
+
+#[test]
+fn test_health_check_flags_insolvency() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+
+    let health = setup.escrow.health_check();
+    assert!(health.is_healthy);
+    assert!(!health.insolvent);
+
+    // Move tokens out of the escrow behind its back
+    let drain = Address::generate(&setup.env);
+    setup.token.transfer(&setup.escrow_address, &drain, &1);
+
+    let health = setup.escrow.health_check();
+    assert!(health.insolvent);
+    assert!(!health.is_healthy);
+}
+
+#[test]
+fn test_stuck_reentrancy_guard_detected_and_cleared() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    assert!(!setup.escrow.health_check().reentrancy_locked);
+
+    // Simulate a guard left behind by an aborted call
+    setup.env.as_contract(&setup.escrow_address, || {
+        setup
+            .env
+            .storage()
+            .temporary()
+            .set(&DataKey::ReentrancyGuard, &true);
+    });
+
+    let health = setup.escrow.health_check();
+    assert!(health.reentrancy_locked);
+    assert!(!health.is_healthy);
+    assert!(setup
+        .escrow
+        .try_lock_funds_str(
+            &setup.depositor,
+            &soroban_sdk::String::from_str(&setup.env, "a"),
+            &1_000,
+            &deadline
+        )
+        .is_err());
+
+    assert!(setup.escrow.force_clear_reentrancy_guard());
+
+    let health = setup.escrow.health_check();
+    assert!(!health.reentrancy_locked);
+    assert!(health.is_healthy);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+}
+
+#[test]
+fn test_failed_call_leaves_no_reentrancy_guard() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+
+    // Duplicate bounty id fails after the guard has been taken
+    assert!(setup
+        .escrow
+        .try_lock_funds(&setup.depositor, &1, &1_000, &deadline)
+        .is_err());
+    assert!(!setup.escrow.health_check().reentrancy_locked);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1_000, &deadline);
+    setup.escrow.release_funds(&2, &setup.contributor);
+}
+
+#[test]
+fn test_stale_reentrancy_guard_expires() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 100_000;
+
+    setup.env.as_contract(&setup.escrow_address, || {
+        setup
+            .env
+            .storage()
+            .temporary()
+            .set(&DataKey::ReentrancyGuard, &true);
+    });
+    assert!(setup.escrow.health_check().reentrancy_locked);
+
+    // Once the entry's TTL lapses the guard is gone without admin action
+    setup.env.ledger().with_mut(|li| li.sequence_number += 100);
+    assert!(!setup.escrow.health_check().reentrancy_locked);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+}
+
+// ============================================================================
+// VIEW FUNCTION TESTS
+// ============================================================================
+
+#[test]
+fn test_bounty_exists() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    assert!(!setup.escrow.bounty_exists(&7));
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &7, &1_000, &deadline);
+    assert!(setup.escrow.bounty_exists(&7));
+    assert!(!setup.escrow.bounty_exists(&8));
+}
+
+#[test]
+fn test_get_escrows_aligns_with_input() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &3, &3_000, &deadline);
+
+    let escrows = setup.escrow.get_escrows(&vec![&setup.env, 3, 2, 1, 4]);
+    assert_eq!(escrows.len(), 4);
+    assert_eq!(escrows.get(0).unwrap().unwrap().amount, 3_000);
+    assert!(escrows.get(1).unwrap().is_none());
+    assert_eq!(escrows.get(2).unwrap().unwrap().amount, 1_000);
+    assert!(escrows.get(3).unwrap().is_none());
+}
+
+#[test]
+fn test_get_escrows_rejects_oversized_request() {
+    let setup = TestSetup::new();
+    let mut ids = Vec::new(&setup.env);
+    for i in 0..=MAX_BATCH_SIZE {
+        ids.push_back(i as u64);
+    }
+    assert_eq!(
+        setup.escrow.try_get_escrows(&ids),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+}
+
+#[test]
+fn test_get_remaining_time() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &(now + 1000));
+
+    assert_eq!(setup.escrow.get_remaining_time(&1), 1000);
+
+    setup.env.ledger().with_mut(|li| li.timestamp = now + 400);
+    assert_eq!(setup.escrow.get_remaining_time(&1), 600);
+
+    setup.env.ledger().with_mut(|li| li.timestamp = now + 5000);
+    assert_eq!(setup.escrow.get_remaining_time(&1), 0);
+
+    assert_eq!(
+        setup.escrow.try_get_remaining_time(&2),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+// ============================================================================
+// NATIVE TOKEN TESTS
+// ============================================================================
+
+#[test]
+fn test_init_native_lock_and_release() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (escrow, escrow_address) = create_escrow_contract(&env);
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+
+    // The test ledger has no native SAC until one is deployed
+    let native = env
+        .deployer()
+        .with_stellar_asset(Bytes::from_array(&env, &NATIVE_ASSET_XDR))
+        .deploy();
+    escrow.init_native(&admin);
+    let stored: Address = env.as_contract(&escrow_address, || {
+        env.storage().instance().get(&DataKey::Token).unwrap()
+    });
+    assert_eq!(stored, native);
+
+    // Native XLM has no issuer; give the SAC a test admin so it can mint
+    let minter = Address::generate(&env);
+    env.as_contract(&native, || {
+        env.storage()
+            .instance()
+            .set(&vec![&env, symbol_short!("Admin")], &minter);
+    });
+    let xlm = token::Client::new(&env, &native);
+    token::StellarAssetClient::new(&env, &native).mint(&depositor, &50_000_000);
+
+    let deadline = env.ledger().timestamp() + 1000;
+    escrow.lock_funds(&depositor, &1, &50_000_000, &deadline);
+    assert_eq!(xlm.balance(&escrow_address), 50_000_000);
+
+    escrow.release_funds(&1, &contributor);
+    assert_eq!(xlm.balance(&contributor), 50_000_000);
+}
+
+// ============================================================================
+// FEE-ON-TRANSFER TOKEN TESTS
+// ============================================================================
+
+/// Token that burns 1% of every transfer, so recipients get less than sent.
+#[contract]
+struct MockFeeToken;
+
+#[contractimpl]
+impl MockFeeToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), to.clone());
+        env.storage().persistent().set(&to, &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().persistent().get(&id).unwrap_or(0)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        let from_balance = Self::balance(env.clone(), from.clone());
+        env.storage()
+            .persistent()
+            .set(&from, &(from_balance - amount));
+        let to_balance = Self::balance(env.clone(), to.clone());
+        env.storage()
+            .persistent()
+            .set(&to, &(to_balance + amount - amount / 100));
+    }
+}
+
+#[test]
+fn test_lock_funds_records_amount_received_from_fee_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (escrow, escrow_address) = create_escrow_contract(&env);
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+
+    let token_id = env.register_contract(None, MockFeeToken);
+    let token = MockFeeTokenClient::new(&env, &token_id);
+    token.mint(&depositor, &10_000);
+    escrow.init(&admin, &token_id);
+
+    let deadline = env.ledger().timestamp() + 1000;
+    escrow.lock_funds(&depositor, &1, &10_000, &deadline);
+
+    let info = escrow.get_escrow_info(&1);
+    assert_eq!(token.balance(&escrow_address), 9_900);
+    assert_eq!(info.amount, 9_900);
+    assert_eq!(info.remaining_amount, 9_900);
+    assert_eq!(escrow.get_total_locked(), 9_900);
+    assert!(!escrow.health_check().insolvent);
+
+    let locked = env
+        .events()
+        .all()
+        .iter()
+        .find(|e| e.1 == (symbol_short!("f_lock"), 1u64, depositor.clone()).into_val(&env))
+        .unwrap();
+    let event: events::FundsLocked = locked.2.into_val(&env);
+    assert_eq!(event.amount, 9_900);
+}
+
+#[test]
+fn test_batch_lock_funds_records_amount_received_from_fee_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (escrow, escrow_address) = create_escrow_contract(&env);
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+
+    let token_id = env.register_contract(None, MockFeeToken);
+    let token = MockFeeTokenClient::new(&env, &token_id);
+    token.mint(&depositor, &15_000);
+    escrow.init(&admin, &token_id);
+
+    let deadline = env.ledger().timestamp() + 1000;
+    let items = vec![
+        &env,
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: depositor.clone(),
+            amount: 10_000,
+            deadline,
+        },
+        LockFundsItem {
+            bounty_id: 2,
+            depositor: depositor.clone(),
+            amount: 5_000,
+            deadline,
+        },
+    ];
+    escrow.batch_lock_funds(&items);
+
+    assert_eq!(token.balance(&escrow_address), 14_850);
+    assert_eq!(escrow.get_escrow_info(&1).remaining_amount, 9_900);
+    assert_eq!(escrow.get_escrow_info(&2).remaining_amount, 4_950);
+    assert_eq!(escrow.get_total_locked(), 14_850);
+    assert!(!escrow.health_check().insolvent);
+}
+
+/// Token whose transfers panic while frozen, standing in for an asset that
+/// is temporarily frozen by its issuer.
+mod freezable_token {
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+    #[contract]
+    pub struct MockFreezableToken;
+
+    #[contractimpl]
+    impl MockFreezableToken {
+        pub fn mint(env: Env, to: Address, amount: i128) {
+            let balance = Self::balance(env.clone(), to.clone());
+            env.storage().persistent().set(&to, &(balance + amount));
+        }
+
+        pub fn balance(env: Env, id: Address) -> i128 {
+            env.storage().persistent().get(&id).unwrap_or(0)
+        }
+
+        pub fn set_frozen(env: Env, frozen: bool) {
+            env.storage()
+                .instance()
+                .set(&symbol_short!("frozen"), &frozen);
+        }
+
+        pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+            from.require_auth();
+            let frozen: bool = env
+                .storage()
+                .instance()
+                .get(&symbol_short!("frozen"))
+                .unwrap_or(false);
+            if frozen {
+                panic!("token frozen");
+            }
+            let from_balance = Self::balance(env.clone(), from.clone());
+            env.storage()
+                .persistent()
+                .set(&from, &(from_balance - amount));
+            let to_balance = Self::balance(env.clone(), to.clone());
+            env.storage().persistent().set(&to, &(to_balance + amount));
+        }
+    }
+}
+use freezable_token::{MockFreezableToken, MockFreezableTokenClient};
+
+#[test]
+fn test_retry_release_after_failed_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (escrow, escrow_address) = create_escrow_contract(&env);
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+
+    let token_id = env.register_contract(None, MockFreezableToken);
+    let token = MockFreezableTokenClient::new(&env, &token_id);
+    token.mint(&depositor, &1_000);
+    escrow.init(&admin, &token_id);
+    let deadline = env.ledger().timestamp() + 1000;
+    escrow.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    token.set_frozen(&true);
+    assert!(escrow.try_release_funds(&1, &contributor).is_err());
+    let info = escrow.get_escrow_info(&1);
+    assert_eq!(info.status, EscrowStatus::Locked);
+    assert_eq!(info.remaining_amount, 1_000);
+    assert_eq!(token.balance(&escrow_address), 1_000);
+
+    token.set_frozen(&false);
+    escrow.retry_release(&1, &contributor);
+    assert_eq!(escrow.get_escrow_info(&1).status, EscrowStatus::Released);
+    assert_eq!(token.balance(&contributor), 1_000);
+    let data = find_event(&env, symbol_short!("rel_retry")).unwrap();
+    let event: events::ReleaseRetried = data.into_val(&env);
+    assert_eq!(event.bounty_id, 1);
+    assert_eq!(event.contributor, contributor);
+
+    assert_eq!(
+        escrow.try_retry_release(&1, &contributor),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
+
+#[test]
+fn test_failed_transfer_leaves_escrows_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (escrow, escrow_address) = create_escrow_contract(&env);
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+
+    let token_id = env.register_contract(None, MockFreezableToken);
+    let token = MockFreezableTokenClient::new(&env, &token_id);
+    token.mint(&depositor, &2_000);
+    escrow.init(&admin, &token_id);
+    let deadline = env.ledger().timestamp() + 1000;
+    escrow.lock_funds(&depositor, &1, &1_000, &deadline);
+    escrow.lock_funds(&depositor, &2, &1_000, &deadline);
+
+    token.set_frozen(&true);
+    assert!(escrow.try_release_funds(&1, &contributor).is_err());
+    let items = vec![
+        &env,
+        ReleaseFundsItem {
+            bounty_id: 1,
+            contributor: contributor.clone(),
+        },
+        ReleaseFundsItem {
+            bounty_id: 2,
+            contributor: contributor.clone(),
+        },
+    ];
+    assert!(escrow.try_batch_release_funds(&items).is_err());
+
+    for bounty_id in [1u64, 2] {
+        let info = escrow.get_escrow_info(&bounty_id);
+        assert_eq!(info.status, EscrowStatus::Locked);
+        assert_eq!(info.remaining_amount, 1_000);
+    }
+    assert_eq!(escrow.get_total_locked(), 2_000);
+    assert_eq!(token.balance(&escrow_address), 2_000);
+}
+
+// ============================================================================
+// ADMIN AND CONFIG EVENT TESTS
+// ============================================================================
+
+/// Returns the data of the last event whose first topic is `topic`.
+fn find_event(env: &Env, topic: Symbol) -> Option<soroban_sdk::Val> {
+    let topic_val: soroban_sdk::Val = topic.into_val(env);
+    env.events()
+        .all()
+        .iter()
+        .filter(|e| {
+            e.1.get(0)
+                .map(|t| t.shallow_eq(&topic_val))
+                .unwrap_or(false)
+        })
+        .last()
+        .map(|e| e.2)
+}
+
+#[test]
+fn test_transfer_admin_emits_admin_transferred() {
+    let setup = TestSetup::new();
+    let new_admin = Address::generate(&setup.env);
+
+    setup.escrow.transfer_admin(&new_admin);
+
+    let stored: Address = setup.env.as_contract(&setup.escrow_address, || {
+        setup.env.storage().instance().get(&DataKey::Admin).unwrap()
+    });
+    assert_eq!(stored, new_admin);
+
+    let data = find_event(&setup.env, symbol_short!("admin")).unwrap();
+    let event: events::AdminTransferred = data.into_val(&setup.env);
+    assert_eq!(event.previous_admin, setup.admin);
+    assert_eq!(event.new_admin, new_admin);
+}
+
+#[test]
+fn test_fee_config_update_emits_event() {
+    let setup = TestSetup::new();
+    setup
+        .escrow
+        .update_fee_config(&Some(100), &Some(200), &None, &Some(true));
+
+    let data = find_event(&setup.env, symbol_short!("fee_cfg")).unwrap();
+    let event: events::FeeConfigUpdated = data.into_val(&setup.env);
+    assert_eq!(event.lock_fee_rate, 100);
+    assert_eq!(event.release_fee_rate, 200);
+    assert!(event.fee_enabled);
+}
+
+#[test]
+fn test_pause_emits_contract_paused() {
+    let setup = TestSetup::new();
+    setup.escrow.pause();
+
+    let data = find_event(&setup.env, symbol_short!("pause")).unwrap();
+    let event: events::ContractPaused = data.into_val(&setup.env);
+    assert_eq!(event.paused_by, setup.admin);
+}
+
+#[test]
+fn test_update_token_emits_token_updated() {
+    let setup = TestSetup::new();
+    let (new_token, _) = create_token_contract(&setup.env, &setup.admin);
+    setup.escrow.update_token(&new_token.address);
+
+    let data = find_event(&setup.env, symbol_short!("token")).unwrap();
+    let event: events::TokenUpdated = data.into_val(&setup.env);
+    assert_eq!(event.old, setup.token.address);
+    assert_eq!(event.new, new_token.address);
+}
+
+#[test]
+fn test_lock_and_release_events_index_addresses() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+    let (_, topics, _) = setup
+        .env
+        .events()
+        .all()
+        .iter()
+        .find(|e| {
+            e.1.get(0)
+                .unwrap()
+                .shallow_eq(&symbol_short!("f_lock").into_val(&setup.env))
+        })
+        .unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("f_lock"), 1u64, setup.depositor.clone()).into_val(&setup.env)
+    );
+
+    setup.escrow.release_funds(&1, &setup.contributor);
+    let (_, topics, _) = setup
+        .env
+        .events()
+        .all()
+        .iter()
+        .find(|e| {
+            e.1.get(0)
+                .unwrap()
+                .shallow_eq(&symbol_short!("f_rel").into_val(&setup.env))
+        })
+        .unwrap();
+    let recipient: Address = topics.get(2).unwrap().into_val(&setup.env);
+    assert_eq!(recipient, setup.contributor);
+}
+
+// ============================================================================
+// CONTRIBUTOR ASSIGNMENT TESTS
+// ============================================================================
+
+#[test]
+fn test_assign_contributor_and_release_to_assigned() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+
+    assert!(setup.escrow.get_assigned_contributor(&1).is_none());
+    assert_eq!(
+        setup.escrow.try_release_to_assigned(&1),
+        Err(Ok(Error::NoContributorAssigned))
+    );
+
+    setup
+        .escrow
+        .assign_contributor(&1, &setup.contributor, &false);
+    let assignment = setup.escrow.get_assigned_contributor(&1).unwrap();
+    assert_eq!(assignment.contributor, setup.contributor);
+
+    let data = find_event(&setup.env, symbol_short!("assign")).unwrap();
+    let event: events::ContributorAssigned = data.into_val(&setup.env);
+    assert_eq!(event.bounty_id, 1);
+    assert_eq!(event.contributor, setup.contributor);
+
+    setup.escrow.release_to_assigned(&1);
+    assert_eq!(setup.token.balance(&setup.contributor), 1_000);
+}
+
+#[test]
+fn test_strict_assignment_rejects_other_recipient() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let other = Address::generate(&setup.env);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+
+    setup
+        .escrow
+        .assign_contributor(&1, &setup.contributor, &true);
+    assert_eq!(
+        setup.escrow.try_release_funds(&1, &other),
+        Err(Ok(Error::ContributorMismatch))
+    );
+
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 1_000);
+}
+
+#[test]
+fn test_non_strict_assignment_allows_other_recipient() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let other = Address::generate(&setup.env);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+
+    setup
+        .escrow
+        .assign_contributor(&1, &setup.contributor, &false);
+    setup.escrow.release_funds(&1, &other);
+    assert_eq!(setup.token.balance(&other), 1_000);
+
+    assert_eq!(
+        setup.escrow.try_assign_contributor(&1, &other, &false),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
+
+// ============================================================================
+// TVL CAP TESTS
+// ============================================================================
+
+#[test]
+fn test_lock_funds_up_to_tvl_cap() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    assert_eq!(setup.escrow.get_max_tvl(), 0);
+    setup.escrow.update_max_tvl(&2_500);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1_500, &deadline);
+    assert_eq!(setup.escrow.get_total_locked(), 2_500);
+
+    assert_eq!(
+        setup
+            .escrow
+            .try_lock_funds(&setup.depositor, &3, &1, &deadline),
+        Err(Ok(Error::TvlCapExceeded))
+    );
+
+    // Releasing frees room under the cap
+    setup.escrow.release_funds(&1, &setup.contributor);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &3, &1_000, &deadline);
+}
+
+#[test]
+fn test_batch_lock_respects_tvl_cap() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup.escrow.update_max_tvl(&1_500);
+
+    let items = vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: setup.depositor.clone(),
+            amount: 1_000,
+            deadline,
+        },
+        LockFundsItem {
+            bounty_id: 2,
+            depositor: setup.depositor.clone(),
+            amount: 1_000,
+            deadline,
+        },
+    ];
+    assert_eq!(
+        setup.escrow.try_batch_lock_funds(&items),
+        Err(Ok(Error::TvlCapExceeded))
+    );
+    assert_eq!(setup.escrow.get_total_locked(), 0);
+}
+
+#[test]
+fn test_batch_lock_applies_lock_checks_and_fee() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let fee_recipient = Address::generate(&setup.env);
+    let item = |bounty_id: u64, deadline: u64| LockFundsItem {
+        bounty_id,
+        depositor: setup.depositor.clone(),
+        amount: 10_000,
+        deadline,
+    };
+
+    setup.env.ledger().set_timestamp(500);
+    assert_eq!(
+        setup
+            .escrow
+            .try_batch_lock_funds(&vec![&setup.env, item(1, deadline), item(2, 500)]),
+        Err(Ok(Error::InvalidDeadline))
+    );
+
+    setup.escrow.update_fee_config(
+        &Some(100),
+        &Some(0),
+        &Some(fee_recipient.clone()),
+        &Some(true),
+    );
+    setup
+        .escrow
+        .batch_lock_funds(&vec![&setup.env, item(1, deadline), item(2, deadline)]);
+
+    assert_eq!(setup.token.balance(&fee_recipient), 200);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 19_800);
+    assert_eq!(setup.escrow.get_escrow_info(&1).remaining_amount, 9_900);
+    assert_eq!(setup.escrow.get_total_locked(), 19_800);
+    assert_eq!(setup.escrow.total_fees_collected(), 200);
+}
+
+// ============================================================================
+// ACTIVE BOUNTY LIMIT TESTS
+// ============================================================================
+
+#[test]
+fn test_max_active_per_depositor() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup.escrow.update_max_active_per_depositor(&2);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1_000, &deadline);
+    assert_eq!(setup.escrow.get_active_bounty_count(&setup.depositor), 2);
+
+    assert_eq!(
+        setup
+            .escrow
+            .try_lock_funds(&setup.depositor, &3, &1_000, &deadline),
+        Err(Ok(Error::TooManyActiveBounties))
+    );
+
+    // Another depositor is unaffected
+    let other = Address::generate(&setup.env);
+    setup.token_admin.mint(&other, &1_000);
+    setup.escrow.lock_funds(&other, &4, &1_000, &deadline);
+
+    // Refunding one frees a slot
+    setup
+        .env
+        .ledger()
+        .with_mut(|li| li.timestamp = deadline + 1);
+    setup
+        .escrow
+        .refund(&1, &None::<i128>, &None::<Address>, &RefundMode::Full);
+    assert_eq!(setup.escrow.get_active_bounty_count(&setup.depositor), 1);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &3, &1_000, &(deadline + 1000));
+    assert_eq!(setup.escrow.get_active_bounty_count(&setup.depositor), 2);
+
+    setup.escrow.release_funds(&2, &setup.contributor);
+    assert_eq!(setup.escrow.get_active_bounty_count(&setup.depositor), 1);
+}
+
+// ============================================================================
+// AUTO-RELEASE TESTS
+// ============================================================================
+
+#[test]
+fn test_auto_release_after_review_window() {
+    let setup = TestSetup::new();
+    let start = setup.env.ledger().timestamp();
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &(start + 10_000));
+    setup.escrow.update_review_window(&3_600);
+    setup
+        .escrow
+        .assign_contributor(&1, &setup.contributor, &false);
+
+    setup
+        .env
+        .ledger()
+        .with_mut(|li| li.timestamp = start + 3_599);
+    assert_eq!(
+        setup.escrow.try_finalize_auto_release(&1),
+        Err(Ok(Error::ReviewWindowOpen))
+    );
+
+    setup
+        .env
+        .ledger()
+        .with_mut(|li| li.timestamp = start + 3_600);
+    setup.escrow.finalize_auto_release(&1);
+    assert_eq!(setup.token.balance(&setup.contributor), 1_000);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Released
+    );
+    assert_eq!(
+        setup.escrow.try_finalize_auto_release(&1),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
+
+#[test]
+fn test_veto_blocks_auto_release() {
+    let setup = TestSetup::new();
+    let start = setup.env.ledger().timestamp();
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &(start + 10_000));
+    setup.escrow.update_review_window(&3_600);
+    setup
+        .escrow
+        .assign_contributor(&1, &setup.contributor, &false);
+
+    setup.escrow.veto(&1);
+    setup
+        .env
+        .ledger()
+        .with_mut(|li| li.timestamp = start + 7_200);
+    assert_eq!(
+        setup.escrow.try_finalize_auto_release(&1),
+        Err(Ok(Error::AutoReleaseVetoed))
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Locked
+    );
+}
+
+#[test]
+fn test_auto_release_requires_window_and_assignment() {
+    let setup = TestSetup::new();
+    let start = setup.env.ledger().timestamp();
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &(start + 10_000));
+
+    assert_eq!(
+        setup.escrow.try_finalize_auto_release(&1),
+        Err(Ok(Error::NoContributorAssigned))
+    );
+    setup
+        .escrow
+        .assign_contributor(&1, &setup.contributor, &false);
+    assert_eq!(
+        setup.escrow.try_finalize_auto_release(&1),
+        Err(Ok(Error::AutoReleaseNotConfigured))
+    );
+}
+
+// ============================================================================
+// REFUND REASON TESTS
+// ============================================================================
+
+#[test]
+fn test_refund_reason_deadline_passed() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup.escrow.refund(&1, &None, &None, &RefundMode::Full);
+
+    let history = setup.escrow.get_refund_history(&1);
+    assert_eq!(history.get(0).unwrap().reason, RefundReason::DeadlinePassed);
+    let data = find_event(&setup.env, symbol_short!("f_ref")).unwrap();
+    let event: events::FundsRefunded = data.into_val(&setup.env);
+    assert_eq!(event.reason, RefundReason::DeadlinePassed);
+}
+
+#[test]
+fn test_refund_reason_depositor_cancelled() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+
+    setup
+        .escrow
+        .approve_refund(&1, &1_000, &setup.depositor, &RefundMode::Custom);
+    setup.escrow.refund(
+        &1,
+        &Some(1_000),
+        &Some(setup.depositor.clone()),
+        &RefundMode::Custom,
+    );
+
+    let history = setup.escrow.get_refund_history(&1);
+    assert_eq!(
+        history.get(0).unwrap().reason,
+        RefundReason::DepositorCancelled
+    );
+    let data = find_event(&setup.env, symbol_short!("f_ref")).unwrap();
+    let event: events::FundsRefunded = data.into_val(&setup.env);
+    assert_eq!(event.reason, RefundReason::DepositorCancelled);
+}
+
+#[test]
+fn test_refund_reason_dispute_resolved() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let resolver = create_mock_resolver(&setup.env);
+    resolver.set_outcome(&ResolutionOutcome::Refund);
+    setup.escrow.lock_funds_with_resolver(
+        &setup.depositor,
+        &1,
+        &1_000,
+        &deadline,
+        &resolver.address,
+    );
+
+    setup.escrow.raise_dispute(&1, &setup.depositor);
+
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(
+        escrow.refund_history.get(0).unwrap().reason,
+        RefundReason::DisputeResolved
+    );
+    let data = find_event(&setup.env, symbol_short!("f_ref")).unwrap();
+    let event: events::FundsRefunded = data.into_val(&setup.env);
+    assert_eq!(event.reason, RefundReason::DisputeResolved);
+}
+
+// ============================================================================
+// REFUNDABLE LISTING TESTS
+// ============================================================================
+
+#[test]
+fn test_list_refundable_returns_expired_locked_bounties() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    let items = vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: setup.depositor.clone(),
+            amount: 100,
+            deadline: now + 100,
+        },
+        LockFundsItem {
+            bounty_id: 2,
+            depositor: setup.depositor.clone(),
+            amount: 100,
+            deadline: now + 5_000,
+        },
+        LockFundsItem {
+            bounty_id: 3,
+            depositor: setup.depositor.clone(),
+            amount: 100,
+            deadline: now + 200,
+        },
+        LockFundsItem {
+            bounty_id: 4,
+            depositor: setup.depositor.clone(),
+            amount: 100,
+            deadline: now + 300,
+        },
+    ];
+    setup.escrow.batch_lock_funds(&items);
+    assert_eq!(setup.escrow.list_refundable(&0, &10), vec![&setup.env]);
+
+    setup.env.ledger().set_timestamp(now + 1_000);
+    // Settled bounties drop out even though their deadline passed
+    setup.escrow.release_funds(&4, &setup.contributor);
+    assert_eq!(
+        setup.escrow.list_refundable(&0, &10),
+        vec![&setup.env, 1u64, 3u64]
+    );
+
+    // Pages scan fixed windows of the index
+    assert_eq!(setup.escrow.list_refundable(&0, &2), vec![&setup.env, 1u64]);
+    assert_eq!(setup.escrow.list_refundable(&2, &2), vec![&setup.env, 3u64]);
+    assert_eq!(setup.escrow.list_refundable(&4, &2), vec![&setup.env]);
+    assert_eq!(
+        setup.escrow.try_list_refundable(&0, &101),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+}
+
+// ============================================================================
+// STRING BOUNTY ID TESTS
+// ============================================================================
+
+#[test]
+fn test_lock_and_release_string_bounty() {
+    let setup = TestSetup::new();
+    let bounty_id = soroban_sdk::String::from_str(&setup.env, "repo#42");
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds_str(&setup.depositor, &bounty_id, &1_000, &deadline);
+    let escrow = setup.escrow.get_escrow_info_str(&bounty_id);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(escrow.remaining_amount, 1_000);
+    assert_eq!(setup.escrow.get_total_locked(), 1_000);
+    assert_eq!(
+        setup
+            .escrow
+            .try_lock_funds_str(&setup.depositor, &bounty_id, &1_000, &deadline),
+        Err(Ok(Error::BountyExists))
+    );
+
+    setup
+        .escrow
+        .release_funds_str(&bounty_id, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 1_000);
+    assert_eq!(
+        setup.escrow.get_escrow_info_str(&bounty_id).status,
+        EscrowStatus::Released
+    );
+    assert_eq!(setup.escrow.get_total_locked(), 0);
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_funds_str(&bounty_id, &setup.contributor),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
+
+#[test]
+fn test_refund_string_bounty_after_deadline() {
+    let setup = TestSetup::new();
+    let bounty_id = soroban_sdk::String::from_str(&setup.env, "repo#7");
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds_str(&setup.depositor, &bounty_id, &1_000, &deadline);
+
+    assert_eq!(
+        setup
+            .escrow
+            .try_refund_str(&bounty_id, &None, &RefundMode::Full),
+        Err(Ok(Error::DeadlineNotPassed))
+    );
+    setup.env.ledger().set_timestamp(deadline);
+    setup
+        .escrow
+        .refund_str(&bounty_id, &None, &RefundMode::Full);
+
+    let escrow = setup.escrow.get_escrow_info_str(&bounty_id);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(
+        escrow.refund_history.get(0).unwrap().reason,
+        RefundReason::DeadlinePassed
+    );
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000);
+}
+
+#[test]
+fn test_partial_refund_string_bounty_withholds_penalty() {
+    let setup = TestSetup::new();
+    let bounty_id = soroban_sdk::String::from_str(&setup.env, "repo#8");
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    let collector = Address::generate(&setup.env);
+    setup
+        .escrow
+        .lock_funds_str(&setup.depositor, &bounty_id, &1_000, &deadline);
+    setup.escrow.update_penalty_config(&1_000, &collector);
+
+    setup.env.ledger().set_timestamp(deadline);
+    setup
+        .escrow
+        .refund_str(&bounty_id, &Some(400), &RefundMode::Partial);
+
+    let escrow = setup.escrow.get_escrow_info_str(&bounty_id);
+    assert_eq!(escrow.status, EscrowStatus::PartiallyRefunded);
+    assert_eq!(escrow.remaining_amount, 600);
+    assert_eq!(setup.escrow.total_penalties_collected(), 40);
+    assert_eq!(setup.token.balance(&collector), 40);
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000 - 640);
+    assert_eq!(setup.escrow.get_total_locked(), 600);
+}
+
+#[test]
+fn test_string_and_numeric_bounty_ids_do_not_collide() {
+    let setup = TestSetup::new();
+    let string_id = soroban_sdk::String::from_str(&setup.env, "42");
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &42, &1_000, &deadline);
+    assert_eq!(
+        setup.escrow.try_get_escrow_info_str(&string_id),
+        Err(Ok(Error::BountyNotFound))
+    );
+    setup
+        .escrow
+        .lock_funds_str(&setup.depositor, &string_id, &500, &deadline);
+
+    setup
+        .escrow
+        .release_funds_str(&string_id, &setup.contributor);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&42).status,
+        EscrowStatus::Locked
+    );
+    assert_eq!(setup.escrow.get_escrow_info(&42).remaining_amount, 1_000);
+    assert_eq!(setup.token.balance(&setup.contributor), 500);
+}
+
+// ============================================================================
+// RECIPIENT VALIDATION TESTS
+// ============================================================================
+
+#[test]
+fn test_release_to_contract_address_rejected() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+
+    assert_eq!(
+        setup.escrow.try_release_funds(&1, &setup.escrow_address),
+        Err(Ok(Error::InvalidRecipient))
+    );
+    let items = vec![
+        &setup.env,
+        ReleaseFundsItem {
+            bounty_id: 1,
+            contributor: setup.escrow_address.clone(),
+        },
+    ];
+    assert_eq!(
+        setup.escrow.try_batch_release_funds(&items),
+        Err(Ok(Error::InvalidRecipient))
+    );
+
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 1_000);
+}
+
+// ============================================================================
+// TOKEN DECIMALS TESTS
+// ============================================================================
+
+#[test]
+fn test_token_decimals_match_stellar_asset_contract() {
+    let setup = TestSetup::new();
+
+    assert_eq!(setup.escrow.get_token_decimals(), setup.token.decimals());
+    assert_eq!(setup.escrow.get_token_decimals(), 7);
+}
+
+#[test]
+fn test_to_smallest_unit_scales_by_token_decimals() {
+    let setup = TestSetup::new();
+
+    assert_eq!(setup.escrow.to_smallest_unit(&25), 250_000_000);
+    assert_eq!(setup.escrow.to_smallest_unit(&0), 0);
+    assert_eq!(
+        setup.escrow.try_to_smallest_unit(&-1),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        setup.escrow.try_to_smallest_unit(&i128::MAX),
+        Err(Ok(Error::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_token_decimals_require_init() {
+    let env = Env::default();
+    let (escrow, _) = create_escrow_contract(&env);
+
+    assert_eq!(
+        escrow.try_get_token_decimals(),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+// ============================================================================
+// MULTI-TOKEN TESTS
+// ============================================================================
+
+#[test]
+fn test_lock_and_release_bounties_in_two_tokens() {
+    let setup = TestSetup::new();
+    let (xlm, xlm_admin) = create_token_contract(&setup.env, &setup.admin);
+    xlm_admin.mint(&setup.depositor, &50_000);
+    setup.escrow.allow_token(&xlm.address);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+    setup
+        .escrow
+        .lock_funds_token(&setup.depositor, &2, &xlm.address, &20_000, &deadline);
+
+    assert_eq!(setup.escrow.get_escrow_info(&1).token, setup.token.address);
+    assert_eq!(setup.escrow.get_escrow_info(&2).token, xlm.address);
+    assert_eq!(setup.escrow.get_total_locked(), 1_000);
+    assert_eq!(setup.escrow.get_total_locked_for(&xlm.address), 20_000);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 1_000);
+    assert_eq!(xlm.balance(&setup.escrow_address), 20_000);
+
+    let other = Address::generate(&setup.env);
+    setup.escrow.release_funds(&1, &setup.contributor);
+    setup.escrow.release_funds(&2, &other);
+
+    assert_eq!(setup.token.balance(&setup.contributor), 1_000);
+    assert_eq!(xlm.balance(&setup.contributor), 0);
+    assert_eq!(xlm.balance(&other), 20_000);
+    assert_eq!(setup.token.balance(&other), 0);
+    assert_eq!(setup.escrow.get_total_locked(), 0);
+    assert_eq!(setup.escrow.get_total_locked_for(&xlm.address), 0);
+}
+
+#[test]
+fn test_refund_pays_out_in_escrow_token() {
+    let setup = TestSetup::new();
+    let (xlm, xlm_admin) = create_token_contract(&setup.env, &setup.admin);
+    xlm_admin.mint(&setup.depositor, &50_000);
+    setup.escrow.allow_token(&xlm.address);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds_token(&setup.depositor, &7, &xlm.address, &20_000, &deadline);
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup.escrow.refund(&7, &None, &None, &RefundMode::Full);
+
+    assert_eq!(xlm.balance(&setup.depositor), 50_000);
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000);
+    assert_eq!(setup.escrow.get_total_locked_for(&xlm.address), 0);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&7).status,
+        EscrowStatus::Refunded
+    );
+}
+
+#[test]
+fn test_update_token_rejected_while_new_token_has_escrows() {
+    let setup = TestSetup::new();
+    let (xlm, xlm_admin) = create_token_contract(&setup.env, &setup.admin);
+    xlm_admin.mint(&setup.depositor, &50_000);
+    setup.escrow.allow_token(&xlm.address);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds_token(&setup.depositor, &1, &xlm.address, &20_000, &deadline);
+
+    assert_eq!(
+        setup.escrow.try_update_token(&xlm.address),
+        Err(Ok(Error::EscrowsOutstanding))
+    );
+}
+
+#[test]
+fn test_lock_funds_token_requires_allowlisted_token() {
+    let setup = TestSetup::new();
+    let (xlm, xlm_admin) = create_token_contract(&setup.env, &setup.admin);
+    xlm_admin.mint(&setup.depositor, &50_000);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    assert!(setup.escrow.is_token_allowed(&setup.token.address));
+    assert!(!setup.escrow.is_token_allowed(&xlm.address));
+    assert_eq!(
+        setup
+            .escrow
+            .try_lock_funds_token(&setup.depositor, &1, &xlm.address, &20_000, &deadline),
+        Err(Ok(Error::TokenNotAllowed))
+    );
+    assert_eq!(xlm.balance(&setup.escrow_address), 0);
+
+    setup.escrow.allow_token(&xlm.address);
+    assert!(setup.escrow.is_token_allowed(&xlm.address));
+    setup
+        .escrow
+        .lock_funds_token(&setup.depositor, &1, &xlm.address, &20_000, &deadline);
+    assert_eq!(xlm.balance(&setup.escrow_address), 20_000);
+
+    setup.escrow.disallow_token(&xlm.address);
+    assert_eq!(
+        setup
+            .escrow
+            .try_lock_funds_token(&setup.depositor, &2, &xlm.address, &20_000, &deadline),
+        Err(Ok(Error::TokenNotAllowed))
+    );
+    // Escrows locked while the token was allowed still settle
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(xlm.balance(&setup.contributor), 20_000);
+}
+
+// ============================================================================
+// ADMIN RENOUNCE TESTS
+// ============================================================================
+
+#[test]
+fn test_renounce_admin_blocked_while_funds_locked() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+
+    assert_eq!(
+        setup.escrow.try_renounce_admin(),
+        Err(Ok(Error::FundsStillLocked))
+    );
+
+    // The admin is untouched and can still release
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 1_000);
+}
+
+#[test]
+fn test_renounce_admin_leaves_contract_refund_only() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+    setup.escrow.release_funds(&1, &setup.contributor);
+
+    setup.escrow.renounce_admin();
+
+    // Re-initializing would hand the contract to a new admin
+    assert_eq!(
+        setup
+            .escrow
+            .try_init(&setup.contributor, &setup.token.address),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+
+    // New locks still work and refund after the deadline, but never release
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &2_000, &deadline);
+    assert_eq!(
+        setup.escrow.try_release_funds(&2, &setup.contributor),
+        Err(Ok(Error::NotInitialized))
+    );
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup.escrow.refund(&2, &None, &None, &RefundMode::Full);
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000 - 1_000);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&2).status,
+        EscrowStatus::Refunded
+    );
+}
+
+// ============================================================================
+// CONTRACT STATS TESTS
+// ============================================================================
+
+#[test]
+fn test_contract_stats_aggregate_locks_releases_and_refunds() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    let empty = setup.escrow.get_contract_stats();
+    assert_eq!(empty.total_locked, 0);
+    assert_eq!(empty.active_count, 0);
+    assert_eq!(empty.admin, Some(setup.admin.clone()));
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &2_000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &3, &4_000, &deadline);
+    setup.escrow.release_funds(&1, &setup.contributor);
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup.escrow.refund(&2, &None, &None, &RefundMode::Full);
+    setup
+        .escrow
+        .refund(&3, &Some(500), &None, &RefundMode::Partial);
+
+    let stats = setup.escrow.get_contract_stats();
+    assert_eq!(
+        stats,
+        ContractStats {
+            total_locked: 3_500,
+            total_released: 1_000,
+            total_refunded: 2_500,
+            active_count: 1,
+            admin: Some(setup.admin.clone()),
+        }
+    );
+    assert_eq!(
+        stats.total_locked + stats.total_released + stats.total_refunded,
+        7_000
+    );
+}
+
+// ============================================================================
+// REFUND GRACE PERIOD TESTS
+// ============================================================================
+
+#[test]
+fn test_refund_rejected_during_grace_window() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+    setup.escrow.update_refund_grace(&600);
+    assert_eq!(setup.escrow.get_refund_grace(), 600);
+
+    setup.env.ledger().set_timestamp(deadline + 599);
+    assert_eq!(
+        setup.escrow.try_refund(&1, &None, &None, &RefundMode::Full),
+        Err(Ok(Error::DeadlineNotPassed))
+    );
+    assert!(!setup.escrow.is_refundable(&1));
+    assert_eq!(setup.escrow.list_refundable(&0, &10).len(), 0);
+
+    // The admin can still release inside the grace window
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 1_000);
+}
+
+#[test]
+fn test_refund_allowed_after_grace_window() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+    setup.escrow.update_refund_grace(&600);
+
+    setup.env.ledger().set_timestamp(deadline + 600);
+    assert!(setup.escrow.is_refundable(&1));
+    setup.escrow.refund(&1, &None, &None, &RefundMode::Full);
+
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(
+        escrow.refund_history.get(0).unwrap().reason,
+        RefundReason::DeadlinePassed
+    );
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000);
+}
+
+// ============================================================================
+// KEEPER FEE TESTS
+// ============================================================================
+
+#[test]
+fn test_keeper_paid_fee_and_depositor_gets_remainder() {
+    let setup = TestSetup::new();
+    let keeper = Address::generate(&setup.env);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup.escrow.update_keeper_fee(&100); // 1%
+    setup
+        .escrow
+        .lock_funds_with_keeper_fee(&setup.depositor, &1, &10_000, &deadline);
+
+    // A later rate change does not affect the opted-in bounty
+    setup.escrow.update_keeper_fee(&500);
+
+    assert_eq!(
+        setup.escrow.try_refund_as_keeper(&1, &keeper),
+        Err(Ok(Error::DeadlineNotPassed))
+    );
+
+    setup.env.ledger().set_timestamp(deadline);
+    assert_eq!(setup.escrow.refund_as_keeper(&1, &keeper), 100);
+
+    assert_eq!(setup.token.balance(&keeper), 100);
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000 - 100);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 0);
+
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.refund_history.len(), 2);
+    assert_eq!(escrow.refund_history.get(0).unwrap().recipient, keeper);
+    assert_eq!(escrow.refund_history.get(0).unwrap().amount, 100);
+}
+
+#[test]
+fn test_keeper_fee_not_charged_without_opt_in() {
+    let setup = TestSetup::new();
+    let keeper = Address::generate(&setup.env);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup.escrow.update_keeper_fee(&100);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &10_000, &deadline);
+
+    setup.env.ledger().set_timestamp(deadline);
+    assert_eq!(setup.escrow.refund_as_keeper(&1, &keeper), 0);
+
+    assert_eq!(setup.token.balance(&keeper), 0);
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000);
+}
+
+#[test]
+fn test_keeper_fee_rate_bounded() {
+    let setup = TestSetup::new();
+    assert_eq!(
+        setup.escrow.try_update_keeper_fee(&1_001),
+        Err(Ok(Error::InvalidFeeRate))
+    );
+    assert_eq!(setup.escrow.get_keeper_fee(), 0);
+}
+
+// ============================================================================
+// STATUS QUERY TESTS
+// ============================================================================
+
+#[test]
+fn test_get_bounties_by_status_filters_each_state() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    for bounty_id in 1..=5u64 {
+        setup
+            .escrow
+            .lock_funds(&setup.depositor, &bounty_id, &1_000, &deadline);
+    }
+    setup.escrow.release_funds(&2, &setup.contributor);
+    setup.env.ledger().set_timestamp(deadline);
+    setup.escrow.refund(&3, &None, &None, &RefundMode::Full);
+    setup
+        .escrow
+        .refund(&4, &Some(400), &None, &RefundMode::Partial);
+
+    let by_status = |status: EscrowStatus| setup.escrow.get_bounties_by_status(&status, &0, &10);
+    assert_eq!(by_status(EscrowStatus::Locked), vec![&setup.env, 1u64, 5]);
+    assert_eq!(by_status(EscrowStatus::Released), vec![&setup.env, 2u64]);
+    assert_eq!(by_status(EscrowStatus::Refunded), vec![&setup.env, 3u64]);
+    assert_eq!(
+        by_status(EscrowStatus::PartiallyRefunded),
+        vec![&setup.env, 4u64]
+    );
+
+    // Paging scans index windows; the second window holds bounty 5 only
+    assert_eq!(
+        setup
+            .escrow
+            .get_bounties_by_status(&EscrowStatus::Locked, &0, &3),
+        vec![&setup.env, 1u64]
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .get_bounties_by_status(&EscrowStatus::Locked, &3, &3),
+        vec![&setup.env, 5u64]
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .try_get_bounties_by_status(&EscrowStatus::Locked, &0, &101),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+}
+
+// ============================================================================
+// ESCROW CREATED EVENT TESTS
+// ============================================================================
+
+#[test]
+fn test_escrow_created_event_matches_stored_escrow() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &7, &1_500, &deadline);
+
+    let (_, topics, data) = setup
+        .env
+        .events()
+        .all()
+        .iter()
+        .find(|e| {
+            e.1.get(0)
+                .unwrap()
+                .shallow_eq(&symbol_short!("esc_new").into_val(&setup.env))
+        })
+        .unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("esc_new"), 7u64).into_val(&setup.env)
+    );
+    let event: events::EscrowCreated = data.into_val(&setup.env);
+    assert_eq!(event.version, events::ESCROW_CREATED_VERSION);
+    assert_eq!(event.bounty_id, 7);
+    assert_eq!(event.escrow, setup.escrow.get_escrow_info(&7));
+    assert_eq!(event.escrow.token, setup.token.address);
+}
+
+// ============================================================================
+// DEPOSITOR-ONLY REFUND TESTS
+// ============================================================================
+
+fn setup_refund_mode<'a>(permissionless_refund: bool) -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    let (escrow, escrow_address) = create_escrow_contract(&env);
+
+    escrow.init_with_refund_mode(&admin, &token.address, &permissionless_refund);
+    token_admin.mint(&depositor, &1_000_000);
+
+    TestSetup {
+        env,
+        admin,
+        depositor,
+        contributor,
+        token,
+        token_admin,
+        escrow,
+        escrow_address,
+    }
+}
+
+#[test]
+fn test_permissionless_mode_allows_both_refund_paths() {
+    let setup = setup_refund_mode(true);
+    assert!(setup.escrow.is_permissionless_refund());
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &2_000, &deadline);
+
+    assert_eq!(
+        setup.escrow.try_refund_by_depositor(&1),
+        Err(Ok(Error::DeadlineNotPassed))
+    );
+
+    setup.env.ledger().set_timestamp(deadline);
+    setup.escrow.refund(&1, &None, &None, &RefundMode::Full);
+    setup.escrow.refund_by_depositor(&2);
+
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&2).status,
+        EscrowStatus::Refunded
+    );
+}
+
+#[test]
+fn test_depositor_only_mode_blocks_permissionless_refund() {
+    let setup = setup_refund_mode(false);
+    assert!(!setup.escrow.is_permissionless_refund());
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+
+    setup.env.ledger().set_timestamp(deadline);
+    assert_eq!(
+        setup.escrow.try_refund(&1, &None, &None, &RefundMode::Full),
+        Err(Ok(Error::PermissionlessRefundDisabled))
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .try_refund_as_keeper(&1, &Address::generate(&setup.env)),
+        Err(Ok(Error::PermissionlessRefundDisabled))
+    );
+
+    setup.escrow.refund_by_depositor(&1);
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000_000);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Refunded
+    );
+}
+
+#[test]
+fn test_depositor_only_mode_keeps_approved_early_refund() {
+    let setup = setup_refund_mode(false);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+
+    setup
+        .escrow
+        .approve_refund(&1, &400, &setup.contributor, &RefundMode::Custom);
+    setup.escrow.refund(
+        &1,
+        &Some(400),
+        &Some(setup.contributor.clone()),
+        &RefundMode::Custom,
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), 400);
+}
+
+#[test]
+fn test_refund_by_depositor_rejects_other_signer() {
+    let setup = setup_refund_mode(false);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+    setup.env.ledger().set_timestamp(deadline);
+
+    let stranger = Address::generate(&setup.env);
+    setup.env.mock_auths(&[soroban_sdk::testutils::MockAuth {
+        address: &stranger,
+        invoke: &soroban_sdk::testutils::MockAuthInvoke {
+            contract: &setup.escrow_address,
+            fn_name: "refund_by_depositor",
+            args: (1u64,).into_val(&setup.env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(setup.escrow.try_refund_by_depositor(&1).is_err());
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Locked
+    );
+    assert_eq!(setup.token.balance(&setup.escrow_address), 1_000);
+}
+
+// ============================================================================
+// SCHEMA VERSION TESTS
+// ============================================================================
+
+#[test]
+fn test_schema_version_set_at_init_and_migrate_is_idempotent() {
+    let setup = TestSetup::new();
+    assert_eq!(setup.escrow.schema_version(), 2);
+
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+    let before = setup.escrow.get_escrow_info(&1);
+
+    assert_eq!(setup.escrow.migrate(), 2);
+    assert_eq!(setup.escrow.migrate(), 2);
+    assert_eq!(setup.escrow.schema_version(), 2);
+    assert_eq!(setup.escrow.get_escrow_info(&1), before);
+}
+
+#[test]
+fn test_migrate_rejects_newer_schema() {
+    let setup = TestSetup::new();
+    setup.env.as_contract(&setup.escrow_address, || {
+        setup
+            .env
+            .storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &3u32);
+    });
+
+    assert_eq!(setup.escrow.try_migrate(), Err(Ok(Error::SchemaMismatch)));
+    assert_eq!(setup.escrow.schema_version(), 3);
+}
+
+/// Rewinds the instance to schema version 1 and stores `escrow` in the
+/// version 1 layout, with its remaining funds held by the contract.
+fn store_v1_escrow(setup: &TestSetup, bounty_id: u64, escrow: &migration::EscrowV1) {
+    setup.env.as_contract(&setup.escrow_address, || {
+        setup
+            .env
+            .storage()
+            .instance()
+            .remove(&DataKey::SchemaVersion);
+        setup
+            .env
+            .storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), escrow);
+    });
+    setup
+        .token_admin
+        .mint(&setup.escrow_address, &escrow.remaining_amount);
+}
+
+#[test]
+fn test_migrate_escrows_converts_v1_records_and_backfills_counters() {
+    let setup = TestSetup::new();
+    let legacy = |status, remaining_amount, refund_history| migration::EscrowV1 {
+        depositor: setup.depositor.clone(),
+        amount: 1_000,
+        status,
+        deadline: 500,
+        refund_history,
+        remaining_amount,
+    };
+    store_v1_escrow(
+        &setup,
+        1,
+        &legacy(EscrowStatus::Locked, 1_000, vec![&setup.env]),
+    );
+    store_v1_escrow(
+        &setup,
+        2,
+        &legacy(
+            EscrowStatus::PartiallyRefunded,
+            300,
+            vec![
+                &setup.env,
+                migration::RefundRecordV1 {
+                    amount: 700,
+                    recipient: setup.depositor.clone(),
+                    mode: RefundMode::Partial,
+                    timestamp: 600,
+                },
+            ],
+        ),
+    );
+    store_v1_escrow(
+        &setup,
+        3,
+        &legacy(EscrowStatus::Released, 0, vec![&setup.env]),
+    );
+    // Stray tokens sent straight to the contract
+    setup.token_admin.mint(&setup.escrow_address, &50);
+    assert_eq!(setup.escrow.schema_version(), 1);
+
+    // Nothing that trusts TotalLocked runs mid-migration
+    let other = Address::generate(&setup.env);
+    assert_eq!(
+        setup.escrow.try_sweep_unaccounted(&other),
+        Err(Ok(Error::SchemaMismatch))
+    );
+    assert_eq!(
+        setup.escrow.try_update_token(&other),
+        Err(Ok(Error::SchemaMismatch))
+    );
+    assert_eq!(
+        setup.escrow.try_renounce_admin(),
+        Err(Ok(Error::SchemaMismatch))
+    );
+
+    let ids = vec![&setup.env, 1u64, 2, 3];
+    assert_eq!(setup.escrow.migrate_escrows(&ids), 3);
+    // Already converted escrows are skipped, so counters aren't doubled
+    assert_eq!(setup.escrow.migrate_escrows(&ids), 0);
+    assert_eq!(
+        setup.escrow.try_migrate_escrows(&vec![&setup.env, 4u64]),
+        Err(Ok(Error::BountyNotFound))
+    );
+
+    let escrow = setup.escrow.get_escrow_info(&2);
+    assert_eq!(escrow.token, setup.token.address);
+    assert_eq!(escrow.created_at, 0);
+    assert_eq!(
+        escrow.refund_history.get(0).unwrap().reason,
+        RefundReason::DeadlinePassed
+    );
+    assert_eq!(setup.escrow.get_total_locked(), 1_300);
+    assert_eq!(setup.escrow.get_escrow_count(), 3);
+    assert_eq!(setup.escrow.get_contract_stats().active_count, 2);
+
+    assert_eq!(setup.escrow.migrate(), 2);
+    assert_eq!(
+        setup.escrow.try_migrate_escrows(&ids),
+        Err(Ok(Error::SchemaMismatch))
+    );
+    assert_eq!(setup.escrow.get_unaccounted_balance(), 50);
+    assert_eq!(setup.escrow.sweep_unaccounted(&other), 50);
+
+    // Migrated escrows settle normally
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 1_000);
+    assert_eq!(setup.escrow.get_total_locked(), 300);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 300);
+}
+
+// ============================================================================
+// CHECKS-EFFECTS-INTERACTIONS TESTS
+// ============================================================================
+
+fn guard_is_set(setup: &TestSetup) -> bool {
+    setup.env.as_contract(&setup.escrow_address, || {
+        setup
+            .env
+            .storage()
+            .temporary()
+            .has(&DataKey::ReentrancyGuard)
+    })
+}
+
+#[test]
+fn test_stuck_guard_does_not_block_default_token_flows() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup.env.as_contract(&setup.escrow_address, || {
+        setup
+            .env
+            .storage()
+            .temporary()
+            .set(&DataKey::ReentrancyGuard, &true);
+    });
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &2_000, &deadline);
+    setup.escrow.release_funds(&1, &setup.contributor);
+    setup.env.ledger().set_timestamp(deadline);
+    setup.escrow.refund(&2, &None, &None, &RefundMode::Full);
+
+    assert_eq!(setup.token.balance(&setup.contributor), 1_000);
+    assert_eq!(setup.token.balance(&setup.depositor), 999_000);
+    assert_eq!(setup.escrow.get_total_locked(), 0);
+}
+
+#[test]
+fn test_lock_release_refund_never_leave_guard_set() {
+    let setup = TestSetup::new();
+    let (xlm, xlm_admin) = create_token_contract(&setup.env, &setup.admin);
+    xlm_admin.mint(&setup.depositor, &50_000);
+    setup.escrow.allow_token(&xlm.address);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+    assert!(!guard_is_set(&setup));
+    setup
+        .escrow
+        .lock_funds_token(&setup.depositor, &2, &xlm.address, &5_000, &deadline);
+    assert!(!guard_is_set(&setup));
+    setup
+        .escrow
+        .lock_funds_token(&setup.depositor, &3, &xlm.address, &7_000, &deadline);
+    assert!(!guard_is_set(&setup));
+
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert!(!guard_is_set(&setup));
+    setup.escrow.release_funds(&2, &setup.contributor);
+    assert!(!guard_is_set(&setup));
+
+    // A failing call leaves nothing behind either
+    assert_eq!(
+        setup.escrow.try_refund(&3, &None, &None, &RefundMode::Full),
+        Err(Ok(Error::DeadlineNotPassed))
+    );
+    assert!(!guard_is_set(&setup));
+
+    setup.env.ledger().set_timestamp(deadline);
+    setup.escrow.refund(&3, &None, &None, &RefundMode::Full);
+    assert!(!guard_is_set(&setup));
+
+    assert_eq!(setup.token.balance(&setup.contributor), 1_000);
+    assert_eq!(xlm.balance(&setup.contributor), 5_000);
+    assert_eq!(xlm.balance(&setup.depositor), 45_000);
+    assert_eq!(setup.escrow.get_total_locked_for(&xlm.address), 0);
+}
+
+#[test]
+fn test_allowlisted_token_lock_respects_stuck_guard() {
+    let setup = TestSetup::new();
+    let (xlm, xlm_admin) = create_token_contract(&setup.env, &setup.admin);
+    xlm_admin.mint(&setup.depositor, &50_000);
+    setup.escrow.allow_token(&xlm.address);
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup.env.as_contract(&setup.escrow_address, || {
+        setup
+            .env
+            .storage()
+            .temporary()
+            .set(&DataKey::ReentrancyGuard, &true);
+    });
+
+    assert!(setup
+        .escrow
+        .try_lock_funds_token(&setup.depositor, &1, &xlm.address, &5_000, &deadline)
+        .is_err());
+    assert_eq!(xlm.balance(&setup.depositor), 50_000);
+}
+
+// ============================================================================
+// ESCROW COUNT TESTS
+// ============================================================================
+
+#[test]
+fn test_escrow_count_tracks_every_lock() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    assert_eq!(setup.escrow.get_escrow_count(), 0);
+
+    for bounty_id in 1..=3u64 {
+        setup
+            .escrow
+            .lock_funds(&setup.depositor, &bounty_id, &1_000, &deadline);
+    }
+    assert_eq!(setup.escrow.get_escrow_count(), 3);
+
+    let items = vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 4,
+            depositor: setup.depositor.clone(),
+            amount: 1_000,
+            deadline,
+        },
+        LockFundsItem {
+            bounty_id: 5,
+            depositor: setup.depositor.clone(),
+            amount: 1_000,
+            deadline,
+        },
+    ];
+    setup.escrow.batch_lock_funds(&items);
+    assert_eq!(setup.escrow.get_escrow_count(), 5);
+
+    // Settling an escrow doesn't lower the lifetime count
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.escrow.get_escrow_count(), 5);
+    assert_eq!(setup.escrow.get_contract_stats().active_count, 4);
+
+    // Failed locks don't count
+    assert!(setup
+        .escrow
+        .try_lock_funds(&setup.depositor, &2, &1_000, &deadline)
+        .is_err());
+    assert_eq!(setup.escrow.get_escrow_count(), 5);
+}
+
+// ============================================================================
+// DEPOSITOR ALLOWLIST TESTS
+// ============================================================================
+
+fn setup_with_options<'a>(options: InitOptions) -> TestSetup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    let (escrow, escrow_address) = create_escrow_contract(&env);
+
+    escrow.init_with_options(&admin, &token.address, &options);
+    token_admin.mint(&depositor, &1_000_000);
+
+    TestSetup {
+        env,
+        admin,
+        depositor,
+        contributor,
+        token,
+        token_admin,
+        escrow,
+        escrow_address,
+    }
+}
+
+#[test]
+fn test_depositor_allowlist_disabled_allows_anyone() {
+    let setup = setup_with_options(InitOptions {
+        permissionless_refund: true,
+        depositor_allowlist_enabled: false,
+        monitoring_enabled: true,
+    });
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    assert!(setup.escrow.is_depositor_allowed(&setup.depositor));
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+
+    // Disallowing has no effect while the allowlist is off
+    setup.escrow.disallow_depositor(&setup.depositor);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1_000, &deadline);
+    assert_eq!(setup.escrow.get_escrow_count(), 2);
+}
+
+#[test]
+fn test_depositor_allowlist_enabled_gates_locks() {
+    let setup = setup_with_options(InitOptions {
+        permissionless_refund: true,
+        depositor_allowlist_enabled: true,
+        monitoring_enabled: true,
+    });
+    let deadline = setup.env.ledger().timestamp() + 1000;
+
+    assert!(!setup.escrow.is_depositor_allowed(&setup.depositor));
+    assert_eq!(
+        setup
+            .escrow
+            .try_lock_funds(&setup.depositor, &1, &1_000, &deadline),
+        Err(Ok(Error::DepositorNotAllowed))
+    );
+    let items = vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: setup.depositor.clone(),
+            amount: 1_000,
+            deadline,
+        },
+    ];
+    assert_eq!(
+        setup.escrow.try_batch_lock_funds(&items),
+        Err(Ok(Error::DepositorNotAllowed))
+    );
+
+    setup.escrow.allow_depositor(&setup.depositor);
+    assert!(setup.escrow.is_depositor_allowed(&setup.depositor));
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+
+    setup.escrow.disallow_depositor(&setup.depositor);
+    assert!(!setup.escrow.is_depositor_allowed(&setup.depositor));
+    assert_eq!(
+        setup
+            .escrow
+            .try_lock_funds(&setup.depositor, &2, &1_000, &deadline),
+        Err(Ok(Error::DepositorNotAllowed))
+    );
+
+    // Escrows locked while allowed still settle
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 1_000);
+}
+
+// ============================================================================
+// ESCROW AGE TESTS
+// ============================================================================
+
+#[test]
+fn test_get_escrow_age_tracks_ledger_time() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1_000);
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+
+    assert_eq!(setup.escrow.get_escrow_age(&1), 0);
+    setup.env.ledger().set_timestamp(1_000 + 3_600);
+    assert_eq!(setup.escrow.get_escrow_age(&1), 3_600);
+    assert_eq!(
+        setup.escrow.try_get_escrow_age(&2),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+// ============================================================================
+// SINGLE-CONTRIBUTOR BATCH RELEASE TESTS
+// ============================================================================
+
+fn token_transfer_count(setup: &TestSetup) -> usize {
+    setup
+        .env
+        .events()
+        .all()
+        .iter()
+        .filter(|e| {
+            e.0 == setup.token.address
+                && e.1
+                    .get(0)
+                    .unwrap()
+                    .shallow_eq(&symbol_short!("transfer").into_val(&setup.env))
+        })
+        .count()
+}
+
+#[test]
+fn test_batch_release_pays_contributor_in_one_transfer() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    for (bounty_id, amount) in [(1u64, 1_000i128), (2, 2_000), (3, 3_000)] {
+        setup
+            .escrow
+            .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    }
+
+    let transfers_before = token_transfer_count(&setup);
+    let results =
+        setup
+            .escrow
+            .batch_release(&vec![&setup.env, 1u64, 2, 3], &setup.contributor, &true);
+    assert_eq!(results.len(), 3);
+    assert!(results.iter().all(|r| r.outcome == BatchOutcome::Ok));
+    assert_eq!(token_transfer_count(&setup), transfers_before + 1);
+    assert_eq!(setup.token.balance(&setup.contributor), 6_000);
+    assert_eq!(setup.escrow.get_total_locked(), 0);
+    for bounty_id in 1..=3u64 {
+        assert_eq!(
+            setup.escrow.get_escrow_info(&bounty_id).status,
+            EscrowStatus::Released
+        );
+    }
+
+    let mut released_amounts: Vec<i128> = Vec::new(&setup.env);
+    for (_, topics, data) in setup.env.events().all().iter() {
+        if topics
+            .get(0)
+            .unwrap()
+            .shallow_eq(&symbol_short!("f_rel").into_val(&setup.env))
+        {
+            let event: events::FundsReleased = data.into_val(&setup.env);
+            released_amounts.push_back(event.amount);
+        }
+    }
+    assert_eq!(released_amounts, vec![&setup.env, 1_000i128, 2_000, 3_000]);
+}
+
+#[test]
+fn test_batch_release_strict_rejects_and_lenient_skips() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    for bounty_id in 1..=3u64 {
+        setup
+            .escrow
+            .lock_funds(&setup.depositor, &bounty_id, &1_000, &deadline);
+    }
+    setup.escrow.release_funds(&2, &setup.admin);
+    let ids = vec![&setup.env, 1u64, 2, 3, 9];
+
+    assert_eq!(
+        setup
+            .escrow
+            .try_batch_release(&ids, &setup.contributor, &true),
+        Err(Ok(Error::FundsNotLocked))
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Locked
+    );
+
+    // Lenient mode skips the released and unknown bounties
+    let results = setup.escrow.batch_release(&ids, &setup.contributor, &false);
+    assert_eq!(
+        results
+            .iter()
+            .filter(|r| r.outcome == BatchOutcome::Ok)
+            .count(),
+        2
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), 2_000);
+    assert_eq!(setup.token.balance(&setup.admin), 1_000);
+}
+
+#[test]
+fn test_batch_release_reports_per_item_outcomes() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    for bounty_id in 1..=4u64 {
+        setup
+            .escrow
+            .lock_funds(&setup.depositor, &bounty_id, &1_000, &deadline);
+    }
+    setup.escrow.release_funds(&2, &setup.admin);
+    setup.escrow.assign_contributor(&4, &setup.admin, &true);
+
+    let ids = vec![&setup.env, 1u64, 2, 9, 1, 4, 3];
+    let results = setup.escrow.batch_release(&ids, &setup.contributor, &false);
+    let expected = [
+        (1u64, BatchOutcome::Ok),
+        (2, BatchOutcome::Skipped(Error::FundsNotLocked as u32)),
+        (9, BatchOutcome::Skipped(Error::BountyNotFound as u32)),
+        (1, BatchOutcome::Skipped(Error::DuplicateBountyId as u32)),
+        (4, BatchOutcome::Skipped(Error::ContributorMismatch as u32)),
+        (3, BatchOutcome::Ok),
+    ];
+    assert_eq!(results.len(), expected.len() as u32);
+    for (result, (id, outcome)) in results.iter().zip(expected) {
+        assert_eq!(result, BatchResult { id, outcome });
+    }
+    assert_eq!(setup.token.balance(&setup.contributor), 2_000);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&4).status,
+        EscrowStatus::Locked
+    );
+}
+
+// ============================================================================
+// RELEASE PREFLIGHT TESTS
+// ============================================================================
+
+#[test]
+fn test_can_release_matches_release_funds_errors() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (uninitialized, _) = create_escrow_contract(&env);
+    let contributor = Address::generate(&env);
+    assert_eq!(
+        uninitialized.try_can_release(&1),
+        Err(Ok(Error::NotInitialized))
+    );
+    assert_eq!(
+        uninitialized.try_release_funds(&1, &contributor),
+        Err(Ok(Error::NotInitialized))
+    );
+
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1_000, &deadline);
+    setup.escrow.release_funds(&2, &setup.contributor);
+
+    let cases = [
+        (7u64, Err(Ok(Error::BountyNotFound))),
+        (2, Err(Ok(Error::FundsNotLocked))),
+    ];
+    for (bounty_id, expected) in cases {
+        assert_eq!(setup.escrow.try_can_release(&bounty_id), expected);
+        assert_eq!(
+            setup
+                .escrow
+                .try_release_funds(&bounty_id, &setup.contributor),
+            expected
+        );
+    }
+
+    setup.escrow.pause();
+    assert_eq!(
+        setup.escrow.try_can_release(&1),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        setup.escrow.try_release_funds(&1, &setup.contributor),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    setup.escrow.unpause();
+    assert_eq!(setup.escrow.try_can_release(&1), Ok(Ok(())));
+    setup.escrow.release_funds(&1, &setup.contributor);
+}
+
+// ============================================================================
+// ESCROW DETAILS
+// ============================================================================
+
+#[test]
+fn test_escrow_details_track_deadline() {
+    let setup = TestSetup::new();
+    setup.escrow.update_refund_grace(&100);
+    let now = setup.env.ledger().timestamp();
+    let deadline = now + 1_000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+
+    let details = setup.escrow.get_escrow_details(&1);
+    assert_eq!(details.escrow, setup.escrow.get_escrow_info(&1));
+    assert!(!details.is_expired);
+    assert_eq!(details.remaining_time, 1_000);
+    assert!(!details.is_refundable);
+
+    // Past the deadline but still inside the refund grace
+    setup.env.ledger().set_timestamp(deadline + 50);
+    let details = setup.escrow.get_escrow_details(&1);
+    assert!(details.is_expired);
+    assert_eq!(details.remaining_time, 0);
+    assert!(!details.is_refundable);
+
+    setup.env.ledger().set_timestamp(deadline + 100);
+    let details = setup.escrow.get_escrow_details(&1);
+    assert!(details.is_refundable);
+    assert_eq!(details.is_refundable, setup.escrow.is_refundable(&1));
+
+    setup.escrow.release_funds(&1, &setup.contributor);
+    let details = setup.escrow.get_escrow_details(&1);
+    assert_eq!(details.escrow.status, EscrowStatus::Released);
+    assert!(!details.is_refundable);
+
+    assert_eq!(
+        setup.escrow.try_get_escrow_details(&2),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+// ============================================================================
+// ADMIN SET
+// ============================================================================
+
+#[test]
+fn test_added_admins_can_release_independently() {
+    let setup = TestSetup::new();
+    let ops_a = Address::generate(&setup.env);
+    let ops_b = Address::generate(&setup.env);
+    assert_eq!(
+        setup.escrow.get_admins(),
+        vec![&setup.env, setup.admin.clone()]
+    );
+
+    setup.escrow.add_admin(&ops_a);
+    setup.escrow.add_admin(&ops_b);
+    assert_eq!(
+        setup.escrow.get_admins(),
+        vec![
+            &setup.env,
+            setup.admin.clone(),
+            ops_a.clone(),
+            ops_b.clone()
+        ]
+    );
+
+    let deadline = setup.env.ledger().timestamp() + 1_000;
+    for bounty_id in 1..=3u64 {
+        setup
+            .escrow
+            .lock_funds(&setup.depositor, &bounty_id, &1_000, &deadline);
+    }
+
+    setup
+        .escrow
+        .release_funds_as(&ops_a, &1, &setup.contributor);
+    setup
+        .escrow
+        .release_funds_as(&ops_b, &2, &setup.contributor);
+    setup.escrow.release_funds(&3, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 3_000);
+
+    let outsider = Address::generate(&setup.env);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &4, &1_000, &deadline);
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_funds_as(&outsider, &4, &setup.contributor),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // A removed admin loses release authority
+    setup.escrow.remove_admin(&ops_b);
+    assert!(!setup.escrow.is_admin(&ops_b));
+    assert_eq!(
+        setup
+            .escrow
+            .try_release_funds_as(&ops_b, &4, &setup.contributor),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_only_primary_admin_manages_admin_set() {
+    let setup = TestSetup::new();
+    let ops = Address::generate(&setup.env);
+    setup.escrow.add_admin(&ops);
+    assert_eq!(
+        setup.env.auths()[0].0,
+        setup.admin,
+        "add_admin is authorized by the primary admin"
+    );
+
+    // Neither the primary admin nor a secondary can remove the primary
+    assert_eq!(
+        setup.escrow.try_remove_admin(&setup.admin),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .try_remove_admin(&Address::generate(&setup.env)),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    setup.escrow.remove_admin(&ops);
+    assert_eq!(
+        setup.env.auths()[0].0,
+        setup.admin,
+        "remove_admin is authorized by the primary admin"
+    );
+    assert_eq!(
+        setup.escrow.get_admins(),
+        vec![&setup.env, setup.admin.clone()]
+    );
+}
+
+// ============================================================================
+// MULTISIG RELEASE
+// ============================================================================
+
+fn setup_multisig(setup: &TestSetup) -> (Address, Address, Address) {
+    let signers = (
+        Address::generate(&setup.env),
+        Address::generate(&setup.env),
+        Address::generate(&setup.env),
+    );
+    setup.escrow.set_release_multisig(
+        &vec![
+            &setup.env,
+            signers.0.clone(),
+            signers.1.clone(),
+            signers.2.clone(),
+        ],
+        &2,
+        &10_000,
+    );
+    signers
+}
+
+#[test]
+fn test_multisig_release_fires_on_second_distinct_confirmation() {
+    let setup = TestSetup::new();
+    let (alice, bob, _carol) = setup_multisig(&setup);
+    let deadline = setup.env.ledger().timestamp() + 1_000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &50_000, &deadline);
+
+    // High-value escrows cannot be released by a single key
+    assert_eq!(
+        setup.escrow.try_release_funds(&1, &setup.contributor),
+        Err(Ok(Error::MultisigRequired))
+    );
+    assert_eq!(
+        setup.escrow.try_can_release(&1),
+        Err(Ok(Error::MultisigRequired))
+    );
+
+    setup.escrow.propose_release(&alice, &1, &setup.contributor);
+    let proposed = setup.env.events().all().last().unwrap();
+    assert_eq!(
+        proposed.1,
+        (symbol_short!("rel_prop"), 1u64).into_val(&setup.env)
+    );
+
+    assert!(!setup.escrow.confirm_release(&alice, &1));
+    // A repeat confirmation does not count twice
+    assert_eq!(
+        setup.escrow.try_confirm_release(&alice, &1),
+        Err(Ok(Error::AlreadyConfirmed))
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+    assert_eq!(
+        setup.escrow.get_release_proposal(&1).unwrap().confirmations,
+        vec![&setup.env, alice.clone()]
+    );
+
+    assert!(setup.escrow.confirm_release(&bob, &1));
+    assert_eq!(setup.token.balance(&setup.contributor), 50_000);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Released
+    );
+    assert_eq!(setup.escrow.get_release_proposal(&1), None);
+
+    let confirm_topics = (symbol_short!("rel_conf"), 1u64).into_val(&setup.env);
+    let confirmations = setup
+        .env
+        .events()
+        .all()
+        .iter()
+        .filter(|event| event.1 == confirm_topics)
+        .count();
+    assert_eq!(confirmations, 2);
+}
+
+#[test]
+fn test_multisig_rejects_outsiders_and_spares_small_escrows() {
+    let setup = TestSetup::new();
+    let (alice, _bob, _carol) = setup_multisig(&setup);
+    let deadline = setup.env.ledger().timestamp() + 1_000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &50_000, &deadline);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1_000, &deadline);
+
+    let outsider = Address::generate(&setup.env);
+    assert_eq!(
+        setup
+            .escrow
+            .try_propose_release(&outsider, &1, &setup.contributor),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        setup.escrow.try_confirm_release(&alice, &1),
+        Err(Ok(Error::ReleaseNotProposed))
+    );
+    setup.escrow.propose_release(&alice, &1, &setup.contributor);
+    assert_eq!(
+        setup.escrow.try_confirm_release(&outsider, &1),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // Below `min_amount` the single-key path still works
+    setup.escrow.release_funds(&2, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 1_000);
+
+    assert_eq!(
+        setup
+            .escrow
+            .try_set_release_multisig(&vec![&setup.env, alice.clone()], &2, &0),
+        Err(Ok(Error::InvalidThreshold))
+    );
+    assert_eq!(
+        setup.escrow.try_set_release_multisig(
+            &vec![&setup.env, alice.clone(), alice.clone()],
+            &2,
+            &0
+        ),
+        Err(Ok(Error::InvalidThreshold))
+    );
+
+    // Clearing the multisig restores single-key releases
+    setup.escrow.set_release_multisig(&vec![&setup.env], &0, &0);
+    assert_eq!(setup.escrow.get_release_multisig(), None);
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 51_000);
+}
+
+// ============================================================================
+// SNAPSHOT DELTA
+// ============================================================================
+
+#[test]
+fn test_snapshot_delta_reflects_operations_between_reads() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1_000);
+    let baseline = setup.escrow.take_snapshot_delta();
+    assert_eq!(
+        setup.env.auths()[0].0,
+        setup.admin,
+        "advancing the baseline is authorized by the admin"
+    );
+    assert_eq!(baseline.from_timestamp, 0);
+    assert_eq!(baseline.to_timestamp, 1_000);
+    // `init` is the only tracked operation so far
+    assert_eq!(baseline.operations, 1);
+
+    let deadline = 10_000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+    setup.env.ledger().set_timestamp(1_600);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1_000, &deadline);
+    setup.escrow.release_funds(&1, &setup.contributor);
+    setup.env.ledger().set_timestamp(2_000);
+
+    let delta = setup.escrow.take_snapshot_delta();
+    assert_eq!(delta.from_timestamp, 1_000);
+    assert_eq!(delta.to_timestamp, 2_000);
+    assert_eq!(delta.elapsed, 1_000);
+    assert_eq!(delta.operations, 3);
+    assert_eq!(delta.errors, 0);
+
+    // Nothing happened since the last read
+    setup.env.ledger().set_timestamp(2_500);
+    let idle = setup.escrow.take_snapshot_delta();
+    assert_eq!(idle.elapsed, 500);
+    assert_eq!(idle.operations, 0);
+}
+
+// ============================================================================
+// LOCK INTERVAL
+// ============================================================================
+
+#[test]
+fn test_rapid_locks_are_rate_limited() {
+    let setup = TestSetup::new();
+    setup.escrow.update_min_lock_interval(&300);
+    assert_eq!(setup.escrow.get_min_lock_interval(), 300);
+    setup.env.ledger().set_timestamp(1_000);
+    let deadline = 10_000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+    setup.env.ledger().set_timestamp(1_100);
+    assert_eq!(
+        setup
+            .escrow
+            .try_lock_funds(&setup.depositor, &2, &1_000, &deadline),
+        Err(Ok(Error::RateLimited))
+    );
+    assert!(!setup.escrow.bounty_exists(&2));
+
+    // Other depositors are unaffected
+    let other = Address::generate(&setup.env);
+    setup.token_admin.mint(&other, &1_000);
+    setup.escrow.lock_funds(&other, &3, &1_000, &deadline);
+}
+
+#[test]
+fn test_spaced_locks_pass_interval() {
+    let setup = TestSetup::new();
+    setup.escrow.update_min_lock_interval(&300);
+    setup.env.ledger().set_timestamp(1_000);
+    let deadline = 10_000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+    setup.env.ledger().set_timestamp(1_300);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1_000, &deadline);
+    assert_eq!(setup.escrow.get_active_bounty_count(&setup.depositor), 2);
+
+    // Disabling the interval lifts the limit
+    setup.escrow.update_min_lock_interval(&0);
+    setup.env.ledger().set_timestamp(1_400);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &3, &1_000, &deadline);
+}
+
+// ============================================================================
+// ADMIN REFUND
+// ============================================================================
+
+#[test]
+fn test_admin_refund_before_deadline() {
+    let setup = TestSetup::new();
+    setup.escrow.update_penalty_config(&1_000, &setup.admin);
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &5_000, &deadline);
+    let before = setup.token.balance(&setup.depositor);
+
+    setup.escrow.admin_refund(&1);
+
+    // Full amount back, with no penalty withheld
+    assert_eq!(setup.token.balance(&setup.depositor), before + 5_000);
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(
+        escrow.refund_history.get(0).unwrap().reason,
+        RefundReason::AdminForced
+    );
+    assert_eq!(setup.escrow.get_total_locked(), 0);
+
+    let event = setup.env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("adm_ref"), 1u64).into_val(&setup.env)
+    );
+    let data: events::AdminRefund = event.2.into_val(&setup.env);
+    assert_eq!(data.admin, setup.admin);
+    assert_eq!(data.amount, 5_000);
+}
+
+#[test]
+fn test_admin_refund_rejects_settled_escrow() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &5_000, &deadline);
+    setup.escrow.release_funds(&1, &setup.contributor);
+
+    assert_eq!(
+        setup.escrow.try_admin_refund(&1),
+        Err(Ok(Error::FundsNotLocked))
+    );
+    assert_eq!(
+        setup.escrow.try_admin_refund(&2),
+        Err(Ok(Error::BountyNotFound))
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), 5_000);
+}
+
+// ============================================================================
+// EXPIRING BOUNTIES
+// ============================================================================
+
+#[test]
+fn test_list_expiring_within_window() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1_000);
+    let mut items = Vec::new(&setup.env);
+    for (i, deadline) in [1_500u64, 2_000, 2_001, 5_000, 1_200].iter().enumerate() {
+        items.push_back(LockFundsItem {
+            bounty_id: i as u64 + 1,
+            depositor: setup.depositor.clone(),
+            amount: 1_000,
+            deadline: *deadline,
+        });
+    }
+    setup.escrow.batch_lock_funds(&items);
+    // Settled bounties are never reported
+    setup.escrow.release_funds(&5, &setup.contributor);
+
+    assert_eq!(
+        setup.escrow.list_expiring_within(&1_000, &0, &10),
+        vec![&setup.env, 1u64, 2]
+    );
+    assert_eq!(
+        setup.escrow.list_expiring_within(&1_000, &1, &1),
+        vec![&setup.env, 2u64]
+    );
+
+    // Once a deadline passes the bounty drops out of the list
+    setup.env.ledger().set_timestamp(1_600);
+    assert_eq!(
+        setup.escrow.list_expiring_within(&1_000, &0, &10),
+        vec![&setup.env, 2u64, 3]
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .try_list_expiring_within(&1_000, &0, &(MAX_BATCH_SIZE + 1)),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+}
+
+// ============================================================================
+// CONTRIBUTOR REPUTATION
+// ============================================================================
+
+#[test]
+fn test_contributor_payout_counters() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1000;
+    for (bounty_id, amount) in [(1u64, 1_000i128), (2, 2_500), (3, 400), (4, 100)] {
+        setup
+            .escrow
+            .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    }
+    assert_eq!(
+        setup
+            .escrow
+            .get_contributor_payout_count(&setup.contributor),
+        0
+    );
+
+    setup.escrow.release_funds(&1, &setup.contributor);
+    setup.escrow.release_funds(&2, &setup.contributor);
+    setup
+        .escrow
+        .batch_release(&vec![&setup.env, 3u64], &setup.contributor, &true);
+    // Payouts to someone else don't count toward this contributor
+    setup.escrow.release_funds(&4, &setup.admin);
+
+    assert_eq!(
+        setup
+            .escrow
+            .get_contributor_payout_count(&setup.contributor),
+        3
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .get_contributor_total_received(&setup.contributor),
+        3_900
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .get_contributor_total_received(&setup.contributor),
+        setup.token.balance(&setup.contributor)
+    );
+    assert_eq!(setup.escrow.get_contributor_payout_count(&setup.admin), 1);
+    assert_eq!(
+        setup.escrow.get_contributor_total_received(&setup.admin),
+        100
+    );
+}
+
+// ============================================================================
+// MINIMUM LOCK DURATION
+// ============================================================================
+
+#[test]
+fn test_min_lock_duration_boundary() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    assert_eq!(setup.escrow.get_min_lock_duration(), 0);
+    // Disabled by default: a deadline one second out is accepted
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &100, &(now + 1));
+
+    setup.escrow.update_min_lock_duration(&3_600);
+    assert_eq!(setup.escrow.get_min_lock_duration(), 3_600);
+    assert_eq!(
+        setup
+            .escrow
+            .try_lock_funds(&setup.depositor, &2, &100, &(now + 3_599)),
+        Err(Ok(Error::DeadlineTooSoon))
+    );
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &100, &(now + 3_600));
+
+    let items = vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 3,
+            depositor: setup.depositor.clone(),
+            amount: 100,
+            deadline: now + 60,
+        },
+    ];
+    assert_eq!(
+        setup.escrow.try_batch_lock_funds(&items),
+        Err(Ok(Error::DeadlineTooSoon))
+    );
+
+    // Resetting to 0 restores the old behaviour
+    setup.escrow.update_min_lock_duration(&0);
+    setup.escrow.batch_lock_funds(&items);
+}
+
+#[test]
+fn test_min_lock_duration_must_stay_below_max_deadline() {
+    let setup = TestSetup::new();
+    let max = setup.escrow.get_max_deadline();
+    assert_eq!(
+        setup.escrow.try_update_min_lock_duration(&max),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    setup.escrow.update_min_lock_duration(&1_000);
+    assert_eq!(
+        setup.escrow.try_update_max_deadline(&1_000),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    setup.escrow.update_max_deadline(&1_001);
+}
+
+// ============================================================================
+// RELAYED LOCKS
+// ============================================================================
+
+#[test]
+fn test_lock_funds_from_spends_allowance_with_depositor_auth() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1_000;
+    let expiration = setup.env.ledger().sequence() + 100;
+    setup
+        .token
+        .approve(&setup.depositor, &setup.escrow_address, &1_500, &expiration);
+
+    // The depositor signs the lock itself, but not a nested token transfer;
+    // the contract spends the allowance
+    setup
+        .escrow
+        .lock_funds_from(&setup.depositor, &1, &1_000, &deadline);
+    let auths = setup.env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, setup.depositor);
+    assert!(auths[0].1.sub_invocations.is_empty());
+
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.depositor, setup.depositor);
+    assert_eq!(escrow.amount, 1_000);
+    assert_eq!(setup.token.balance(&setup.escrow_address), 1_000);
+    assert_eq!(
+        setup
+            .token
+            .allowance(&setup.depositor, &setup.escrow_address),
+        500
+    );
+
+    // Beyond the remaining allowance the token rejects the pull
+    assert!(setup
+        .escrow
+        .try_lock_funds_from(&setup.depositor, &2, &501, &deadline)
+        .is_err());
+    assert_eq!(setup.token.balance(&setup.escrow_address), 1_000);
+    setup
+        .escrow
+        .lock_funds_from(&setup.depositor, &2, &500, &deadline);
+    assert_eq!(
+        setup
+            .token
+            .allowance(&setup.depositor, &setup.escrow_address),
+        0
+    );
+}
+
+// ============================================================================
+// UNACCOUNTED BALANCE
+// ============================================================================
+
+#[test]
+fn test_sweep_unaccounted_recovers_stray_tokens() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1_000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &2_000, &deadline);
+    assert_eq!(setup.escrow.get_unaccounted_balance(), 0);
+    assert_eq!(
+        setup.escrow.try_sweep_unaccounted(&setup.admin),
+        Err(Ok(Error::InsufficientFunds))
+    );
+
+    // Tokens minted straight to the contract belong to no escrow
+    setup.token_admin.mint(&setup.escrow_address, &750);
+    assert_eq!(setup.escrow.get_unaccounted_balance(), 750);
+    assert_eq!(
+        setup.escrow.try_sweep_unaccounted(&setup.escrow_address),
+        Err(Ok(Error::InvalidRecipient))
+    );
+
+    let treasury = Address::generate(&setup.env);
+    assert_eq!(setup.escrow.sweep_unaccounted(&treasury), 750);
+    assert_eq!(setup.token.balance(&treasury), 750);
+    assert_eq!(setup.escrow.get_unaccounted_balance(), 0);
+    assert_eq!(setup.escrow.get_balance(), 2_000);
+
+    let event = setup.env.events().all().last().unwrap();
+    assert_eq!(event.1, (symbol_short!("sweep"),).into_val(&setup.env));
+    let data: events::UnaccountedSwept = event.2.into_val(&setup.env);
+    assert_eq!(data.amount, 750);
+    assert_eq!(data.to, treasury);
+
+    // The escrow itself is unaffected
+    setup.escrow.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 2_000);
+}
+
+// ============================================================================
+// CLAIM EXPIRY
+// ============================================================================
+
+#[test]
+fn test_claim_before_deadline_pays_contributor() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &(now + 10_000));
+    setup.escrow.authorize_claim(&1, &setup.contributor, &3_600);
+
+    let pending = setup.escrow.get_pending_claim(&1).unwrap();
+    assert_eq!(pending.contributor, setup.contributor);
+    assert_eq!(pending.amount, 1_000);
+    assert_eq!(pending.claim_deadline, now + 3_600);
+    assert_eq!(
+        setup.escrow.try_reclaim_unclaimed(&1),
+        Err(Ok(Error::DeadlineNotPassed))
+    );
+
+    // Still claimable on the deadline itself
+    setup.env.ledger().set_timestamp(now + 3_600);
+    assert_eq!(setup.escrow.claim(&1), 1_000);
+    assert_eq!(
+        setup.env.auths()[0].0,
+        setup.contributor,
+        "claim is authorized by the contributor"
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), 1_000);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Released
+    );
+    assert_eq!(setup.escrow.get_pending_claim(&1), None);
+    assert_eq!(setup.escrow.try_claim(&1), Err(Ok(Error::BountyNotFound)));
+}
+
+#[test]
+fn test_reclaim_unclaimed_after_claim_deadline() {
+    let setup = TestSetup::new();
+    setup.escrow.update_penalty_config(&1_000, &setup.admin);
+    let now = setup.env.ledger().timestamp();
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &(now + 10_000));
+    setup.escrow.authorize_claim(&1, &setup.contributor, &3_600);
+    let before = setup.token.balance(&setup.depositor);
+
+    setup.env.ledger().set_timestamp(now + 3_601);
+    assert_eq!(setup.escrow.try_claim(&1), Err(Ok(Error::ClaimExpired)));
+
+    setup.escrow.reclaim_unclaimed(&1);
+    // Full amount back, before the bounty deadline and with no penalty
+    assert_eq!(setup.token.balance(&setup.depositor), before + 1_000);
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+    let escrow = setup.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(
+        escrow.refund_history.get(0).unwrap().reason,
+        RefundReason::ClaimExpired
+    );
+    assert_eq!(setup.escrow.get_total_locked(), 0);
+    assert_eq!(
+        setup.escrow.try_reclaim_unclaimed(&1),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_claim_rechecks_release_gates() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &20_000, &(now + 10_000));
+    setup.escrow.authorize_claim(&1, &setup.contributor, &3_600);
+
+    // Gates tightened after authorization still apply at claim time
+    setup
+        .escrow
+        .set_contributor_denied(&setup.contributor, &true);
+    assert_eq!(
+        setup.escrow.try_claim(&1),
+        Err(Ok(Error::ContributorDenied))
+    );
+    setup
+        .escrow
+        .set_contributor_denied(&setup.contributor, &false);
+
+    setup_multisig(&setup);
+    assert_eq!(setup.escrow.try_claim(&1), Err(Ok(Error::MultisigRequired)));
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&1).status,
+        EscrowStatus::Locked
+    );
+}
+
+#[test]
+fn test_authorize_claim_validation() {
+    let setup = TestSetup::new();
+    setup.env.ledger().set_timestamp(1_000);
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+    assert_eq!(
+        setup
+            .escrow
+            .try_authorize_claim(&1, &setup.contributor, &3_600),
+        Err(Ok(Error::BountyNotFound))
+    );
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline);
+    assert_eq!(
+        setup.escrow.try_authorize_claim(&1, &setup.contributor, &0),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    assert_eq!(
+        setup
+            .escrow
+            .try_authorize_claim(&1, &setup.contributor, &u64::MAX),
+        Err(Ok(Error::TimeOverflow))
+    );
+
+    // A direct release settles the bounty and drops the claim
+    setup.escrow.authorize_claim(&1, &setup.contributor, &3_600);
+    setup.escrow.release_funds(&1, &setup.admin);
+    assert_eq!(setup.escrow.get_pending_claim(&1), None);
+}
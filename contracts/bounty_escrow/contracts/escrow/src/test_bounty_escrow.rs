@@ -5,7 +5,7 @@ use soroban_sdk::{
     token, vec, Address, Env,
 };
 
-use crate::{BountyEscrowContract, BountyEscrowContractClient};
+use crate::{BountyEscrowContract, BountyEscrowContractClient, InitOptions};
 
 fn create_test_env() -> (Env, BountyEscrowContractClient<'static>, Address) {
     let env = Env::default();
@@ -118,11 +118,6 @@ fn test_single_release_schedule() {
 }
 */
 
-fn create_escrow_contract<'a>(e: &Env) -> BountyEscrowContractClient<'a> {
-    let contract_id = e.register_contract(None, BountyEscrowContract);
-    BountyEscrowContractClient::new(e, &contract_id)
-}
-
 /* Release schedule tests commented out - functionality not implemented
 #[test]
 fn test_multiple_release_schedules() {
@@ -239,8 +234,8 @@ fn test_lock_fund() {
     // Get all events emitted
     let events = env.events().all();
 
-    // Verify the event was emitted (5 original events + 4 monitoring events from init & lock_funds)
-    assert_eq!(events.len(), 9);
+    // Verify the event was emitted (6 original events + 4 monitoring events from init & lock_funds)
+    assert_eq!(events.len(), 10);
 }
 
 #[test]
@@ -273,8 +268,39 @@ fn test_release_fund() {
     // Get all events emitted
     let events = env.events().all();
 
-    // Verify the event was emitted (7 original events + 6 monitoring events from init, lock_funds & release_funds)
-    assert_eq!(events.len(), 13);
+    // Verify the event was emitted (8 original events + 6 monitoring events from init, lock_funds & release_funds)
+    assert_eq!(events.len(), 14);
+}
+
+#[test]
+fn test_monitoring_disabled_skips_metric_events() {
+    let (env, client, _contract_id) = create_test_env();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init_with_options(
+        &admin,
+        &token,
+        &InitOptions {
+            permissionless_refund: true,
+            depositor_allowlist_enabled: false,
+            monitoring_enabled: false,
+        },
+    );
+    // Token `set_admin` plus the init event, without the 2 monitoring
+    // events `test_init_event` sees
+    assert_eq!(env.events().all().len(), 2);
+
+    token_admin_client.mint(&depositor, &1000);
+    client.lock_funds(&depositor, &1, &1000, &10);
+    // `test_lock_fund` sees 10 with the 4 monitoring events
+    assert_eq!(env.events().all().len(), 6);
+
+    // Counters keep running without the events
+    assert_eq!(client.get_analytics().operation_count, 2);
 }
 
 #[test]
@@ -657,36 +683,36 @@ fn test_complete_bounty_workflow_lock_refund() {
 
 #[test]
 fn test_pause_functionality() {
-    let (env, client, contract_id) = create_test_env();
+    let (env, client, _contract_id) = create_test_env();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
 
     // Create and setup token
-    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    let (token_address, _token_client, _token_admin) = create_token_contract(&env, &admin);
 
     // Initialize escrow
     client.init(&admin, &token_address);
 
     // Initially not paused
-    assert_eq!(client.is_paused(), false);
+    assert!(!client.is_paused());
 
     // Pause contract
     client.pause();
-    assert_eq!(client.is_paused(), true);
+    assert!(client.is_paused());
 
     // Unpause contract
     client.unpause();
-    assert_eq!(client.is_paused(), false);
+    assert!(!client.is_paused());
 
     // Pause again for emergency test
     client.pause();
-    assert_eq!(client.is_paused(), true);
+    assert!(client.is_paused());
 
     // Unpause to verify idempotent
     client.unpause();
     client.unpause(); // Call again - should not error
-    assert_eq!(client.is_paused(), false);
+    assert!(!client.is_paused());
 }
 
 #[test]
@@ -704,7 +730,7 @@ fn test_emergency_withdraw() {
 
     // Pause contract
     client.pause();
-    assert_eq!(client.is_paused(), true);
+    assert!(client.is_paused());
 
     // Call emergency_withdraw (it will fail gracefully if no funds)
     // The important thing is that it's callable when paused
@@ -712,5 +738,5 @@ fn test_emergency_withdraw() {
     client.emergency_withdraw(&emergency_recipient);
 
     // Verify pause state still true
-    assert_eq!(client.is_paused(), true);
+    assert!(client.is_paused());
 }
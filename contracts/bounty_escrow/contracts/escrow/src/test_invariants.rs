@@ -0,0 +1,311 @@
+#![cfg(test)]
+
+//! Property tests driving random operation sequences through the escrow and
+//! checking that its accounting never drifts from the tokens it holds and
+//! that settled escrows stay settled.
+
+use proptest::prelude::*;
+use soroban_sdk::{
+    testutils::{Address as _, EnvTestConfig, Ledger},
+    token, Address, Env,
+};
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, Error, EscrowStatus, RefundMode};
+
+const BOUNTY_IDS: u64 = 4;
+
+#[derive(Clone, Debug)]
+enum Op {
+    Lock {
+        bounty_id: u64,
+        amount: i128,
+        duration: u64,
+    },
+    Release {
+        bounty_id: u64,
+    },
+    Refund {
+        bounty_id: u64,
+    },
+    PartialRefund {
+        bounty_id: u64,
+        amount: i128,
+    },
+    Advance {
+        seconds: u64,
+    },
+}
+
+prop_compose! {
+    fn lock_op()(bounty_id in 0..BOUNTY_IDS, amount in 1i128..10_000, duration in 1u64..5_000) -> Op {
+        Op::Lock { bounty_id, amount, duration }
+    }
+}
+
+prop_compose! {
+    fn partial_refund_op()(bounty_id in 0..BOUNTY_IDS, amount in 1i128..5_000) -> Op {
+        Op::PartialRefund { bounty_id, amount }
+    }
+}
+
+/// Settlement attempts only; bounties are locked up front by the test.
+fn settle_op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        (0..BOUNTY_IDS).prop_map(|bounty_id| Op::Release { bounty_id }),
+        (0..BOUNTY_IDS).prop_map(|bounty_id| Op::Refund { bounty_id }),
+        (1u64..3_000).prop_map(|seconds| Op::Advance { seconds }),
+    ]
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        3 => lock_op(),
+        2 => (0..BOUNTY_IDS).prop_map(|bounty_id| Op::Release { bounty_id }),
+        2 => (0..BOUNTY_IDS).prop_map(|bounty_id| Op::Refund { bounty_id }),
+        1 => partial_refund_op(),
+        2 => (1u64..3_000).prop_map(|seconds| Op::Advance { seconds }),
+    ]
+}
+
+struct Harness<'a> {
+    env: Env,
+    token: token::Client<'a>,
+    token_admin: token::StellarAssetClient<'a>,
+    escrow: BountyEscrowContractClient<'a>,
+    escrow_address: Address,
+}
+
+impl<'a> Harness<'a> {
+    fn new() -> Self {
+        // Randomized cases would churn a fresh snapshot file on every run.
+        let env = Env::new_with_config(EnvTestConfig {
+            capture_snapshot_at_drop: false,
+        });
+        env.mock_all_auths();
+        env.budget().reset_unlimited();
+
+        let admin = Address::generate(&env);
+        let token_address = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+        let token = token::Client::new(&env, &token_address);
+        let token_admin = token::StellarAssetClient::new(&env, &token_address);
+
+        let escrow_address = env.register_contract(None, BountyEscrowContract);
+        let escrow = BountyEscrowContractClient::new(&env, &escrow_address);
+        escrow.init(&admin, &token_address);
+        // Admin settlements would otherwise hit the release cooldown and the
+        // hourly operation cap long before a sequence finishes.
+        env.as_contract(&escrow_address, || {
+            crate::anti_abuse::set_whitelist(&env, admin.clone(), true)
+        });
+
+        Self {
+            env,
+            token,
+            token_admin,
+            escrow,
+            escrow_address,
+        }
+    }
+
+    fn lock(&self, bounty_id: u64, amount: i128, duration: u64) -> Result<(), Error> {
+        // A fresh depositor per lock keeps the per-address rate limit from
+        // masking the paths under test.
+        let depositor = Address::generate(&self.env);
+        self.token_admin.mint(&depositor, &amount);
+        let deadline = self.env.ledger().timestamp() + duration;
+        match self
+            .escrow
+            .try_lock_funds(&depositor, &bounty_id, &amount, &deadline)
+        {
+            Ok(_) => Ok(()),
+            Err(Ok(err)) => Err(err),
+            Err(Err(_)) => panic!("lock_funds aborted"),
+        }
+    }
+
+    fn status(&self, bounty_id: u64) -> EscrowStatus {
+        self.escrow.get_escrow_info(&bounty_id).status
+    }
+
+    /// Locks at an absolute `deadline`, skipping the horizon check.
+    fn lock_at(&self, bounty_id: u64, deadline: u64) -> Result<(), Error> {
+        self.escrow.update_max_deadline(&u64::MAX);
+        let depositor = Address::generate(&self.env);
+        self.token_admin.mint(&depositor, &1_000);
+        match self
+            .escrow
+            .try_lock_funds(&depositor, &bounty_id, &1_000, &deadline)
+        {
+            Ok(_) => Ok(()),
+            Err(Ok(err)) => Err(err),
+            Err(Err(_)) => panic!("lock_funds aborted"),
+        }
+    }
+
+    /// Applies `op`, returning the contract error of a settlement that failed.
+    fn apply(&self, op: &Op) -> Option<Error> {
+        match op {
+            Op::Lock {
+                bounty_id,
+                amount,
+                duration,
+            } => self.lock(*bounty_id, *amount, *duration).err(),
+            Op::Release { bounty_id } => {
+                let contributor = Address::generate(&self.env);
+                match self.escrow.try_release_funds(bounty_id, &contributor) {
+                    Err(Ok(err)) => Some(err),
+                    _ => None,
+                }
+            }
+            Op::Refund { bounty_id } => {
+                match self
+                    .escrow
+                    .try_refund(bounty_id, &None, &None, &RefundMode::Full)
+                {
+                    Err(Ok(err)) => Some(err),
+                    _ => None,
+                }
+            }
+            Op::PartialRefund { bounty_id, amount } => {
+                let _ =
+                    self.escrow
+                        .try_refund(bounty_id, &Some(*amount), &None, &RefundMode::Partial);
+                None
+            }
+            Op::Advance { seconds } => {
+                self.env.ledger().with_mut(|li| li.timestamp += seconds);
+                None
+            }
+        }
+    }
+
+    /// Sum of what is still owed out of every unsettled escrow.
+    fn outstanding(&self) -> i128 {
+        (0..BOUNTY_IDS)
+            .filter_map(|id| self.escrow.try_get_escrow_info(&id).ok()?.ok())
+            .filter(|escrow| {
+                matches!(
+                    escrow.status,
+                    EscrowStatus::Locked | EscrowStatus::PartiallyRefunded
+                )
+            })
+            .map(|escrow| escrow.remaining_amount)
+            .sum()
+    }
+
+    fn assert_balanced(&self) -> Result<(), TestCaseError> {
+        let balance = self.token.balance(&self.escrow_address);
+        prop_assert_eq!(balance, self.escrow.get_total_locked());
+        prop_assert_eq!(balance, self.outstanding());
+        Ok(())
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn prop_balance_matches_locked_escrows(ops in prop::collection::vec(op(), 1..24)) {
+        let harness = Harness::new();
+        for op in &ops {
+            harness.apply(op);
+            harness.assert_balanced()?;
+        }
+    }
+
+    #[test]
+    fn prop_settled_escrows_never_transition(
+        durations in prop::array::uniform4(1u64..5_000),
+        ops in prop::collection::vec(settle_op(), 1..24),
+    ) {
+        let harness = Harness::new();
+        for (bounty_id, duration) in durations.iter().enumerate() {
+            harness.lock(bounty_id as u64, 1_000, *duration).unwrap();
+        }
+
+        // First terminal status observed for each bounty
+        let mut settled: [Option<EscrowStatus>; BOUNTY_IDS as usize] = Default::default();
+        for op in &ops {
+            let target = match op {
+                Op::Release { bounty_id } | Op::Refund { bounty_id } => Some(*bounty_id),
+                _ => None,
+            };
+            let before = target.map(|id| harness.status(id));
+            let err = harness.apply(op);
+
+            if let (Some(id), Some(before)) = (target, before) {
+                if matches!(before, EscrowStatus::Released | EscrowStatus::Refunded) {
+                    prop_assert_eq!(err, Some(Error::FundsNotLocked));
+                    prop_assert_eq!(harness.status(id), before);
+                }
+            }
+
+            for (id, slot) in settled.iter_mut().enumerate() {
+                let status = harness.status(id as u64);
+                match slot {
+                    Some(first) => prop_assert_eq!(&status, first),
+                    None if status != EscrowStatus::Locked => *slot = Some(status),
+                    None => {}
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn prop_deadline_arithmetic_never_wraps(
+        headroom in 1u64..1_000,
+        grace in prop_oneof![0u64..2_000, (u64::MAX - 2_000)..=u64::MAX],
+        later_grace in prop_oneof![0u64..2_000, (u64::MAX - 2_000)..=u64::MAX],
+    ) {
+        let harness = Harness::new();
+        harness.escrow.update_refund_grace(&grace);
+        let deadline = u64::MAX - headroom;
+
+        // A lock is only accepted when its refund unlock time is representable
+        let locked = harness.lock_at(0, deadline);
+        if deadline.checked_add(grace).is_some() {
+            prop_assert_eq!(locked, Ok(()));
+        } else {
+            prop_assert_eq!(locked, Err(Error::InvalidDeadline));
+            return Ok(());
+        }
+
+        // Raising the grace afterwards surfaces as an error, not a wrap
+        harness.escrow.update_refund_grace(&later_grace);
+        let overflows = deadline.checked_add(later_grace).is_none();
+        let refund = harness.escrow.try_refund(&0, &None, &None, &RefundMode::Full);
+        let refundable = harness.escrow.try_is_refundable(&0);
+        if overflows {
+            prop_assert_eq!(refund, Err(Ok(Error::TimeOverflow)));
+            prop_assert_eq!(refundable, Err(Ok(Error::TimeOverflow)));
+        } else {
+            prop_assert_eq!(refund, Err(Ok(Error::DeadlineNotPassed)));
+            prop_assert_eq!(refundable, Ok(Ok(false)));
+        }
+        prop_assert_eq!(harness.escrow.list_refundable(&0, &BOUNTY_IDS.try_into().unwrap()).len(), 0);
+        prop_assert_eq!(harness.status(0), EscrowStatus::Locked);
+        harness.assert_balanced()?;
+    }
+
+    #[test]
+    fn prop_review_window_never_wraps(
+        assigned_at in (u64::MAX - 2_000)..u64::MAX,
+        review_window in prop_oneof![1u64..2_000, (u64::MAX - 2_000)..=u64::MAX],
+    ) {
+        let harness = Harness::new();
+        harness.lock(0, 1_000, 1_000).unwrap();
+        harness.escrow.update_review_window(&review_window);
+        harness.env.ledger().with_mut(|li| li.timestamp = assigned_at);
+        harness.escrow.assign_contributor(&0, &Address::generate(&harness.env), &false);
+
+        let result = harness.escrow.try_finalize_auto_release(&0);
+        if assigned_at.checked_add(review_window).is_none() {
+            prop_assert_eq!(result, Err(Ok(Error::TimeOverflow)));
+        } else {
+            prop_assert_eq!(result, Err(Ok(Error::ReviewWindowOpen)));
+        }
+        prop_assert_eq!(harness.status(0), EscrowStatus::Locked);
+    }
+}
@@ -0,0 +1,342 @@
+//! # Program Escrow Events Module
+//!
+//! Typed events for the program lifecycle, so indexers can rebuild each
+//! program's balance and payout state off-chain.
+//!
+//! ```text
+//! Init Program → ProgramInitialized
+//!      ↓
+//! Lock Funds   → ProgramFundsLocked
+//!      ↓
+//! Payouts      → SinglePayout / BatchPayout
+//!      ↓
+//! Close        → ProgramClosed
+//! ```
+//!
+//! Alongside that flow:
+//!
+//! - Administration: `ProgramPauseChanged`, `ProgramAdminTransferred`,
+//!   `RecipientBlockChanged`
+//! - Two-signature payouts: `PayoutProposed`, `PayoutApproved`
+//! - Deferred payouts: `PayoutScheduled`, `ScheduledPayoutExecuted`,
+//!   `BatchReserved`, `ReservedChunkPaid`
+//! - Corrections: `PayoutClawedBack`
+//!
+//! Every event carries the program ID as its second topic.
+
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String};
+
+// ============================================================================
+// Program Lifecycle Events
+// ============================================================================
+
+/// Event emitted when a program is registered.
+///
+/// # Event Topic
+/// Symbol: `prog_init`, program_id
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramInitialized {
+    pub program_id: String,
+    pub authorized_payout_key: Address,
+    pub token_address: Address,
+    pub total_funds: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_program_initialized(env: &Env, event: ProgramInitialized) {
+    let topics = (symbol_short!("prog_init"), event.program_id.clone());
+    env.events().publish(topics, event);
+}
+
+/// Event emitted when funds are added to a program's prize pool.
+///
+/// `amount` is net of any lock fee.
+///
+/// # Event Topic
+/// Symbol: `prog_lock`, program_id
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramFundsLocked {
+    pub program_id: String,
+    pub amount: i128,
+    pub remaining_balance: i128,
+    pub locked_at: u64,
+}
+
+pub fn emit_program_funds_locked(env: &Env, event: ProgramFundsLocked) {
+    let topics = (symbol_short!("prog_lock"), event.program_id.clone());
+    env.events().publish(topics, event);
+}
+
+/// Event emitted when a program is closed and its unspent funds swept.
+///
+/// # Event Topic
+/// Symbol: `prg_close`, program_id
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramClosed {
+    pub program_id: String,
+    pub swept_amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_program_closed(env: &Env, event: ProgramClosed) {
+    let topics = (symbol_short!("prg_close"), event.program_id.clone());
+    env.events().publish(topics, event);
+}
+
+// ============================================================================
+// Program Administration Events
+// ============================================================================
+
+/// Event emitted when a program's payouts are paused or resumed.
+///
+/// # Event Topic
+/// Symbol: `PrgPause` when pausing, `PrgResume` when resuming, program_id
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramPauseChanged {
+    pub program_id: String,
+    pub paused: bool,
+    pub timestamp: u64,
+}
+
+pub fn emit_program_pause_changed(env: &Env, event: ProgramPauseChanged) {
+    let topic = if event.paused {
+        symbol_short!("PrgPause")
+    } else {
+        symbol_short!("PrgResume")
+    };
+    env.events()
+        .publish((topic, event.program_id.clone()), event);
+}
+
+/// Event emitted when a program's admin key changes hands.
+///
+/// # Event Topic
+/// Symbol: `PrgAdmXfr`, program_id
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramAdminTransferred {
+    pub program_id: String,
+    pub previous_admin: Address,
+    pub new_admin: Address,
+}
+
+pub fn emit_program_admin_transferred(env: &Env, event: ProgramAdminTransferred) {
+    let topics = (symbol_short!("PrgAdmXfr"), event.program_id.clone());
+    env.events().publish(topics, event);
+}
+
+/// Event emitted when a recipient is blocked or unblocked.
+///
+/// # Event Topic
+/// Symbol: `RcptBlock`, program_id
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecipientBlockChanged {
+    pub program_id: String,
+    pub recipient: Address,
+    pub blocked: bool,
+}
+
+pub fn emit_recipient_block_changed(env: &Env, event: RecipientBlockChanged) {
+    let topics = (symbol_short!("RcptBlock"), event.program_id.clone());
+    env.events().publish(topics, event);
+}
+
+// ============================================================================
+// Payout Events
+// ============================================================================
+
+/// Event emitted for a payout to one recipient.
+///
+/// `amount` is what the recipient received, net of any payout fee.
+///
+/// # Event Topic
+/// Symbol: `payout`, program_id, recipient
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SinglePayout {
+    pub program_id: String,
+    pub recipient: Address,
+    pub amount: i128,
+    pub remaining_balance: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_single_payout(env: &Env, event: SinglePayout) {
+    let topics = (
+        symbol_short!("payout"),
+        event.program_id.clone(),
+        event.recipient.clone(),
+    );
+    env.events().publish(topics, event);
+}
+
+/// Event emitted when a batch of payouts is executed.
+///
+/// `total_amount` includes payout fees.
+///
+/// # Event Topic
+/// Symbol: `batch_pay`, program_id
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchPayout {
+    pub program_id: String,
+    pub recipient_count: u32,
+    pub total_amount: i128,
+    pub remaining_balance: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_batch_payout(env: &Env, event: BatchPayout) {
+    let topics = (symbol_short!("batch_pay"), event.program_id.clone());
+    env.events().publish(topics, event);
+}
+
+/// Event emitted when a payout is clawed back into the program's balance.
+///
+/// # Event Topic
+/// Symbol: `PayClawbk`, program_id, payout_id
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutClawedBack {
+    pub program_id: String,
+    pub payout_id: u32,
+    pub recipient: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_payout_clawed_back(env: &Env, event: PayoutClawedBack) {
+    let topics = (
+        symbol_short!("PayClawbk"),
+        event.program_id.clone(),
+        event.payout_id,
+    );
+    env.events().publish(topics, event);
+}
+
+// ============================================================================
+// Payout Approval Events
+// ============================================================================
+
+/// Event emitted when the program admin proposes a payout for approval.
+///
+/// # Event Topic
+/// Symbol: `PayProp`, program_id
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutProposed {
+    pub program_id: String,
+    pub payout_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+pub fn emit_payout_proposed(env: &Env, event: PayoutProposed) {
+    let topics = (symbol_short!("PayProp"), event.program_id.clone());
+    env.events().publish(topics, event);
+}
+
+/// Event emitted when the approver signs off and the payout is executed.
+///
+/// # Event Topic
+/// Symbol: `PayAppr`, program_id
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutApproved {
+    pub program_id: String,
+    pub payout_id: u64,
+    pub approver: Address,
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+pub fn emit_payout_approved(env: &Env, event: PayoutApproved) {
+    let topics = (symbol_short!("PayAppr"), event.program_id.clone());
+    env.events().publish(topics, event);
+}
+
+// ============================================================================
+// Scheduled and Reserved Payout Events
+// ============================================================================
+
+/// Event emitted when a payout is scheduled and its amount reserved.
+///
+/// # Event Topic
+/// Symbol: `PaySched`, program_id
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutScheduled {
+    pub program_id: String,
+    pub schedule_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub unlock_at: u64,
+}
+
+pub fn emit_payout_scheduled(env: &Env, event: PayoutScheduled) {
+    let topics = (symbol_short!("PaySched"), event.program_id.clone());
+    env.events().publish(topics, event);
+}
+
+/// Event emitted when a scheduled payout is paid out.
+///
+/// # Event Topic
+/// Symbol: `PaySchExe`, program_id
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledPayoutExecuted {
+    pub program_id: String,
+    pub schedule_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_scheduled_payout_executed(env: &Env, event: ScheduledPayoutExecuted) {
+    let topics = (symbol_short!("PaySchExe"), event.program_id.clone());
+    env.events().publish(topics, event);
+}
+
+/// Event emitted when a batch of payouts is reserved for chunked execution.
+///
+/// # Event Topic
+/// Symbol: `BatchRsv`, program_id
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchReserved {
+    pub program_id: String,
+    pub reservation_id: u64,
+    pub recipient_count: u32,
+    pub total_amount: i128,
+}
+
+pub fn emit_batch_reserved(env: &Env, event: BatchReserved) {
+    let topics = (symbol_short!("BatchRsv"), event.program_id.clone());
+    env.events().publish(topics, event);
+}
+
+/// Event emitted for each paid chunk of a batch reservation.
+///
+/// Covers entries `start` up to (not including) `end`.
+///
+/// # Event Topic
+/// Symbol: `RsvPaid`, program_id
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReservedChunkPaid {
+    pub program_id: String,
+    pub reservation_id: u64,
+    pub start: u32,
+    pub end: u32,
+    pub amount: i128,
+}
+
+pub fn emit_reserved_chunk_paid(env: &Env, event: ReservedChunkPaid) {
+    let topics = (symbol_short!("RsvPaid"), event.program_id.clone());
+    env.events().publish(topics, event);
+}
@@ -61,7 +61,7 @@
 //! │  │  - total_funds                           │                  │
 //! │  │  - remaining_balance                     │                  │
 //! │  │  - authorized_payout_key                 │                  │
-//! │  │  - payout_history: [PayoutRecord]        │                  │
+//! │  │  - reserved_balance                      │                  │
 //! │  │  - token_address                         │                  │
 //! │  └──────────────────────────────────────────┘                  │
 //! └─────────────────────────────────────────────────────────────────┘
@@ -123,11 +123,13 @@
 //!
 //! ## Event System
 //!
-//! The contract emits events for all major operations:
-//! - `ProgramInit`: Program initialization
-//! - `FundsLocked`: Prize funds locked
+//! The contract emits typed events (see the `events` module) for all major
+//! operations:
+//! - `ProgramInitialized`: Program initialization
+//! - `ProgramFundsLocked`: Prize funds locked
 //! - `BatchPayout`: Multiple prizes distributed
-//! - `Payout`: Single prize distributed
+//! - `SinglePayout`: Single prize distributed
+//! - `ProgramClosed`: Program closed and unspent funds swept
 //!
 //! ## Best Practices
 //!
@@ -139,18 +141,13 @@
 //! 6. **Token Approval**: Ensure contract has token allowance before locking funds
 
 #![no_std]
+mod events;
+
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, token, vec, Address, Env, String, Symbol,
-    Vec,
+    contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short, token,
+    vec, Address, Env, String, Symbol, Vec,
 };
 
-// Event types
-#[allow(dead_code)]
-const PROGRAM_INITIALIZED: Symbol = symbol_short!("ProgInit");
-const FUNDS_LOCKED: Symbol = symbol_short!("FundLock");
-const BATCH_PAYOUT: Symbol = symbol_short!("BatchPay");
-const PAYOUT: Symbol = symbol_short!("Payout");
-
 // Storage keys
 const PROGRAM_DATA: Symbol = symbol_short!("ProgData");
 const FEE_CONFIG: Symbol = symbol_short!("FeeCfg");
@@ -160,6 +157,22 @@ const FEE_CONFIG: Symbol = symbol_short!("FeeCfg");
 const BASIS_POINTS: i128 = 10_000;
 const MAX_FEE_RATE: i128 = 1_000; // Maximum 10% fee
 
+/// Largest page returned by `get_payout_history`.
+const MAX_HISTORY_PAGE: u32 = 50;
+
+/// Largest page returned by `list_programs` and batch size for `get_programs`.
+const MAX_PROGRAM_PAGE: u32 = 50;
+
+/// Largest batch accepted by `batch_payout_strict`; its duplicate scan is O(n²).
+const MAX_STRICT_BATCH_SIZE: u32 = 100;
+
+// Most recipients paid by one execute_reserved call
+const MAX_RESERVED_CHUNK: u32 = 100;
+
+/// Recipient limit for `batch_payout` and `distribute_proportional` until
+/// the admin sets one with `set_max_batch_size`.
+const DEFAULT_MAX_BATCH_SIZE: u32 = 100;
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct FeeConfig {
@@ -168,6 +181,75 @@ pub struct FeeConfig {
     pub fee_recipient: Address, // Address to receive fees
     pub fee_enabled: bool,      // Global fee enable/disable flag
 }
+
+/// Contract errors raised via `panic_with_error!`.
+///
+/// Most validation failures still panic with a message; these typed errors
+/// are used where callers need to distinguish the failure programmatically.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// A balance update would overflow or underflow `i128`
+    ArithmeticOverflow = 1,
+    /// `recipients` and `amounts` have different lengths
+    LengthMismatch = 2,
+    /// A batch payout was requested with no recipients
+    EmptyBatch = 3,
+    /// Requested payouts exceed the program's remaining balance
+    InsufficientFunds = 4,
+    /// Proportional distribution weights sum to zero
+    ZeroTotalWeight = 5,
+    /// The program has been closed
+    ProgramClosed = 6,
+    /// Payouts for the program are paused
+    ProgramPaused = 7,
+    /// A payout would take a recipient past the program's per-recipient cap
+    RecipientCapExceeded = 8,
+    /// The same recipient appears more than once in a strict batch
+    DuplicateRecipient = 9,
+    /// The batch exceeds the maximum allowed size
+    BatchTooLarge = 10,
+    /// No scheduled payout exists with the given ID
+    ScheduledPayoutNotFound = 11,
+    /// The scheduled payout's unlock time has not been reached
+    PayoutNotUnlocked = 12,
+    /// The scheduled payout was already executed
+    PayoutAlreadyExecuted = 13,
+    /// A program with the given ID is already registered
+    ProgramExists = 14,
+    /// No admin transfer has been proposed for the program
+    NoPendingAdmin = 15,
+    /// A payout is below the program's minimum payout amount
+    PayoutTooSmall = 16,
+    /// No payout exists at the given history index
+    PayoutNotFound = 17,
+    /// The clawback window for the payout has passed or was never opened
+    ClawbackWindowClosed = 18,
+    /// The payout has already been clawed back
+    PayoutAlreadyClawedBack = 19,
+    /// The payout meets the approval threshold and must go through
+    /// `propose_payout` / `approve_payout`
+    ApprovalRequired = 20,
+    /// No pending payout exists with the given ID
+    PendingPayoutNotFound = 21,
+    /// The program was not initialized with a payout approver
+    ApprovalNotConfigured = 22,
+    /// The recipient is on the program's blocklist
+    RecipientBlocked = 23,
+    /// Payouts to the escrow contract's own address are not allowed
+    InvalidRecipient = 24,
+    /// No batch reservation exists with the given ID
+    ReservationNotFound = 25,
+    /// `execute_reserved` did not resume where the last chunk stopped
+    ReservationOutOfOrder = 26,
+    /// The program was not initialized with an expiry
+    ExpiryNotConfigured = 27,
+    /// The program's expiry has not been reached
+    ProgramNotExpired = 28,
+    /// A scheduled payout's unlock time is not in the future
+    InvalidUnlockTime = 29,
+}
 // ==================== MONITORING MODULE ====================
 mod monitoring {
     use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
@@ -264,6 +346,7 @@ mod monitoring {
     pub fn emit_performance(env: &Env, function: Symbol, duration: u64) {
         let count_key = (Symbol::new(env, "perf_cnt"), function.clone());
         let time_key = (Symbol::new(env, "perf_time"), function.clone());
+        let last_key = (Symbol::new(env, "perf_last"), function.clone());
 
         let count: u64 = env.storage().persistent().get(&count_key).unwrap_or(0);
         let total: u64 = env.storage().persistent().get(&time_key).unwrap_or(0);
@@ -272,6 +355,9 @@ mod monitoring {
         env.storage()
             .persistent()
             .set(&time_key, &(total + duration));
+        env.storage()
+            .persistent()
+            .set(&last_key, &env.ledger().timestamp());
 
         env.events().publish(
             (symbol_short!("metric"), symbol_short!("perf")),
@@ -344,7 +430,7 @@ mod monitoring {
         let total: u64 = env.storage().persistent().get(&time_key).unwrap_or(0);
         let last: u64 = env.storage().persistent().get(&last_key).unwrap_or(0);
 
-        let avg = if count > 0 { total / count } else { 0 };
+        let avg = total.checked_div(count).unwrap_or(0);
 
         PerformanceStats {
             function_name,
@@ -493,9 +579,6 @@ mod anti_abuse {
 // Event Types
 // ============================================================================
 
-/// Event emitted when a program is initialized/registerd
-const PROGRAM_REGISTERED: Symbol = symbol_short!("ProgReg");
-
 // ============================================================================
 // Storage Keys
 // ============================================================================
@@ -629,17 +712,25 @@ pub struct ProgramScheduleReleased {
 /// * `program_id` - Unique identifier for the program/hackathon
 /// * `total_funds` - Total amount of funds locked (cumulative)
 /// * `remaining_balance` - Current available balance for payouts
+/// * `reserved_balance` - Held for scheduled payouts and batch reservations
+///   that have not been paid yet; not part of `remaining_balance`
 /// * `authorized_payout_key` - Address authorized to trigger payouts
-/// * `payout_history` - Complete record of all payouts
+/// * `payout_history` - Legacy inline log, no longer written; payouts are
+///   recorded in the paged log read through `get_payout_history`
 /// * `token_address` - Token contract used for transfers
+/// * `status` - Lifecycle state; closed programs accept no further payouts
+/// * `paused` - Payouts are frozen while set; funding and queries still work
+/// * `payout_count` - Payouts made through `single_payout` and `batch_payout`
+/// * `unique_recipients` - Distinct addresses among those payouts
 ///
 /// # Storage
 /// Stored in instance storage with key `PROGRAM_DATA`.
 ///
 /// # Invariants
 /// - `remaining_balance <= total_funds` (always)
-/// - `remaining_balance = total_funds - sum(payout_history.amounts)`
-/// - `payout_history` is append-only
+/// - `remaining_balance + reserved_balance <= total_funds`; the difference is
+///   what has left the program as payouts, fees, refunds or sweeps
+/// - The paged payout log (`get_payout_history`) is append-only
 /// - `program_id` and `authorized_payout_key` are immutable after init
 ///
 /// # Example
@@ -648,9 +739,14 @@ pub struct ProgramScheduleReleased {
 ///     program_id: String::from_str(&env, "Hackathon2024"),
 ///     total_funds: 10_000_0000000,
 ///     remaining_balance: 7_000_0000000,
+///     reserved_balance: 0,
 ///     authorized_payout_key: backend_address,
 ///     payout_history: vec![&env],
 ///     token_address: usdc_token_address,
+///     status: ProgramStatus::Active,
+///     paused: false,
+///     payout_count: 0,
+///     unique_recipients: 0,
 /// };
 /// ```
 /// Complete program state and configuration.
@@ -660,8 +756,9 @@ pub struct ProgramScheduleReleased {
 ///
 /// # Invariants
 /// - `remaining_balance <= total_funds` (always)
-/// - `remaining_balance = total_funds - sum(payout_history.amounts)`
-/// - `payout_history` is append-only
+/// - `remaining_balance + reserved_balance <= total_funds`; the difference is
+///   what has left the program as payouts, fees, refunds or sweeps
+/// - The paged payout log (`get_payout_history`) is append-only
 /// - `program_id` and `authorized_payout_key` are immutable after registration
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -669,20 +766,98 @@ pub struct ProgramData {
     pub program_id: String,
     pub total_funds: i128,
     pub remaining_balance: i128,
+    pub reserved_balance: i128,
     pub authorized_payout_key: Address,
     pub payout_history: Vec<PayoutRecord>,
     pub token_address: Address,
+    pub status: ProgramStatus,
+    pub paused: bool,
+    pub payout_count: u64,
+    pub unique_recipients: u64,
+}
+
+/// A vesting payout whose amount is reserved out of `remaining_balance`
+/// until it unlocks.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledPayout {
+    pub schedule_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub unlock_at: u64,
+    pub executed: bool,
+}
+
+/// A batch payout whose total is reserved out of `remaining_balance` and
+/// paid out in chunks. `paid_count` is the index of the next unpaid entry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchReservation {
+    pub reservation_id: u64,
+    pub recipients: Vec<Address>,
+    pub amounts: Vec<i128>,
+    pub total: i128,
+    pub paid_count: u32,
+}
+
+/// Second-signature requirement for large payouts.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutApprovalConfig {
+    pub approver: Address,
+    pub threshold: i128, // payouts at or above this need approval
+}
+
+/// A payout awaiting the approver's signature.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingPayout {
+    pub payout_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub proposed_at: u64,
+    pub executed: bool,
+}
+
+/// Lifecycle state of a program.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProgramStatus {
+    Active, // Accepting funds and payouts
+    Closed, // Unspent funds swept back; no further payouts
 }
 
 /// Storage key type for individual programs
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
-    Program(String),              // program_id -> ProgramData
-    ReleaseSchedule(String, u64), // program_id, schedule_id -> ProgramReleaseSchedule
-    ReleaseHistory(String),       // program_id -> Vec<ProgramReleaseHistory>
-    NextScheduleId(String),       // program_id -> next schedule_id
-    IsPaused,                     // Global contract pause state
+    Program(String),                   // program_id -> ProgramData
+    ReleaseSchedule(String, u64),      // program_id, schedule_id -> ProgramReleaseSchedule
+    ReleaseHistory(String),            // program_id -> Vec<ProgramReleaseHistory>
+    NextScheduleId(String),            // program_id -> next schedule_id
+    IsPaused,                          // Global contract pause state
+    RecipientCap(String),              // program_id -> max cumulative payout per recipient
+    RecipientPaid(String, Address),    // program_id, recipient -> cumulative amount paid
+    PayoutRef(String, String),         // program_id, ref_id -> processed marker
+    PayoutHistoryEntry(String, u32),   // program_id, index -> PayoutRecord, append-only
+    PayoutHistoryLen(String),          // program_id -> number of PayoutHistoryEntry records
+    Funders(String),                   // program_id -> Vec<(Address, i128)> net contributions
+    ScheduledPayout(String, u64),      // program_id, schedule_id -> ScheduledPayout
+    NextScheduledPayoutId(String),     // program_id -> next scheduled payout id
+    PendingProgramAdmin(String),       // program_id -> proposed authorized payout key
+    MinPayout(String),                 // program_id -> smallest allowed payout amount
+    ClawbackWindow(String),            // program_id -> seconds a payout stays reversible
+    ClawedBack(String, u32),           // program_id, payout history index -> reversed marker
+    PayoutApproval(String),            // program_id -> PayoutApprovalConfig
+    PendingPayout(String, u64),        // program_id, payout_id -> PendingPayout
+    NextPendingPayoutId(String),       // program_id -> next pending payout id
+    BlockedRecipient(String, Address), // program_id, recipient -> blocked marker
+    BatchReservation(String, u64),     // program_id, reservation_id -> BatchReservation
+    NextReservationId(String),         // program_id -> next reservation id
+    ProgramExpiry(String),             // program_id -> timestamp after which funds are refundable
+    MaxBatchSize,                      // Most recipients accepted by one batch payout
+    TotalPaidOut,                      // Lifetime net payouts across all programs
+    PaidRecipient(String, Address),    // program_id, recipient -> paid at least once marker
 }
 
 // ============================================================================
@@ -730,7 +905,7 @@ impl ProgramEscrowContract {
     /// - Program ID should be unique and descriptive
     ///
     /// # Events
-    /// Emits: `ProgramInit(program_id, authorized_payout_key, token_address, 0)`
+    /// Emits: `ProgramInitialized { program_id, authorized_payout_key, token_address, .. }`
     ///
     /// # Example
     /// ```rust
@@ -782,6 +957,13 @@ impl ProgramEscrowContract {
             .unwrap_or(false)
     }
 
+    /// Rejects batches longer than the configured maximum batch size.
+    fn ensure_batch_size(env: &Env, len: u32) {
+        if len > Self::get_max_batch_size(env.clone()) {
+            panic_with_error!(env, Error::BatchTooLarge);
+        }
+    }
+
     /// Get pause status (view function)
     pub fn is_paused(env: Env) -> bool {
         Self::is_paused_internal(&env)
@@ -873,7 +1055,7 @@ impl ProgramEscrowContract {
         let program_key = DataKey::Program(program_id.clone());
         if env.storage().instance().has(&program_key) {
             monitoring::track_operation(&env, symbol_short!("init_prg"), caller, false);
-            panic!("Program already exists");
+            panic_with_error!(&env, Error::ProgramExists);
         }
 
         // Create program data
@@ -881,9 +1063,14 @@ impl ProgramEscrowContract {
             program_id: program_id.clone(),
             total_funds: 0,
             remaining_balance: 0,
+            reserved_balance: 0,
             authorized_payout_key: authorized_payout_key.clone(),
             payout_history: vec![&env],
             token_address: token_address.clone(),
+            status: ProgramStatus::Active,
+            paused: false,
+            payout_count: 0,
+            unique_recipients: 0,
         };
 
         // Initialize fee config with zero fees (disabled by default)
@@ -908,9 +1095,15 @@ impl ProgramEscrowContract {
         env.storage().instance().set(&PROGRAM_REGISTRY, &registry);
 
         // Emit registration event
-        env.events().publish(
-            (PROGRAM_REGISTERED,),
-            (program_id, authorized_payout_key, token_address, 0i128),
+        events::emit_program_initialized(
+            &env,
+            events::ProgramInitialized {
+                program_id,
+                authorized_payout_key,
+                token_address,
+                total_funds: 0,
+                timestamp: env.ledger().timestamp(),
+            },
         );
 
         // Track successful operation
@@ -923,6 +1116,226 @@ impl ProgramEscrowContract {
         program_data
     }
 
+    /// Initializes a program with a cap on the cumulative amount any single
+    /// recipient can be paid.
+    ///
+    /// Behaves like `initialize_program`; afterwards every `single_payout`
+    /// and `batch_payout` is checked against `recipient_cap`.
+    ///
+    /// # Panics
+    /// * If `recipient_cap` is zero or negative
+    /// * Under the same conditions as `initialize_program`
+    pub fn initialize_program_with_cap(
+        env: Env,
+        program_id: String,
+        authorized_payout_key: Address,
+        token_address: Address,
+        recipient_cap: i128,
+    ) -> ProgramData {
+        if recipient_cap <= 0 {
+            panic!("Recipient cap must be greater than zero");
+        }
+
+        let program_data = Self::initialize_program(
+            env.clone(),
+            program_id.clone(),
+            authorized_payout_key,
+            token_address,
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::RecipientCap(program_id), &recipient_cap);
+
+        program_data
+    }
+
+    /// Initializes a program that rejects payouts below `min_payout`.
+    ///
+    /// Behaves like `initialize_program`; afterwards `single_payout` and
+    /// every entry of `batch_payout` must be at least `min_payout`. A
+    /// `min_payout` of 0 means no minimum.
+    ///
+    /// # Panics
+    /// * If `min_payout` is negative
+    /// * Under the same conditions as `initialize_program`
+    pub fn initialize_program_with_minimum(
+        env: Env,
+        program_id: String,
+        authorized_payout_key: Address,
+        token_address: Address,
+        min_payout: i128,
+    ) -> ProgramData {
+        if min_payout < 0 {
+            panic!("Minimum payout cannot be negative");
+        }
+
+        let program_data = Self::initialize_program(
+            env.clone(),
+            program_id.clone(),
+            authorized_payout_key,
+            token_address,
+        );
+        if min_payout > 0 {
+            env.storage()
+                .instance()
+                .set(&DataKey::MinPayout(program_id), &min_payout);
+        }
+
+        program_data
+    }
+
+    /// Initializes a program whose large payouts need a second signature.
+    ///
+    /// Behaves like `initialize_program`; afterwards any `single_payout` or
+    /// `batch_payout` entry of at least `approval_threshold` fails with
+    /// `Error::ApprovalRequired` and must instead be proposed with
+    /// `propose_payout` and executed by `approver` via `approve_payout`.
+    ///
+    /// # Panics
+    /// * If `approver` is the authorized payout key
+    /// * If `approval_threshold` is zero or negative
+    /// * Under the same conditions as `initialize_program`
+    pub fn initialize_program_with_approver(
+        env: Env,
+        program_id: String,
+        authorized_payout_key: Address,
+        token_address: Address,
+        approver: Address,
+        approval_threshold: i128,
+    ) -> ProgramData {
+        if approver == authorized_payout_key {
+            panic!("Approver must differ from the authorized payout key");
+        }
+        if approval_threshold <= 0 {
+            panic!("Approval threshold must be greater than zero");
+        }
+
+        let program_data = Self::initialize_program(
+            env.clone(),
+            program_id.clone(),
+            authorized_payout_key,
+            token_address,
+        );
+        env.storage().instance().set(
+            &DataKey::PayoutApproval(program_id),
+            &PayoutApprovalConfig {
+                approver,
+                threshold: approval_threshold,
+            },
+        );
+
+        program_data
+    }
+
+    /// Initializes a program whose unspent funds become refundable at `expiry`.
+    ///
+    /// Behaves like `initialize_program`; once the ledger reaches `expiry`,
+    /// anyone may call `refund_program` to return the remaining balance to
+    /// the program's funders.
+    ///
+    /// # Panics
+    /// * If `expiry` is not in the future
+    /// * Under the same conditions as `initialize_program`
+    pub fn initialize_program_with_expiry(
+        env: Env,
+        program_id: String,
+        authorized_payout_key: Address,
+        token_address: Address,
+        expiry: u64,
+    ) -> ProgramData {
+        if expiry <= env.ledger().timestamp() {
+            panic!("Expiry must be in the future");
+        }
+
+        let program_data = Self::initialize_program(
+            env.clone(),
+            program_id.clone(),
+            authorized_payout_key,
+            token_address,
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::ProgramExpiry(program_id), &expiry);
+
+        program_data
+    }
+
+    /// Returns the program's expiry timestamp, if one was configured.
+    pub fn get_program_expiry(env: Env, program_id: String) -> Option<u64> {
+        env.storage()
+            .instance()
+            .get(&DataKey::ProgramExpiry(program_id))
+    }
+
+    /// Returns the program's approval requirement, if one was configured.
+    pub fn get_payout_approval_config(
+        env: Env,
+        program_id: String,
+    ) -> Option<PayoutApprovalConfig> {
+        env.storage()
+            .instance()
+            .get(&DataKey::PayoutApproval(program_id))
+    }
+
+    /// Returns the program's minimum payout amount (0 if none).
+    pub fn get_min_payout(env: Env, program_id: String) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinPayout(program_id))
+            .unwrap_or(0)
+    }
+
+    /// Blocks payouts from a program to `recipient` (program admin only).
+    ///
+    /// Every payout path, including each entry of a batch, then fails with
+    /// `Error::RecipientBlocked` for this address. Idempotent.
+    pub fn block_recipient(env: Env, program_id: String, recipient: Address) {
+        Self::set_recipient_blocked(env, program_id, recipient, true);
+    }
+
+    /// Lifts a block placed with `block_recipient` (program admin only).
+    pub fn unblock_recipient(env: Env, program_id: String, recipient: Address) {
+        Self::set_recipient_blocked(env, program_id, recipient, false);
+    }
+
+    /// Returns whether `recipient` is blocked from receiving program payouts.
+    pub fn is_recipient_blocked(env: Env, program_id: String, recipient: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::BlockedRecipient(program_id, recipient))
+    }
+
+    fn set_recipient_blocked(env: Env, program_id: String, recipient: Address, blocked: bool) {
+        let program_data = Self::get_program_info(env.clone(), program_id.clone());
+        program_data.authorized_payout_key.require_auth();
+
+        let key = DataKey::BlockedRecipient(program_id.clone(), recipient.clone());
+        if blocked {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+
+        events::emit_recipient_block_changed(
+            &env,
+            events::RecipientBlockChanged {
+                program_id,
+                recipient,
+                blocked,
+            },
+        );
+    }
+
+    /// Returns the cumulative amount paid to `recipient` by a program.
+    ///
+    /// Amounts are gross, i.e. they include any payout fee.
+    pub fn get_recipient_paid(env: Env, program_id: String, recipient: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RecipientPaid(program_id, recipient))
+            .unwrap_or(0)
+    }
+
     /// Calculate fee amount based on rate (in basis points)
     fn calculate_fee(amount: i128, fee_rate: i128) -> i128 {
         if fee_rate == 0 {
@@ -948,25 +1361,205 @@ impl ProgramEscrowContract {
             })
     }
 
+    /// Panics with `Error::ProgramClosed` unless the program is active.
+    fn ensure_program_open(env: &Env, program_data: &ProgramData) {
+        if program_data.status == ProgramStatus::Closed {
+            panic_with_error!(env, Error::ProgramClosed);
+        }
+    }
+
+    /// Panics unless the program is open and its payouts are not paused.
+    fn ensure_payouts_enabled(env: &Env, program_data: &ProgramData) {
+        Self::ensure_program_open(env, program_data);
+        if program_data.paused {
+            panic_with_error!(env, Error::ProgramPaused);
+        }
+    }
+
+    /// Panics unless the program may pay `recipient`: the contract itself
+    /// and blocked addresses are rejected.
+    fn ensure_recipient_allowed(env: &Env, program_id: &String, recipient: &Address) {
+        if *recipient == env.current_contract_address() {
+            panic_with_error!(env, Error::InvalidRecipient);
+        }
+        if env.storage().persistent().has(&DataKey::BlockedRecipient(
+            program_id.clone(),
+            recipient.clone(),
+        )) {
+            panic_with_error!(env, Error::RecipientBlocked);
+        }
+    }
+
+    /// Adds a payout to the recipient's running total, enforcing the
+    /// program's per-recipient cap if one is configured.
+    fn record_recipient_payout(env: &Env, program_id: &String, recipient: &Address, amount: i128) {
+        Self::ensure_recipient_allowed(env, program_id, recipient);
+
+        let paid_key = DataKey::RecipientPaid(program_id.clone(), recipient.clone());
+        let paid: i128 = env.storage().persistent().get(&paid_key).unwrap_or(0);
+        let new_total = paid
+            .checked_add(amount)
+            .unwrap_or_else(|| panic_with_error!(env, Error::ArithmeticOverflow));
+
+        let cap: Option<i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey::RecipientCap(program_id.clone()));
+        if let Some(cap) = cap {
+            if new_total > cap {
+                panic_with_error!(env, Error::RecipientCapExceeded);
+            }
+        }
+
+        env.storage().persistent().set(&paid_key, &new_total);
+    }
+
+    /// Marks `recipient` as paid by the program, returning whether this is
+    /// the first payout it has received.
+    fn mark_recipient_paid(env: &Env, program_id: &String, recipient: &Address) -> bool {
+        let key = DataKey::PaidRecipient(program_id.clone(), recipient.clone());
+        if env.storage().persistent().has(&key) {
+            return false;
+        }
+        env.storage().persistent().set(&key, &true);
+        true
+    }
+
+    fn ensure_min_payout(env: &Env, program_id: &String, amount: i128) {
+        let min_payout: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MinPayout(program_id.clone()))
+            .unwrap_or(0);
+        if amount < min_payout {
+            panic_with_error!(env, Error::PayoutTooSmall);
+        }
+    }
+
+    /// Rejects direct payouts that need a second signature.
+    fn ensure_no_approval_required(env: &Env, program_id: &String, amount: i128) {
+        let config: Option<PayoutApprovalConfig> = env
+            .storage()
+            .instance()
+            .get(&DataKey::PayoutApproval(program_id.clone()));
+        if let Some(config) = config {
+            if amount >= config.threshold {
+                panic_with_error!(env, Error::ApprovalRequired);
+            }
+        }
+    }
+
+    /// Appends payout records to the program's persistent payout log.
+    fn append_payout_history(env: &Env, program_id: &String, records: Vec<PayoutRecord>) {
+        let mut index = Self::payout_history_len(env, program_id);
+        let mut paid: i128 = 0;
+        for record in records.iter() {
+            paid += record.amount;
+            env.storage().persistent().set(
+                &DataKey::PayoutHistoryEntry(program_id.clone(), index),
+                &record,
+            );
+            index += 1;
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::PayoutHistoryLen(program_id.clone()), &index);
+
+        Self::add_total_paid_out(env, paid);
+    }
+
+    /// Number of records in a program's payout log.
+    fn payout_history_len(env: &Env, program_id: &String) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PayoutHistoryLen(program_id.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Adds `amount` to the lifetime payout total kept for `get_total_paid_out`.
+    fn add_total_paid_out(env: &Env, amount: i128) {
+        let total: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalPaidOut)
+            .unwrap_or(0);
+        let total = total
+            .checked_add(amount)
+            .unwrap_or_else(|| panic_with_error!(env, Error::ArithmeticOverflow));
+        env.storage().instance().set(&DataKey::TotalPaidOut, &total);
+    }
+
+    /// Splits `pool` by `weights`, assigning the rounding remainder to the
+    /// last entry so the shares always sum to `pool`.
+    fn split_pro_rata(env: &Env, pool: i128, weights: &Vec<i128>, total_weight: i128) -> Vec<i128> {
+        let mut shares: Vec<i128> = vec![env];
+        let mut allocated: i128 = 0;
+        let last = weights.len() - 1;
+        for (i, weight) in weights.iter().enumerate() {
+            let share = if i as u32 == last {
+                pool - allocated
+            } else {
+                pool.checked_mul(weight)
+                    .unwrap_or_else(|| panic_with_error!(env, Error::ArithmeticOverflow))
+                    / total_weight
+            };
+            allocated += share;
+            shares.push_back(share);
+        }
+        shares
+    }
+
     /// Lock initial funds into the program escrow
     ///
-    /// Lists all registered program IDs in the contract.
+    /// Lists registered program IDs in registration order.
+    ///
+    /// # Arguments
+    /// * `start` - Index of the first program to return
+    /// * `limit` - Maximum programs to return (capped at 50)
     ///
     /// # Returns
-    /// * `Vec<String>` - List of all program IDs
+    /// * `Vec<String>` - A page of program IDs
     ///
     /// # Example
     /// ```rust
-    /// let programs = escrow_client.list_programs();
+    /// let programs = escrow_client.list_programs(&0, &20);
     /// for program_id in programs.iter() {
     ///     println!("Program: {}", program_id);
     /// }
     /// ```
-    pub fn list_programs(env: Env) -> Vec<String> {
-        env.storage()
+    pub fn list_programs(env: Env, start: u32, limit: u32) -> Vec<String> {
+        let registry: Vec<String> = env
+            .storage()
             .instance()
             .get(&PROGRAM_REGISTRY)
-            .unwrap_or(vec![&env])
+            .unwrap_or(vec![&env]);
+
+        let end = start
+            .saturating_add(limit.min(MAX_PROGRAM_PAGE))
+            .min(registry.len());
+        if start >= end {
+            return vec![&env];
+        }
+        registry.slice(start..end)
+    }
+
+    /// Retrieves several programs in one call.
+    ///
+    /// The result lines up positionally with `program_ids`: `None` for
+    /// unknown IDs, `Some(program)` otherwise.
+    ///
+    /// # Panics
+    /// * With `Error::BatchTooLarge` if more than 50 IDs are given
+    pub fn get_programs(env: Env, program_ids: Vec<String>) -> Vec<Option<ProgramData>> {
+        if program_ids.len() > MAX_PROGRAM_PAGE {
+            panic_with_error!(&env, Error::BatchTooLarge);
+        }
+
+        let mut programs = vec![&env];
+        for program_id in program_ids.iter() {
+            programs.push_back(env.storage().instance().get(&DataKey::Program(program_id)));
+        }
+        programs
     }
 
     /// Checks if a program exists.
@@ -981,45 +1574,343 @@ impl ProgramEscrowContract {
         env.storage().instance().has(&program_key)
     }
 
-    // ========================================================================
-    // Fund Management
-    // ========================================================================
-
-    /// Locks funds into the program escrow for prize distribution.
+    /// Closes a program and returns its unspent funds.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `amount` - Amount of tokens to lock (in token's smallest denomination)
+    /// * `program_id` - The program to close
+    /// * `refund_funders` - Split the sweep pro rata across recorded funders
+    ///   instead of returning it to the program admin. Funds locked without
+    ///   attribution count as the admin's contribution, so their share still
+    ///   goes to the admin. Falls back to the admin if no funders were recorded.
     ///
     /// # Returns
-    /// * `ProgramData` - Updated program data with new balance
+    /// * `ProgramData` - The closed program with a zero `remaining_balance`
     ///
     /// # Panics
-    /// * If amount is zero or negative
-    /// * If program is not initialized
-    ///
-    /// # State Changes
-    /// - Increases `total_funds` by amount
-    /// - Increases `remaining_balance` by amount
-    /// - Emits FundsLocked event
+    /// * If program is not found
+    /// * With `Error::ProgramClosed` if the program is already closed
     ///
-    /// # Prerequisites
-    /// Before calling this function:
-    /// 1. Caller must have sufficient token balance
-    /// 2. Caller must approve contract for token transfer
-    /// 3. Tokens must actually be transferred to contract
+    /// # Authorization
+    /// - Only the authorized payout key (program admin) can call this function
     ///
-    /// # Security Considerations
-    /// - Amount must be positive
-    /// - This function doesn't perform the actual token transfer
-    /// - Caller is responsible for transferring tokens to contract
-    /// - Consider verifying contract balance matches recorded amount
-    /// - Multiple lock operations are additive (cumulative)
+    /// # State Changes
+    /// - Transfers `remaining_balance` plus `reserved_balance` to the
+    ///   authorized payout key or funders
+    /// - Cancels unexecuted scheduled payouts and unpaid batch reservations;
+    ///   their funds are part of the sweep
+    /// - Sets `status` to `ProgramStatus::Closed`; later locks and payouts fail
     ///
     /// # Events
-    /// Emits: `FundsLocked(program_id, amount, new_remaining_balance)`
-    ///
-    /// # Example
+    /// Emits: `ProgramClosed { program_id, swept_amount, timestamp }`
+    pub fn close_program(env: Env, program_id: String, refund_funders: bool) -> ProgramData {
+        let start = env.ledger().timestamp();
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Program(program_id.clone()))
+            .unwrap_or_else(|| panic!("Program not found"));
+
+        Self::ensure_program_open(&env, &program_data);
+        program_data.authorized_payout_key.require_auth();
+
+        Self::sweep_program(
+            &env,
+            program_id,
+            program_data,
+            refund_funders,
+            symbol_short!("close"),
+            start,
+        )
+    }
+
+    /// Refunds an expired program's unspent funds (permissionless).
+    ///
+    /// Once the ledger reaches the expiry set by
+    /// `initialize_program_with_expiry`, the remaining and reserved balances
+    /// are split pro rata across the recorded funders (or returned to the program admin if
+    /// none were recorded) and the program is closed, exactly as
+    /// `close_program(program_id, true)` would. Funds locked without
+    /// attribution are refunded to the program admin, so a funder never
+    /// receives more than their own contribution. No authorization is
+    /// required, so funds never depend on the organizer staying responsive.
+    ///
+    /// # Panics
+    /// * If program is not found
+    /// * With `Error::ProgramClosed` if the program is already closed
+    /// * With `Error::ExpiryNotConfigured` if the program has no expiry
+    /// * With `Error::ProgramNotExpired` before the expiry
+    ///
+    /// # Events
+    /// Emits: `ProgramClosed { program_id, swept_amount, timestamp }`
+    pub fn refund_program(env: Env, program_id: String) -> ProgramData {
+        let start = env.ledger().timestamp();
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Program(program_id.clone()))
+            .unwrap_or_else(|| panic!("Program not found"));
+
+        Self::ensure_program_open(&env, &program_data);
+        let expiry: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ProgramExpiry(program_id.clone()))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::ExpiryNotConfigured));
+        if env.ledger().timestamp() < expiry {
+            panic_with_error!(&env, Error::ProgramNotExpired);
+        }
+
+        Self::sweep_program(
+            &env,
+            program_id,
+            program_data,
+            true,
+            symbol_short!("prg_rfnd"),
+            start,
+        )
+    }
+
+    /// Marks the program closed and transfers out its remaining and reserved
+    /// balances, pro rata to funders when `refund_funders` is set and any
+    /// exist. Open schedules and reservations can no longer execute once the
+    /// program is closed, so their funds are swept with the rest.
+    ///
+    /// When refunding funders, the sweep is split over `total_funds`: each
+    /// funder gets their contribution's share, which never exceeds what they
+    /// put in, and the share of funds locked through plain
+    /// `lock_program_funds` goes to the program admin.
+    fn sweep_program(
+        env: &Env,
+        program_id: String,
+        mut program_data: ProgramData,
+        refund_funders: bool,
+        operation: Symbol,
+        start: u64,
+    ) -> ProgramData {
+        let program_key = DataKey::Program(program_id.clone());
+        let swept_amount = program_data.remaining_balance + program_data.reserved_balance;
+        program_data.remaining_balance = 0;
+        program_data.reserved_balance = 0;
+        program_data.status = ProgramStatus::Closed;
+        env.storage().instance().set(&program_key, &program_data);
+
+        if swept_amount > 0 {
+            let token_client = token::Client::new(env, &program_data.token_address);
+            let contract_address = env.current_contract_address();
+            let funders = Self::get_funders(env.clone(), program_id.clone());
+
+            if refund_funders && !funders.is_empty() {
+                let mut contributions: Vec<i128> = vec![env];
+                let mut total_contributed: i128 = 0;
+                for (_, contributed) in funders.iter() {
+                    contributions.push_back(contributed);
+                    total_contributed += contributed;
+                }
+                let unattributed = (program_data.total_funds - total_contributed).max(0);
+                if unattributed > 0 {
+                    contributions.push_back(unattributed);
+                }
+                let shares = Self::split_pro_rata(
+                    env,
+                    swept_amount,
+                    &contributions,
+                    total_contributed + unattributed,
+                );
+                for (i, (funder, _)) in funders.iter().enumerate() {
+                    let share = shares.get(i as u32).unwrap();
+                    if share > 0 {
+                        token_client.transfer(&contract_address, &funder, &share);
+                    }
+                }
+                if unattributed > 0 {
+                    let admin_share = shares.get(funders.len()).unwrap();
+                    if admin_share > 0 {
+                        token_client.transfer(
+                            &contract_address,
+                            &program_data.authorized_payout_key,
+                            &admin_share,
+                        );
+                    }
+                }
+            } else {
+                token_client.transfer(
+                    &contract_address,
+                    &program_data.authorized_payout_key,
+                    &swept_amount,
+                );
+            }
+        }
+
+        events::emit_program_closed(
+            env,
+            events::ProgramClosed {
+                program_id,
+                swept_amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(
+            env,
+            operation.clone(),
+            program_data.authorized_payout_key.clone(),
+            true,
+        );
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(env, symbol_short!("close"), duration);
+
+        program_data
+    }
+
+    /// Freezes payouts for a single program (program admin only).
+    ///
+    /// While paused, `single_payout`, `batch_payout`, `distribute_proportional`
+    /// and schedule releases fail with `Error::ProgramPaused`. Funding and
+    /// view functions keep working. Idempotent.
+    pub fn pause_program(env: Env, program_id: String) -> ProgramData {
+        Self::set_program_paused(env, program_id, true)
+    }
+
+    /// Re-enables payouts for a paused program (program admin only).
+    pub fn resume_program(env: Env, program_id: String) -> ProgramData {
+        Self::set_program_paused(env, program_id, false)
+    }
+
+    fn set_program_paused(env: Env, program_id: String, paused: bool) -> ProgramData {
+        let program_key = DataKey::Program(program_id.clone());
+        let mut program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&program_key)
+            .unwrap_or_else(|| panic!("Program not found"));
+
+        Self::ensure_program_open(&env, &program_data);
+        program_data.authorized_payout_key.require_auth();
+
+        if program_data.paused == paused {
+            return program_data;
+        }
+
+        program_data.paused = paused;
+        env.storage().instance().set(&program_key, &program_data);
+
+        events::emit_program_pause_changed(
+            &env,
+            events::ProgramPauseChanged {
+                program_id,
+                paused,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        program_data
+    }
+
+    /// Returns the program's admin, i.e. its authorized payout key.
+    ///
+    /// # Panics
+    /// * If program is not found
+    pub fn get_program_admin(env: Env, program_id: String) -> Address {
+        Self::get_program_info(env, program_id).authorized_payout_key
+    }
+
+    /// Proposes a new admin for a program (current program admin only).
+    ///
+    /// Nothing changes until `new_admin` calls `accept_program_admin`, so a
+    /// mistyped address cannot lock the program out. A later proposal
+    /// replaces an earlier one.
+    ///
+    /// # Panics
+    /// * If program is not found
+    pub fn propose_program_admin(env: Env, program_id: String, new_admin: Address) {
+        let program_data = Self::get_program_info(env.clone(), program_id.clone());
+        program_data.authorized_payout_key.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingProgramAdmin(program_id), &new_admin);
+    }
+
+    /// Completes an admin transfer; must be authorized by the proposed admin.
+    ///
+    /// The new admin becomes the program's `authorized_payout_key` and takes
+    /// over payouts, scheduling, pausing and closure.
+    ///
+    /// # Panics
+    /// * If program is not found
+    /// * With `Error::NoPendingAdmin` if no transfer was proposed
+    ///
+    /// # Events
+    /// Emits: `ProgramAdminTransferred`
+    pub fn accept_program_admin(env: Env, program_id: String) -> ProgramData {
+        let pending_key = DataKey::PendingProgramAdmin(program_id.clone());
+        let new_admin: Address = env
+            .storage()
+            .instance()
+            .get(&pending_key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::NoPendingAdmin));
+        new_admin.require_auth();
+
+        let program_key = DataKey::Program(program_id.clone());
+        let mut program_data = Self::get_program_info(env.clone(), program_id.clone());
+        let previous_admin = program_data.authorized_payout_key.clone();
+        program_data.authorized_payout_key = new_admin.clone();
+        env.storage().instance().set(&program_key, &program_data);
+        env.storage().instance().remove(&pending_key);
+
+        events::emit_program_admin_transferred(
+            &env,
+            events::ProgramAdminTransferred {
+                program_id,
+                previous_admin,
+                new_admin,
+            },
+        );
+
+        program_data
+    }
+
+    // ========================================================================
+    // Fund Management
+    // ========================================================================
+
+    /// Locks funds into the program escrow for prize distribution.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `amount` - Amount of tokens to lock (in token's smallest denomination)
+    ///
+    /// # Returns
+    /// * `ProgramData` - Updated program data with new balance
+    ///
+    /// # Panics
+    /// * If amount is zero or negative
+    /// * If program is not initialized
+    /// * With `Error::ArithmeticOverflow` if the new balance would overflow
+    ///
+    /// # State Changes
+    /// - Increases `total_funds` by amount
+    /// - Increases `remaining_balance` by amount
+    /// - Emits ProgramFundsLocked event
+    ///
+    /// # Prerequisites
+    /// Before calling this function:
+    /// 1. Caller must have sufficient token balance
+    /// 2. Caller must approve contract for token transfer
+    /// 3. Tokens must actually be transferred to contract
+    ///
+    /// # Security Considerations
+    /// - Amount must be positive
+    /// - This function doesn't perform the actual token transfer
+    /// - Caller is responsible for transferring tokens to contract
+    /// - Consider verifying contract balance matches recorded amount
+    /// - Multiple lock operations are additive (cumulative)
+    ///
+    /// # Events
+    /// Emits: `ProgramFundsLocked { program_id, amount, remaining_balance, locked_at }`
+    ///
+    /// # Example
     /// ```rust
     /// use soroban_sdk::token;
     ///
@@ -1067,7 +1958,7 @@ impl ProgramEscrowContract {
         // Apply rate limiting
         anti_abuse::check_rate_limit(&env, env.current_contract_address());
 
-        let _start = env.ledger().timestamp();
+        let start = env.ledger().timestamp();
         let caller = env.current_contract_address();
 
         // Check if contract is paused
@@ -1093,6 +1984,8 @@ impl ProgramEscrowContract {
                 panic!("Program not found")
             });
 
+        Self::ensure_program_open(&env, &program_data);
+
         // Calculate and collect fee if enabled
         let fee_config = Self::get_fee_config_internal(&env);
         let fee_amount = if fee_config.fee_enabled && fee_config.lock_fee_rate > 0 {
@@ -1103,8 +1996,14 @@ impl ProgramEscrowContract {
         let net_amount = amount - fee_amount;
 
         // Update balances with net amount
-        program_data.total_funds += net_amount;
-        program_data.remaining_balance += net_amount;
+        program_data.total_funds = program_data
+            .total_funds
+            .checked_add(net_amount)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::ArithmeticOverflow));
+        program_data.remaining_balance = program_data
+            .remaining_balance
+            .checked_add(net_amount)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::ArithmeticOverflow));
 
         // Emit fee collected event if applicable
         if fee_amount > 0 {
@@ -1122,19 +2021,80 @@ impl ProgramEscrowContract {
         // Store updated data
         env.storage().instance().set(&program_key, &program_data);
 
-        // Emit FundsLocked event (with net amount after fee)
-        env.events().publish(
-            (FUNDS_LOCKED,),
-            (
-                program_data.program_id.clone(),
-                net_amount,
-                program_data.remaining_balance,
-            ),
+        // Emit ProgramFundsLocked event (with net amount after fee)
+        events::emit_program_funds_locked(
+            &env,
+            events::ProgramFundsLocked {
+                program_id: program_data.program_id.clone(),
+                amount: net_amount,
+                remaining_balance: program_data.remaining_balance,
+                locked_at: env.ledger().timestamp(),
+            },
         );
 
+        monitoring::track_operation(&env, symbol_short!("lock"), caller, true);
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("lock"), duration);
+
+        program_data
+    }
+
+    /// Locks funds on behalf of `funder` and attributes the contribution.
+    ///
+    /// Pulls `amount` from `funder` into the contract, then behaves like
+    /// `lock_program_funds` for whatever actually arrived and adds the net
+    /// amount to the funder's entry in `get_funders`. Co-sponsored pools use
+    /// this so each sponsor's share can be refunded on `close_program`; since
+    /// the funder pays in here, a contribution can only be attributed to the
+    /// address that funded it.
+    pub fn lock_program_funds_from(
+        env: Env,
+        program_id: String,
+        funder: Address,
+        amount: i128,
+    ) -> ProgramData {
+        funder.require_auth();
+
+        if amount <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+
+        let program = Self::get_program_info(env.clone(), program_id.clone());
+        let contract = env.current_contract_address();
+        let token_client = token::Client::new(&env, &program.token_address);
+        let balance_before = token_client.balance(&contract);
+        token_client.transfer(&funder, &contract, &amount);
+        let received = token_client.balance(&contract) - balance_before;
+
+        let before = program.total_funds;
+        let program_data = Self::lock_program_funds(env.clone(), program_id.clone(), received);
+        let net_amount = program_data.total_funds - before;
+
+        let key = DataKey::Funders(program_id);
+        let mut funders: Vec<(Address, i128)> =
+            env.storage().persistent().get(&key).unwrap_or(vec![&env]);
+        let existing = funders.iter().position(|(addr, _)| addr == funder);
+        match existing {
+            Some(i) => {
+                let (_, contributed) = funders.get(i as u32).unwrap();
+                funders.set(i as u32, (funder, contributed + net_amount));
+            }
+            None => funders.push_back((funder, net_amount)),
+        }
+        env.storage().persistent().set(&key, &funders);
+
         program_data
     }
 
+    /// Returns each recorded funder with their cumulative net contribution,
+    /// in order of first contribution.
+    pub fn get_funders(env: Env, program_id: String) -> Vec<(Address, i128)> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Funders(program_id))
+            .unwrap_or(vec![&env])
+    }
+
     // ========================================================================
     // Payout Functions
     // ========================================================================
@@ -1152,11 +2112,13 @@ impl ProgramEscrowContract {
     /// # Panics
     /// * If caller is not the authorized payout key
     /// * If program is not initialized
-    /// * If recipients and amounts vectors have different lengths
-    /// * If vectors are empty
+    /// * With `Error::LengthMismatch` if recipients and amounts differ in length
+    /// * With `Error::EmptyBatch` if vectors are empty
+    /// * With `Error::BatchTooLarge` if there are more recipients than `get_max_batch_size`
     /// * If any amount is zero or negative
-    /// * If total payout exceeds remaining balance
-    /// * If arithmetic overflow occurs
+    /// * With `Error::InsufficientFunds` if total payout exceeds remaining balance
+    /// * With `Error::ProgramClosed` if the program has been closed
+    /// * With `Error::ArithmeticOverflow` if arithmetic overflow occurs
     ///
     /// # Authorization
     /// - **CRITICAL**: Only authorized payout key can call
@@ -1181,7 +2143,7 @@ impl ProgramEscrowContract {
     /// - Consider implementing payout limits for additional safety
     ///
     /// # Events
-    /// Emits: `BatchPayout(program_id, recipient_count, total_amount, new_balance)`
+    /// Emits: `BatchPayout { program_id, recipient_count, total_amount, remaining_balance, .. }`
     ///
     /// # Example
     /// ```rust
@@ -1231,7 +2193,7 @@ impl ProgramEscrowContract {
     /// 5. Keep batch size reasonable (recommend < 50 recipients)
     ///
     /// # Limitations
-    /// - Maximum batch size limited by gas/resource limits
+    /// - At most `get_max_batch_size` recipients per call (100 by default)
     /// - For very large batches, consider multiple calls
     /// - All amounts must be positive  
     pub fn batch_payout(
@@ -1240,11 +2202,24 @@ impl ProgramEscrowContract {
         recipients: Vec<Address>,
         amounts: Vec<i128>,
     ) -> ProgramData {
+        let start = env.ledger().timestamp();
+
         // Check if contract is paused
         if Self::is_paused_internal(&env) {
             panic!("Contract is paused");
         }
 
+        // Validate inputs
+        if recipients.len() != amounts.len() {
+            panic_with_error!(&env, Error::LengthMismatch);
+        }
+
+        if recipients.is_empty() {
+            panic_with_error!(&env, Error::EmptyBatch);
+        }
+
+        Self::ensure_batch_size(&env, recipients.len());
+
         // Apply rate limiting to the contract itself or the program
         // We can't easily get the caller here without getting program data first
 
@@ -1256,21 +2231,14 @@ impl ProgramEscrowContract {
             .get(&program_key)
             .unwrap_or_else(|| panic!("Program not found"));
 
+        Self::ensure_payouts_enabled(&env, &program_data);
+
         // Apply rate limiting to the authorized payout key
         anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
 
         // Verify authorization - CRITICAL
         program_data.authorized_payout_key.require_auth();
 
-        // Validate inputs
-        if recipients.len() != amounts.len() {
-            panic!("Recipients and amounts vectors must have the same length");
-        }
-
-        if recipients.is_empty() {
-            panic!("Cannot process empty batch");
-        }
-
         // Calculate total with overflow protection
         let mut total_payout: i128 = 0;
         for i in 0..amounts.len() {
@@ -1278,32 +2246,122 @@ impl ProgramEscrowContract {
             if amount <= 0 {
                 panic!("All amounts must be greater than zero");
             }
+            Self::ensure_min_payout(&env, &program_id, amount);
+            Self::ensure_no_approval_required(&env, &program_id, amount);
             total_payout = total_payout
                 .checked_add(amount)
-                .unwrap_or_else(|| panic!("Payout amount overflow"));
+                .unwrap_or_else(|| panic_with_error!(&env, Error::ArithmeticOverflow));
         }
 
-        // Validate balance
+        // Validate the whole batch against the balance before any transfer,
+        // so an over-budget batch never pays out a subset of recipients
         if total_payout > program_data.remaining_balance {
-            panic!(
-                "Insufficient balance: requested {}, available {}",
-                total_payout, program_data.remaining_balance
-            );
+            panic_with_error!(&env, Error::InsufficientFunds);
+        }
+
+        let updated_data = Self::execute_batch_payout(
+            &env,
+            program_id,
+            program_key,
+            program_data,
+            &recipients,
+            &amounts,
+            total_payout,
+        );
+
+        monitoring::track_operation(
+            &env,
+            symbol_short!("batch_pay"),
+            updated_data.authorized_payout_key.clone(),
+            true,
+        );
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("batch_pay"), duration);
+
+        updated_data
+    }
+
+    /// Like `batch_payout`, but rejects batches that list a recipient twice.
+    ///
+    /// Guards against mistyped batches that would double-pay someone. The
+    /// duplicate scan compares every pair of recipients, so it costs O(n²)
+    /// and batches are capped at 100 recipients.
+    ///
+    /// # Panics
+    /// * With `Error::BatchTooLarge` if more than 100 recipients are given
+    /// * With `Error::DuplicateRecipient` if any address appears twice
+    /// * Under the same conditions as `batch_payout`
+    pub fn batch_payout_strict(
+        env: Env,
+        program_id: String,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> ProgramData {
+        if recipients.len() > MAX_STRICT_BATCH_SIZE {
+            panic_with_error!(&env, Error::BatchTooLarge);
+        }
+
+        for i in 0..recipients.len() {
+            let recipient = recipients.get(i).unwrap();
+            for j in (i + 1)..recipients.len() {
+                if recipients.get(j).unwrap() == recipient {
+                    panic_with_error!(&env, Error::DuplicateRecipient);
+                }
+            }
+        }
+
+        Self::batch_payout(env, program_id, recipients, amounts)
+    }
+
+    /// Transfers a pre-validated batch and records it against the program.
+    ///
+    /// Callers must have checked authorization, lengths, and that
+    /// `total_payout` (the sum of `amounts`) fits in `remaining_balance`.
+    /// Zero amounts are skipped.
+    fn execute_batch_payout(
+        env: &Env,
+        program_id: String,
+        program_key: DataKey,
+        program_data: ProgramData,
+        recipients: &Vec<Address>,
+        amounts: &Vec<i128>,
+        total_payout: i128,
+    ) -> ProgramData {
+        // Enforce recipient caps up front; duplicates within the batch accumulate
+        for i in 0..recipients.len() {
+            let amount = amounts.get(i).unwrap();
+            if amount > 0 {
+                Self::record_recipient_payout(
+                    env,
+                    &program_id,
+                    &recipients.get(i).unwrap(),
+                    amount,
+                );
+            }
         }
 
         // Calculate fees if enabled
-        let fee_config = Self::get_fee_config_internal(&env);
+        let fee_config = Self::get_fee_config_internal(env);
         let mut total_fees: i128 = 0;
 
         // Execute transfers
-        let mut updated_history = program_data.payout_history.clone();
+        let mut new_records: Vec<PayoutRecord> = vec![env];
         let timestamp = env.ledger().timestamp();
         let contract_address = env.current_contract_address();
-        let token_client = token::Client::new(&env, &program_data.token_address);
+        let token_client = token::Client::new(env, &program_data.token_address);
+        let mut payout_count: u64 = 0;
+        let mut new_recipients: u64 = 0;
 
         for i in 0..recipients.len() {
             let recipient = recipients.get(i).unwrap();
             let amount = amounts.get(i).unwrap();
+            if amount == 0 {
+                continue;
+            }
+            payout_count += 1;
+            if Self::mark_recipient_paid(env, &program_id, &recipient) {
+                new_recipients += 1;
+            }
 
             // Calculate fee for this payout
             let fee_amount = if fee_config.fee_enabled && fee_config.payout_fee_rate > 0 {
@@ -1328,8 +2386,9 @@ impl ProgramEscrowContract {
                 amount: net_amount,
                 timestamp,
             };
-            updated_history.push_back(payout_record);
+            new_records.push_back(payout_record);
         }
+        Self::append_payout_history(env, &program_id, new_records);
 
         // Emit fee collected event if applicable
         if total_fees > 0 {
@@ -1346,30 +2405,232 @@ impl ProgramEscrowContract {
 
         // Update program data
         let mut updated_data = program_data.clone();
-        updated_data.remaining_balance -= total_payout; // Total includes fees
-        updated_data.payout_history = updated_history;
+        // Total includes fees
+        updated_data.remaining_balance = updated_data
+            .remaining_balance
+            .checked_sub(total_payout)
+            .unwrap_or_else(|| panic_with_error!(env, Error::ArithmeticOverflow));
+        updated_data.payout_count += payout_count;
+        updated_data.unique_recipients += new_recipients;
 
         // Store updated data
         env.storage().instance().set(&program_key, &updated_data);
 
-        // Emit event
-        env.events().publish(
-            (BATCH_PAYOUT,),
-            (
+        events::emit_batch_payout(
+            env,
+            events::BatchPayout {
                 program_id,
-                recipients.len(),
-                total_payout,
-                updated_data.remaining_balance,
-            ),
+                recipient_count: recipients.len(),
+                total_amount: total_payout,
+                remaining_balance: updated_data.remaining_balance,
+                timestamp,
+            },
         );
 
         updated_data
     }
 
-    /// Executes a single payout to one recipient.
+    /// Splits the program's entire remaining balance by weight.
+    ///
+    /// Each recipient receives `remaining_balance * weight / total_weight`;
+    /// the rounding remainder goes to the last recipient so the pool is
+    /// fully distributed. Transfers, fees and history follow `batch_payout`.
     ///
     /// # Arguments
-    /// * `env` - The contract environment
+    /// * `program_id` - The program whose pool is distributed
+    /// * `recipients` - Recipient addresses
+    /// * `weights` - Relative shares (e.g. placement points), same length as `recipients`
+    ///
+    /// # Panics
+    /// * With `Error::LengthMismatch` if the vectors differ in length
+    /// * With `Error::EmptyBatch` if no recipients are given
+    /// * With `Error::BatchTooLarge` if there are more recipients than `get_max_batch_size`
+    /// * With `Error::ZeroTotalWeight` if the weights sum to zero
+    /// * With `Error::InsufficientFunds` if the pool is empty
+    /// * With `Error::PayoutTooSmall` / `Error::ApprovalRequired` if any
+    ///   non-zero share is below the minimum or meets the approval threshold
+    ///
+    /// # Authorization
+    /// - Only the authorized payout key can call this function
+    ///
+    /// # Example
+    /// ```rust
+    /// // 1st/2nd/3rd place split 50/30/20
+    /// escrow_client.distribute_proportional(
+    ///     &program_id,
+    ///     &vec![&env, first, second, third],
+    ///     &vec![&env, 50, 30, 20],
+    /// );
+    /// ```
+    pub fn distribute_proportional(
+        env: Env,
+        program_id: String,
+        recipients: Vec<Address>,
+        weights: Vec<u32>,
+    ) -> ProgramData {
+        let start = env.ledger().timestamp();
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
+
+        if recipients.len() != weights.len() {
+            panic_with_error!(&env, Error::LengthMismatch);
+        }
+
+        if recipients.is_empty() {
+            panic_with_error!(&env, Error::EmptyBatch);
+        }
+
+        Self::ensure_batch_size(&env, recipients.len());
+
+        let total_weight: i128 = weights.iter().map(i128::from).sum();
+        if total_weight == 0 {
+            panic_with_error!(&env, Error::ZeroTotalWeight);
+        }
+
+        let program_key = DataKey::Program(program_id.clone());
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&program_key)
+            .unwrap_or_else(|| panic!("Program not found"));
+
+        Self::ensure_payouts_enabled(&env, &program_data);
+
+        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
+        program_data.authorized_payout_key.require_auth();
+
+        let pool = program_data.remaining_balance;
+        if pool <= 0 {
+            panic_with_error!(&env, Error::InsufficientFunds);
+        }
+
+        let mut weights_i128: Vec<i128> = vec![&env];
+        for weight in weights.iter() {
+            weights_i128.push_back(i128::from(weight));
+        }
+        let amounts = Self::split_pro_rata(&env, pool, &weights_i128, total_weight);
+        for amount in amounts.iter() {
+            if amount > 0 {
+                Self::ensure_min_payout(&env, &program_id, amount);
+                Self::ensure_no_approval_required(&env, &program_id, amount);
+            }
+        }
+
+        let updated_data = Self::execute_batch_payout(
+            &env,
+            program_id,
+            program_key,
+            program_data,
+            &recipients,
+            &amounts,
+            pool,
+        );
+
+        monitoring::track_operation(
+            &env,
+            symbol_short!("prop_pay"),
+            updated_data.authorized_payout_key.clone(),
+            true,
+        );
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("prop_pay"), duration);
+
+        updated_data
+    }
+
+    /// Splits `total` evenly among `recipients` in one batch.
+    ///
+    /// Each recipient receives `total / recipients.len()`; the remainder of
+    /// the division goes to the first recipient so exactly `total` leaves the
+    /// pool. Transfers, fees and history follow `batch_payout`.
+    ///
+    /// # Panics
+    /// * If `total` is zero or negative
+    /// * With `Error::EmptyBatch` if no recipients are given
+    /// * With `Error::BatchTooLarge` if there are more recipients than `get_max_batch_size`
+    /// * With `Error::InsufficientFunds` if `total` exceeds the remaining balance
+    /// * With `Error::PayoutTooSmall` / `Error::ApprovalRequired` if any share
+    ///   is below the minimum or meets the approval threshold, as for
+    ///   `batch_payout`
+    ///
+    /// # Authorization
+    /// - Only the authorized payout key can call this function
+    pub fn split_equal(
+        env: Env,
+        program_id: String,
+        recipients: Vec<Address>,
+        total: i128,
+    ) -> ProgramData {
+        let start = env.ledger().timestamp();
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
+
+        if recipients.is_empty() {
+            panic_with_error!(&env, Error::EmptyBatch);
+        }
+        Self::ensure_batch_size(&env, recipients.len());
+
+        if total <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+
+        let program_key = DataKey::Program(program_id.clone());
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&program_key)
+            .unwrap_or_else(|| panic!("Program not found"));
+
+        Self::ensure_payouts_enabled(&env, &program_data);
+
+        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
+        program_data.authorized_payout_key.require_auth();
+
+        if total > program_data.remaining_balance {
+            panic_with_error!(&env, Error::InsufficientFunds);
+        }
+
+        let count = i128::from(recipients.len());
+        let share = total / count;
+        let mut amounts: Vec<i128> = vec![&env, share + total % count];
+        for _ in 1..recipients.len() {
+            amounts.push_back(share);
+        }
+        for amount in amounts.iter() {
+            if amount > 0 {
+                Self::ensure_min_payout(&env, &program_id, amount);
+                Self::ensure_no_approval_required(&env, &program_id, amount);
+            }
+        }
+
+        let updated_data = Self::execute_batch_payout(
+            &env,
+            program_id,
+            program_key,
+            program_data,
+            &recipients,
+            &amounts,
+            total,
+        );
+
+        monitoring::track_operation(
+            &env,
+            symbol_short!("split_eq"),
+            updated_data.authorized_payout_key.clone(),
+            true,
+        );
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("split_eq"), duration);
+
+        updated_data
+    }
+
+    /// Executes a single payout to one recipient.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
     /// * `recipient` - Address of the prize recipient
     /// * `amount` - Amount to transfer (in token's smallest denomination)
     ///
@@ -1380,7 +2641,9 @@ impl ProgramEscrowContract {
     /// * If caller is not the authorized payout key
     /// * If program is not initialized
     /// * If amount is zero or negative
-    /// * If amount exceeds remaining balance
+    /// * With `Error::InsufficientFunds` if amount exceeds remaining balance,
+    ///   before any transfer is attempted
+    /// * With `Error::ProgramClosed` if the program has been closed
     ///
     /// # Authorization
     /// - Only authorized payout key can call this function
@@ -1389,7 +2652,7 @@ impl ProgramEscrowContract {
     /// - Transfers tokens from contract to recipient
     /// - Adds PayoutRecord to history
     /// - Decreases `remaining_balance` by amount
-    /// - Emits Payout event
+    /// - Emits SinglePayout event
     ///
     /// # Security Considerations
     /// - Verify recipient address before calling
@@ -1398,7 +2661,7 @@ impl ProgramEscrowContract {
     /// - Transfer is logged in payout history
     ///
     /// # Events
-    /// Emits: `Payout(program_id, recipient, amount, new_balance)`
+    /// Emits: `SinglePayout { program_id, recipient, amount, remaining_balance, .. }`
     ///
     /// # Example
     /// ```rust
@@ -1425,6 +2688,8 @@ impl ProgramEscrowContract {
         recipient: Address,
         amount: i128,
     ) -> ProgramData {
+        let start = env.ledger().timestamp();
+
         // Check if contract is paused
         if Self::is_paused_internal(&env) {
             panic!("Contract is paused");
@@ -1438,6 +2703,8 @@ impl ProgramEscrowContract {
             .get(&program_key)
             .unwrap_or_else(|| panic!("Program not found"));
 
+        Self::ensure_payouts_enabled(&env, &program_data);
+
         program_data.authorized_payout_key.require_auth();
         // Apply rate limiting to the authorized payout key
         anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
@@ -1452,15 +2719,17 @@ impl ProgramEscrowContract {
         if amount <= 0 {
             panic!("Amount must be greater than zero");
         }
+        Self::ensure_min_payout(&env, &program_id, amount);
+        Self::ensure_no_approval_required(&env, &program_id, amount);
 
-        // Validate balance
+        // Validate balance before moving funds; don't rely on the token
+        // transfer failing, since the contract may hold other programs' funds
         if amount > program_data.remaining_balance {
-            panic!(
-                "Insufficient balance: requested {}, available {}",
-                amount, program_data.remaining_balance
-            );
+            panic_with_error!(&env, Error::InsufficientFunds);
         }
 
+        Self::record_recipient_payout(&env, &program_id, &recipient, amount);
+
         // Calculate and collect fee if enabled
         let fee_config = Self::get_fee_config_internal(&env);
         let fee_amount = if fee_config.fee_enabled && fee_config.payout_fee_rate > 0 {
@@ -1498,150 +2767,426 @@ impl ProgramEscrowContract {
             timestamp,
         };
 
-        let mut updated_history = program_data.payout_history.clone();
-        updated_history.push_back(payout_record);
+        Self::append_payout_history(&env, &program_id, vec![&env, payout_record]);
 
         // Update program data
         let mut updated_data = program_data.clone();
-        updated_data.remaining_balance -= amount; // Total amount (includes fee)
-        updated_data.payout_history = updated_history;
+        // Total amount (includes fee)
+        updated_data.remaining_balance = updated_data
+            .remaining_balance
+            .checked_sub(amount)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::ArithmeticOverflow));
+        updated_data.payout_count += 1;
+        if Self::mark_recipient_paid(&env, &program_id, &recipient) {
+            updated_data.unique_recipients += 1;
+        }
 
         // Store updated data
         env.storage().instance().set(&program_key, &updated_data);
 
-        // Emit Payout event (with net amount after fee)
-        // Emit event
-        env.events().publish(
-            (PAYOUT,),
-            (
+        // Emit SinglePayout event (with net amount after fee)
+        events::emit_single_payout(
+            &env,
+            events::SinglePayout {
                 program_id,
                 recipient,
-                net_amount,
-                updated_data.remaining_balance,
-            ),
+                amount: net_amount,
+                remaining_balance: updated_data.remaining_balance,
+                timestamp,
+            },
+        );
+
+        monitoring::track_operation(
+            &env,
+            symbol_short!("payout"),
+            updated_data.authorized_payout_key.clone(),
+            true,
         );
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("payout"), duration);
 
         updated_data
     }
 
-    // ========================================================================
-    // Release Schedule Functions
-    // ========================================================================
-
-    /// Creates a time-based release schedule for a program.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `program_id` - The program to create schedule for
-    /// * `amount` - Amount to release (in token's smallest denomination)
-    /// * `release_timestamp` - Unix timestamp when funds become available
-    /// * `recipient` - Address that will receive the funds
-    ///
-    /// # Returns
-    /// * `ProgramData` - Updated program data
-    ///
-    /// # Panics
-    /// * If program is not initialized
-    /// * If caller is not authorized payout key
-    /// * If amount is invalid
-    /// * If timestamp is in the past
-    /// * If amount exceeds remaining balance
-    ///
-    /// # State Changes
-    /// - Creates ProgramReleaseSchedule record
-    /// - Updates next schedule ID
-    /// - Emits ScheduleCreated event
+    /// Executes a single payout at most once per `ref_id`.
     ///
-    /// # Authorization
-    /// - Only authorized payout key can call this function
+    /// Backends that retry submissions should pass a stable `ref_id` (e.g.
+    /// their internal payout ID). The first call behaves like `single_payout`
+    /// and records the reference; repeated calls with the same `ref_id`
+    /// return the current program data without transferring again.
     ///
-    /// # Example
-    /// ```rust
-    /// let now = env.ledger().timestamp();
-    /// let release_time = now + (30 * 24 * 60 * 60); // 30 days from now
-    /// escrow_client.create_program_release_schedule(
-    ///     &"Hackathon2024",
-    ///     &500_0000000, // 500 tokens
-    ///     &release_time,
-    ///     &winner_address
-    /// );
-    /// ```
-    pub fn create_program_release_schedule(
+    /// # Arguments
+    /// * `program_id` - The program to pay from
+    /// * `recipient` - Address of the prize recipient
+    /// * `amount` - Amount to transfer
+    /// * `ref_id` - Caller-chosen idempotency key, unique per program
+    pub fn single_payout_idempotent(
         env: Env,
         program_id: String,
-        amount: i128,
-        release_timestamp: u64,
         recipient: Address,
+        amount: i128,
+        ref_id: String,
     ) -> ProgramData {
-        let start = env.ledger().timestamp();
-
-        // Check if contract is paused
-        if Self::is_paused_internal(&env) {
-            panic!("Contract is paused");
+        let ref_key = DataKey::PayoutRef(program_id.clone(), ref_id);
+        if env.storage().persistent().has(&ref_key) {
+            return Self::get_program_info(env, program_id);
         }
 
-        // Get program data
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
+        let program_data = Self::single_payout(env.clone(), program_id, recipient, amount);
+        env.storage().persistent().set(&ref_key, &true);
 
-        // Apply rate limiting to the authorized payout key
-        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
+        program_data
+    }
 
-        // Verify authorization
+    // ========================================================================
+    // Payout Clawback
+    // ========================================================================
+
+    /// Sets how long after a payout it may be clawed back (program admin only).
+    ///
+    /// A window of 0 (the default) disables clawbacks.
+    pub fn set_clawback_window(env: Env, program_id: String, window_secs: u64) {
+        let program_data = Self::get_program_info(env.clone(), program_id.clone());
         program_data.authorized_payout_key.require_auth();
 
-        // Validate amount
-        if amount <= 0 {
-            panic!("Amount must be greater than zero");
-        }
+        env.storage()
+            .instance()
+            .set(&DataKey::ClawbackWindow(program_id), &window_secs);
+    }
 
-        // Validate timestamp
-        if release_timestamp <= env.ledger().timestamp() {
-            panic!("Release timestamp must be in the future");
-        }
+    /// Returns a mistaken payout to the program's balance.
+    ///
+    /// The contract cannot pull tokens back on its own, so this only works if
+    /// the recipient cooperates: both the program admin and the recipient
+    /// must authorize the call. The net amount the recipient received is
+    /// transferred back and added to `remaining_balance`; any payout fee
+    /// already sent to the fee recipient is not recovered.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program that made the payout
+    /// * `payout_id` - Index of the payout in `get_payout_history`
+    ///
+    /// # Panics
+    /// * If program is not found
+    /// * With `Error::ProgramClosed` if the program has been closed, since
+    ///   clawed-back funds could no longer be paid out or swept
+    /// * With `Error::PayoutNotFound` if `payout_id` is out of range
+    /// * With `Error::ClawbackWindowClosed` if the window has passed
+    /// * With `Error::PayoutAlreadyClawedBack` on a repeated clawback
+    ///
+    /// # Events
+    /// Emits: `PayClawbk` with `(recipient, amount)`
+    pub fn clawback_payout(env: Env, program_id: String, payout_id: u32) -> ProgramData {
+        let program_key = DataKey::Program(program_id.clone());
+        let mut program_data = Self::get_program_info(env.clone(), program_id.clone());
+        Self::ensure_program_open(&env, &program_data);
+        program_data.authorized_payout_key.require_auth();
 
-        // Check sufficient remaining balance
-        let scheduled_total = get_program_total_scheduled_amount(&env, &program_id);
-        if scheduled_total + amount > program_data.remaining_balance {
-            panic!("Insufficient balance for scheduled amount");
+        let record: PayoutRecord = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PayoutHistoryEntry(program_id.clone(), payout_id))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::PayoutNotFound));
+
+        let clawed_key = DataKey::ClawedBack(program_id.clone(), payout_id);
+        if env.storage().persistent().has(&clawed_key) {
+            panic_with_error!(&env, Error::PayoutAlreadyClawedBack);
         }
 
-        // Get next schedule ID
-        let schedule_id: u64 = env
+        let window: u64 = env
             .storage()
-            .persistent()
-            .get(&DataKey::NextScheduleId(program_id.clone()))
-            .unwrap_or(1);
+            .instance()
+            .get(&DataKey::ClawbackWindow(program_id.clone()))
+            .unwrap_or(0);
+        if window == 0 || env.ledger().timestamp() > record.timestamp.saturating_add(window) {
+            panic_with_error!(&env, Error::ClawbackWindowClosed);
+        }
 
-        // Create release schedule
-        let schedule = ProgramReleaseSchedule {
-            schedule_id,
-            amount,
-            release_timestamp,
-            recipient: recipient.clone(),
-            released: false,
-            released_at: None,
-            released_by: None,
-        };
+        record.recipient.require_auth();
 
-        // Store schedule
-        env.storage().persistent().set(
-            &DataKey::ReleaseSchedule(program_id.clone(), schedule_id),
-            &schedule,
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        token_client.transfer(
+            &record.recipient,
+            &env.current_contract_address(),
+            &record.amount,
         );
 
-        // Update next schedule ID
-        env.storage().persistent().set(
-            &DataKey::NextScheduleId(program_id.clone()),
-            &(schedule_id + 1),
-        );
+        program_data.remaining_balance = program_data
+            .remaining_balance
+            .checked_add(record.amount)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::ArithmeticOverflow));
+        env.storage().instance().set(&program_key, &program_data);
+        env.storage().persistent().set(&clawed_key, &true);
 
-        // Emit program schedule created event
-        env.events().publish(
+        let paid_key = DataKey::RecipientPaid(program_id.clone(), record.recipient.clone());
+        let paid: i128 = env.storage().persistent().get(&paid_key).unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&paid_key, &paid.saturating_sub(record.amount).max(0));
+
+        events::emit_payout_clawed_back(
+            &env,
+            events::PayoutClawedBack {
+                program_id,
+                payout_id,
+                recipient: record.recipient,
+                amount: record.amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        program_data
+    }
+
+    // ========================================================================
+    // Payout Approval (Two-Signature Payouts)
+    // ========================================================================
+
+    /// Records a payout that executes once the program's approver signs off.
+    ///
+    /// Funds are not reserved; the balance is checked again on approval.
+    ///
+    /// # Returns
+    /// * `u64` - The pending payout ID, unique within the program
+    ///
+    /// # Panics
+    /// * With `Error::ApprovalNotConfigured` if the program has no approver
+    /// * If amount is zero or negative, or below the program minimum
+    /// * With `Error::ProgramClosed` / `Error::ProgramPaused` as for payouts
+    ///
+    /// # Authorization
+    /// - Only the authorized payout key can call this function
+    ///
+    /// # Events
+    /// Emits: `PayoutProposed`
+    pub fn propose_payout(env: Env, program_id: String, recipient: Address, amount: i128) -> u64 {
+        let program_data = Self::get_program_info(env.clone(), program_id.clone());
+        Self::ensure_payouts_enabled(&env, &program_data);
+        program_data.authorized_payout_key.require_auth();
+
+        if Self::get_payout_approval_config(env.clone(), program_id.clone()).is_none() {
+            panic_with_error!(&env, Error::ApprovalNotConfigured);
+        }
+        if amount <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+        Self::ensure_min_payout(&env, &program_id, amount);
+
+        let id_key = DataKey::NextPendingPayoutId(program_id.clone());
+        let payout_id: u64 = env.storage().persistent().get(&id_key).unwrap_or(1);
+        env.storage().persistent().set(&id_key, &(payout_id + 1));
+
+        env.storage().persistent().set(
+            &DataKey::PendingPayout(program_id.clone(), payout_id),
+            &PendingPayout {
+                payout_id,
+                recipient: recipient.clone(),
+                amount,
+                proposed_at: env.ledger().timestamp(),
+                executed: false,
+            },
+        );
+
+        events::emit_payout_proposed(
+            &env,
+            events::PayoutProposed {
+                program_id,
+                payout_id,
+                recipient,
+                amount,
+            },
+        );
+
+        payout_id
+    }
+
+    /// Approves and executes a pending payout (program approver only).
+    ///
+    /// # Panics
+    /// * With `Error::PendingPayoutNotFound` if `payout_id` is unknown
+    /// * With `Error::PayoutAlreadyExecuted` if it was already approved
+    /// * With `Error::InsufficientFunds` if the balance no longer covers it
+    /// * With `Error::ProgramClosed` / `Error::ProgramPaused` as for payouts
+    ///
+    /// # Events
+    /// Emits: `PayoutApproved`
+    pub fn approve_payout(env: Env, program_id: String, payout_id: u64) -> ProgramData {
+        let program_key = DataKey::Program(program_id.clone());
+        let program_data = Self::get_program_info(env.clone(), program_id.clone());
+        Self::ensure_payouts_enabled(&env, &program_data);
+
+        let config = Self::get_payout_approval_config(env.clone(), program_id.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, Error::ApprovalNotConfigured));
+        config.approver.require_auth();
+
+        let pending_key = DataKey::PendingPayout(program_id.clone(), payout_id);
+        let mut pending: PendingPayout = env
+            .storage()
+            .persistent()
+            .get(&pending_key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::PendingPayoutNotFound));
+        if pending.executed {
+            panic_with_error!(&env, Error::PayoutAlreadyExecuted);
+        }
+        if pending.amount > program_data.remaining_balance {
+            panic_with_error!(&env, Error::InsufficientFunds);
+        }
+
+        pending.executed = true;
+        env.storage().persistent().set(&pending_key, &pending);
+
+        let updated_data = Self::execute_batch_payout(
+            &env,
+            program_id.clone(),
+            program_key,
+            program_data,
+            &vec![&env, pending.recipient.clone()],
+            &vec![&env, pending.amount],
+            pending.amount,
+        );
+
+        events::emit_payout_approved(
+            &env,
+            events::PayoutApproved {
+                program_id,
+                payout_id,
+                approver: config.approver,
+                recipient: pending.recipient,
+                amount: pending.amount,
+            },
+        );
+
+        updated_data
+    }
+
+    /// Returns a pending payout by ID.
+    pub fn get_pending_payout(env: Env, program_id: String, payout_id: u64) -> PendingPayout {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PendingPayout(program_id, payout_id))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::PendingPayoutNotFound))
+    }
+
+    // ========================================================================
+    // Release Schedule Functions
+    // ========================================================================
+
+    /// Creates a time-based release schedule for a program.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program to create schedule for
+    /// * `amount` - Amount to release (in token's smallest denomination)
+    /// * `release_timestamp` - Unix timestamp when funds become available
+    /// * `recipient` - Address that will receive the funds
+    ///
+    /// # Returns
+    /// * `ProgramData` - Updated program data
+    ///
+    /// # Panics
+    /// * If program is not initialized
+    /// * If caller is not authorized payout key
+    /// * If amount is invalid
+    /// * If timestamp is in the past
+    /// * If amount exceeds remaining balance
+    ///
+    /// # State Changes
+    /// - Creates ProgramReleaseSchedule record
+    /// - Updates next schedule ID
+    /// - Emits ScheduleCreated event
+    ///
+    /// # Authorization
+    /// - Only authorized payout key can call this function
+    ///
+    /// # Example
+    /// ```rust
+    /// let now = env.ledger().timestamp();
+    /// let release_time = now + (30 * 24 * 60 * 60); // 30 days from now
+    /// escrow_client.create_program_release_schedule(
+    ///     &"Hackathon2024",
+    ///     &500_0000000, // 500 tokens
+    ///     &release_time,
+    ///     &winner_address
+    /// );
+    /// ```
+    pub fn create_program_release_schedule(
+        env: Env,
+        program_id: String,
+        amount: i128,
+        release_timestamp: u64,
+        recipient: Address,
+    ) -> ProgramData {
+        let start = env.ledger().timestamp();
+
+        // Check if contract is paused
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
+
+        // Get program data
+        let program_key = DataKey::Program(program_id.clone());
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&program_key)
+            .unwrap_or_else(|| panic!("Program not found"));
+
+        Self::ensure_program_open(&env, &program_data);
+
+        // Apply rate limiting to the authorized payout key
+        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
+
+        // Verify authorization
+        program_data.authorized_payout_key.require_auth();
+
+        // Validate amount
+        if amount <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+
+        // Validate timestamp
+        if release_timestamp <= env.ledger().timestamp() {
+            panic!("Release timestamp must be in the future");
+        }
+
+        // Check sufficient remaining balance
+        let scheduled_total = get_program_total_scheduled_amount(&env, &program_id);
+        if scheduled_total + amount > program_data.remaining_balance {
+            panic!("Insufficient balance for scheduled amount");
+        }
+
+        // Get next schedule ID
+        let schedule_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::NextScheduleId(program_id.clone()))
+            .unwrap_or(1);
+
+        // Create release schedule
+        let schedule = ProgramReleaseSchedule {
+            schedule_id,
+            amount,
+            release_timestamp,
+            recipient: recipient.clone(),
+            released: false,
+            released_at: None,
+            released_by: None,
+        };
+
+        // Store schedule
+        env.storage().persistent().set(
+            &DataKey::ReleaseSchedule(program_id.clone(), schedule_id),
+            &schedule,
+        );
+
+        // Update next schedule ID
+        env.storage().persistent().set(
+            &DataKey::NextScheduleId(program_id.clone()),
+            &(schedule_id + 1),
+        );
+
+        // Emit program schedule created event
+        env.events().publish(
             (PROG_SCHEDULE_CREATED,),
             ProgramScheduleCreated {
                 program_id: program_id.clone(),
@@ -1683,6 +3228,8 @@ impl ProgramEscrowContract {
     /// * If schedule doesn't exist
     /// * If schedule is already released
     /// * If schedule is not yet due
+    /// * With `Error::InsufficientFunds` if the remaining balance no longer
+    ///   covers the schedule
     ///
     /// # State Changes
     /// - Transfers tokens to recipient
@@ -1713,6 +3260,8 @@ impl ProgramEscrowContract {
             .get(&program_key)
             .unwrap_or_else(|| panic!("Program not found"));
 
+        Self::ensure_payouts_enabled(&env, &program_data);
+
         // Get schedule
         if !env
             .storage()
@@ -1739,6 +3288,14 @@ impl ProgramEscrowContract {
             panic!("Schedule not yet due for release");
         }
 
+        // Schedules are not reserved, so other payouts may have spent the
+        // balance since this one was created
+        let remaining_balance = program_data
+            .remaining_balance
+            .checked_sub(schedule.amount)
+            .filter(|balance| *balance >= 0)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::InsufficientFunds));
+
         // Get token client
         let contract_address = env.current_contract_address();
         let token_client = token::Client::new(&env, &program_data.token_address);
@@ -1753,7 +3310,8 @@ impl ProgramEscrowContract {
 
         // Update program data
         let mut updated_data = program_data.clone();
-        updated_data.remaining_balance -= schedule.amount;
+        updated_data.remaining_balance = remaining_balance;
+        Self::add_total_paid_out(&env, schedule.amount);
 
         // Add to release history
         let history_entry = ProgramReleaseHistory {
@@ -1818,6 +3376,8 @@ impl ProgramEscrowContract {
     /// * If caller is not authorized payout key
     /// * If schedule doesn't exist
     /// * If schedule is already released
+    /// * With `Error::InsufficientFunds` if the remaining balance no longer
+    ///   covers the schedule
     ///
     /// # State Changes
     /// - Transfers tokens to recipient
@@ -1845,6 +3405,8 @@ impl ProgramEscrowContract {
             .get(&program_key)
             .unwrap_or_else(|| panic!("Program not found"));
 
+        Self::ensure_payouts_enabled(&env, &program_data);
+
         // Apply rate limiting to the authorized payout key
         anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
 
@@ -1871,6 +3433,14 @@ impl ProgramEscrowContract {
             panic!("Schedule already released");
         }
 
+        // Schedules are not reserved, so other payouts may have spent the
+        // balance since this one was created
+        let remaining_balance = program_data
+            .remaining_balance
+            .checked_sub(schedule.amount)
+            .filter(|balance| *balance >= 0)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::InsufficientFunds));
+
         // Get token client
         let contract_address = env.current_contract_address();
         let token_client = token::Client::new(&env, &program_data.token_address);
@@ -1886,7 +3456,8 @@ impl ProgramEscrowContract {
 
         // Update program data
         let mut updated_data = program_data.clone();
-        updated_data.remaining_balance -= schedule.amount;
+        updated_data.remaining_balance = remaining_balance;
+        Self::add_total_paid_out(&env, schedule.amount);
 
         // Add to release history
         let history_entry = ProgramReleaseHistory {
@@ -1944,62 +3515,459 @@ impl ProgramEscrowContract {
     }
 
     // ========================================================================
-    // View Functions (Read-only)
+    // Scheduled (Vesting) Payouts
     // ========================================================================
 
-    /// Retrieves complete program information.
+    /// Reserves `amount` for `recipient`, payable once `unlock_at` is reached.
     ///
-    /// # Arguments
-    /// * `env` - The contract environment
+    /// Unlike release schedules, the amount is deducted from
+    /// `remaining_balance` immediately, so other payouts cannot spend it.
+    /// Closing the program cancels the payout if it has not executed yet.
     ///
     /// # Returns
-    /// * `ProgramData` - Complete program state including:
-    ///   - Program ID
-    ///   - Total funds locked
-    ///   - Remaining balance
-    ///   - Authorized payout key
-    ///   - Complete payout history
-    ///   - Token contract address
+    /// * `u64` - The scheduled payout ID, unique within the program
     ///
     /// # Panics
-    /// * If program is not initialized
-    ///
-    /// # Use Cases
-    /// - Verifying program configuration
-    /// - Checking balances before payouts
-    /// - Auditing payout history
-    /// - Displaying program status in UI
-    ///
-    /// # Example
-    /// ```rust
-    /// let info = escrow_client.get_program_info();
-    /// println!("Program: {}", info.program_id);
-    /// println!("Total Locked: {}", info.total_funds);
-    /// println!("Remaining: {}", info.remaining_balance);
-    /// println!("Payouts Made: {}", info.payout_history.len());
-    /// ```
+    /// * If amount is zero or negative
+    /// * With `Error::InvalidUnlockTime` if `unlock_at` is not in the future
+    /// * With `Error::PayoutTooSmall` / `Error::ApprovalRequired` as for
+    ///   `single_payout`
+    /// * With `Error::InsufficientFunds` if amount exceeds remaining balance
+    /// * With `Error::ProgramClosed` / `Error::ProgramPaused` as for payouts
     ///
-    /// # Gas Cost
-    /// Very Low - Single storage read
-    pub fn get_program_info(env: Env, program_id: String) -> ProgramData {
-        let program_key = DataKey::Program(program_id);
-        env.storage()
+    /// # Authorization
+    /// - Only the authorized payout key can call this function
+    pub fn schedule_payout(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        amount: i128,
+        unlock_at: u64,
+    ) -> u64 {
+        let start = env.ledger().timestamp();
+        if Self::is_paused_internal(&env) {
+            panic!("Contract is paused");
+        }
+
+        let program_key = DataKey::Program(program_id.clone());
+        let mut program_data: ProgramData = env
+            .storage()
             .instance()
             .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"))
-    }
+            .unwrap_or_else(|| panic!("Program not found"));
 
-    /// Retrieves the remaining balance for a specific program.
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID to query
-    ///
-    /// # Returns
-    /// * `i128` - Remaining balance
-    ///
-    /// # Panics
-    /// * If program doesn't exist
-    pub fn get_remaining_balance(env: Env, program_id: String) -> i128 {
+        Self::ensure_payouts_enabled(&env, &program_data);
+        program_data.authorized_payout_key.require_auth();
+        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
+
+        if amount <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+        if unlock_at <= env.ledger().timestamp() {
+            panic_with_error!(&env, Error::InvalidUnlockTime);
+        }
+        Self::ensure_min_payout(&env, &program_id, amount);
+        Self::ensure_no_approval_required(&env, &program_id, amount);
+        if amount > program_data.remaining_balance {
+            panic_with_error!(&env, Error::InsufficientFunds);
+        }
+
+        Self::record_recipient_payout(&env, &program_id, &recipient, amount);
+
+        program_data.remaining_balance -= amount;
+        program_data.reserved_balance += amount;
+        env.storage().instance().set(&program_key, &program_data);
+
+        let id_key = DataKey::NextScheduledPayoutId(program_id.clone());
+        let schedule_id: u64 = env.storage().persistent().get(&id_key).unwrap_or(1);
+        env.storage().persistent().set(&id_key, &(schedule_id + 1));
+
+        env.storage().persistent().set(
+            &DataKey::ScheduledPayout(program_id.clone(), schedule_id),
+            &ScheduledPayout {
+                schedule_id,
+                recipient: recipient.clone(),
+                amount,
+                unlock_at,
+                executed: false,
+            },
+        );
+
+        events::emit_payout_scheduled(
+            &env,
+            events::PayoutScheduled {
+                program_id,
+                schedule_id,
+                recipient,
+                amount,
+                unlock_at,
+            },
+        );
+
+        monitoring::track_operation(
+            &env,
+            symbol_short!("sched_pay"),
+            program_data.authorized_payout_key.clone(),
+            true,
+        );
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("sched_pay"), duration);
+
+        schedule_id
+    }
+
+    /// Pays out a scheduled payout once its unlock time has passed.
+    ///
+    /// Permissionless: funds can only go to the recipient fixed at scheduling.
+    ///
+    /// # Panics
+    /// * With `Error::ScheduledPayoutNotFound` if the ID is unknown
+    /// * With `Error::PayoutNotUnlocked` if `now < unlock_at`
+    /// * With `Error::PayoutAlreadyExecuted` on a second execution
+    /// * With `Error::RecipientBlocked` if the recipient was blocked after
+    ///   scheduling
+    /// * With `Error::ProgramClosed` / `Error::ProgramPaused` as for payouts
+    pub fn execute_scheduled_payout(
+        env: Env,
+        program_id: String,
+        schedule_id: u64,
+    ) -> ScheduledPayout {
+        let start = env.ledger().timestamp();
+        let mut program_data = Self::get_program_info(env.clone(), program_id.clone());
+        Self::ensure_payouts_enabled(&env, &program_data);
+
+        let key = DataKey::ScheduledPayout(program_id.clone(), schedule_id);
+        let mut scheduled: ScheduledPayout = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::ScheduledPayoutNotFound));
+
+        if scheduled.executed {
+            panic_with_error!(&env, Error::PayoutAlreadyExecuted);
+        }
+        let now = env.ledger().timestamp();
+        if now < scheduled.unlock_at {
+            panic_with_error!(&env, Error::PayoutNotUnlocked);
+        }
+        Self::ensure_recipient_allowed(&env, &program_id, &scheduled.recipient);
+
+        scheduled.executed = true;
+        env.storage().persistent().set(&key, &scheduled);
+        program_data.reserved_balance -= scheduled.amount;
+        env.storage()
+            .instance()
+            .set(&DataKey::Program(program_id.clone()), &program_data);
+
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        token_client.transfer(
+            &env.current_contract_address(),
+            &scheduled.recipient,
+            &scheduled.amount,
+        );
+
+        Self::append_payout_history(
+            &env,
+            &program_id,
+            vec![
+                &env,
+                PayoutRecord {
+                    recipient: scheduled.recipient.clone(),
+                    amount: scheduled.amount,
+                    timestamp: now,
+                },
+            ],
+        );
+
+        events::emit_scheduled_payout_executed(
+            &env,
+            events::ScheduledPayoutExecuted {
+                program_id,
+                schedule_id,
+                recipient: scheduled.recipient.clone(),
+                amount: scheduled.amount,
+                timestamp: now,
+            },
+        );
+
+        monitoring::track_operation(
+            &env,
+            symbol_short!("exec_pay"),
+            scheduled.recipient.clone(),
+            true,
+        );
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("exec_pay"), duration);
+
+        scheduled
+    }
+
+    /// Returns a scheduled payout by ID.
+    pub fn get_scheduled_payout(env: Env, program_id: String, schedule_id: u64) -> ScheduledPayout {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ScheduledPayout(program_id, schedule_id))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::ScheduledPayoutNotFound))
+    }
+
+    // ========================================================================
+    // Reserved Batch Payouts
+    // ========================================================================
+
+    /// Validates a batch and reserves its total out of `remaining_balance`,
+    /// to be paid in chunks with `execute_reserved`.
+    ///
+    /// Lets distributions too large for one transaction's resource budget
+    /// go out across several calls. Validation matches `batch_payout`, and
+    /// recipient caps are charged at reservation time. Closing the program
+    /// cancels whatever is still unpaid.
+    ///
+    /// # Returns
+    /// * `u64` - The reservation ID, unique within the program
+    ///
+    /// # Panics
+    /// * With `Error::LengthMismatch` / `Error::EmptyBatch` on malformed input
+    /// * If any amount is zero or negative
+    /// * With `Error::InsufficientFunds` if the total exceeds remaining balance
+    /// * With `Error::ProgramClosed` / `Error::ProgramPaused` as for payouts
+    ///
+    /// # Authorization
+    /// - Only the authorized payout key can call this function
+    pub fn reserve_batch(
+        env: Env,
+        program_id: String,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> u64 {
+        if recipients.len() != amounts.len() {
+            panic_with_error!(&env, Error::LengthMismatch);
+        }
+        if recipients.is_empty() {
+            panic_with_error!(&env, Error::EmptyBatch);
+        }
+
+        let program_key = DataKey::Program(program_id.clone());
+        let mut program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&program_key)
+            .unwrap_or_else(|| panic!("Program not found"));
+        Self::ensure_payouts_enabled(&env, &program_data);
+        program_data.authorized_payout_key.require_auth();
+
+        let mut total: i128 = 0;
+        for amount in amounts.iter() {
+            if amount <= 0 {
+                panic!("All amounts must be greater than zero");
+            }
+            Self::ensure_min_payout(&env, &program_id, amount);
+            Self::ensure_no_approval_required(&env, &program_id, amount);
+            total = total
+                .checked_add(amount)
+                .unwrap_or_else(|| panic_with_error!(&env, Error::ArithmeticOverflow));
+        }
+        if total > program_data.remaining_balance {
+            panic_with_error!(&env, Error::InsufficientFunds);
+        }
+        for i in 0..recipients.len() {
+            Self::record_recipient_payout(
+                &env,
+                &program_id,
+                &recipients.get(i).unwrap(),
+                amounts.get(i).unwrap(),
+            );
+        }
+
+        program_data.remaining_balance -= total;
+        program_data.reserved_balance += total;
+        env.storage().instance().set(&program_key, &program_data);
+
+        let id_key = DataKey::NextReservationId(program_id.clone());
+        let reservation_id: u64 = env.storage().persistent().get(&id_key).unwrap_or(1);
+        env.storage()
+            .persistent()
+            .set(&id_key, &(reservation_id + 1));
+
+        let count = recipients.len();
+        env.storage().persistent().set(
+            &DataKey::BatchReservation(program_id.clone(), reservation_id),
+            &BatchReservation {
+                reservation_id,
+                recipients,
+                amounts,
+                total,
+                paid_count: 0,
+            },
+        );
+
+        events::emit_batch_reserved(
+            &env,
+            events::BatchReserved {
+                program_id,
+                reservation_id,
+                recipient_count: count,
+                total_amount: total,
+            },
+        );
+
+        reservation_id
+    }
+
+    /// Pays the next chunk of a reservation: entries `start` up to
+    /// `start + limit`, or the end of the batch.
+    ///
+    /// `start` must equal the reservation's `paid_count`, so a chunk that
+    /// already landed cannot be paid twice by a retried call.
+    ///
+    /// # Panics
+    /// * With `Error::BatchTooLarge` if `limit` exceeds 100
+    /// * With `Error::ReservationNotFound` if the ID is unknown
+    /// * With `Error::ReservationOutOfOrder` if `start != paid_count`
+    /// * With `Error::RecipientBlocked` if a recipient in the chunk was
+    ///   blocked after the reservation; the chunk stays unpaid
+    /// * With `Error::ProgramClosed` / `Error::ProgramPaused` as for payouts
+    ///
+    /// # Authorization
+    /// - Only the authorized payout key can call this function
+    pub fn execute_reserved(
+        env: Env,
+        program_id: String,
+        reservation_id: u64,
+        start: u32,
+        limit: u32,
+    ) -> BatchReservation {
+        if limit > MAX_RESERVED_CHUNK {
+            panic_with_error!(&env, Error::BatchTooLarge);
+        }
+        let mut program_data = Self::get_program_info(env.clone(), program_id.clone());
+        Self::ensure_payouts_enabled(&env, &program_data);
+        program_data.authorized_payout_key.require_auth();
+
+        let key = DataKey::BatchReservation(program_id.clone(), reservation_id);
+        let mut reservation: BatchReservation = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(&env, Error::ReservationNotFound));
+        if start != reservation.paid_count {
+            panic_with_error!(&env, Error::ReservationOutOfOrder);
+        }
+
+        let end = start
+            .saturating_add(limit)
+            .min(reservation.recipients.len());
+        let now = env.ledger().timestamp();
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        let mut records: Vec<PayoutRecord> = vec![&env];
+        let mut paid: i128 = 0;
+        for i in start..end {
+            let recipient = reservation.recipients.get(i).unwrap();
+            let amount = reservation.amounts.get(i).unwrap();
+            Self::ensure_recipient_allowed(&env, &program_id, &recipient);
+            paid += amount;
+            records.push_back(PayoutRecord {
+                recipient,
+                amount,
+                timestamp: now,
+            });
+        }
+        Self::append_payout_history(&env, &program_id, records.clone());
+
+        reservation.paid_count = end;
+        env.storage().persistent().set(&key, &reservation);
+        program_data.reserved_balance -= paid;
+        env.storage()
+            .instance()
+            .set(&DataKey::Program(program_id.clone()), &program_data);
+
+        for record in records.iter() {
+            token_client.transfer(&contract_address, &record.recipient, &record.amount);
+        }
+
+        events::emit_reserved_chunk_paid(
+            &env,
+            events::ReservedChunkPaid {
+                program_id,
+                reservation_id,
+                start,
+                end,
+                amount: paid,
+            },
+        );
+
+        reservation
+    }
+
+    /// Returns a batch reservation by ID.
+    pub fn get_batch_reservation(
+        env: Env,
+        program_id: String,
+        reservation_id: u64,
+    ) -> BatchReservation {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BatchReservation(program_id, reservation_id))
+            .unwrap_or_else(|| panic_with_error!(&env, Error::ReservationNotFound))
+    }
+
+    // ========================================================================
+    // View Functions (Read-only)
+    // ========================================================================
+
+    /// Retrieves complete program information.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// * `ProgramData` - Complete program state including:
+    ///   - Program ID
+    ///   - Total funds locked
+    ///   - Remaining balance
+    ///   - Authorized payout key
+    ///   - Complete payout history
+    ///   - Token contract address
+    ///
+    /// # Panics
+    /// * If program is not initialized
+    ///
+    /// # Use Cases
+    /// - Verifying program configuration
+    /// - Checking balances before payouts
+    /// - Auditing payout history
+    /// - Displaying program status in UI
+    ///
+    /// # Example
+    /// ```rust
+    /// let info = escrow_client.get_program_info();
+    /// println!("Program: {}", info.program_id);
+    /// println!("Total Locked: {}", info.total_funds);
+    /// println!("Remaining: {}", info.remaining_balance);
+    /// println!("Payouts Made: {}", info.payout_count);
+    /// ```
+    ///
+    /// # Gas Cost
+    /// Very Low - Single storage read
+    pub fn get_program_info(env: Env, program_id: String) -> ProgramData {
+        let program_key = DataKey::Program(program_id);
+        env.storage()
+            .instance()
+            .get(&program_key)
+            .unwrap_or_else(|| panic!("Program not found"))
+    }
+
+    /// Retrieves the remaining balance for a specific program.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID to query
+    ///
+    /// # Returns
+    /// * `i128` - Balance available for new payouts, excluding reserved funds
+    ///
+    /// # Panics
+    /// * If program doesn't exist
+    pub fn get_remaining_balance(env: Env, program_id: String) -> i128 {
         let program_key = DataKey::Program(program_id);
         let program_data: ProgramData = env
             .storage()
@@ -2010,6 +3978,45 @@ impl ProgramEscrowContract {
         program_data.remaining_balance
     }
 
+    /// Retrieves funds committed to scheduled payouts and batch reservations
+    /// that have not been paid out yet.
+    ///
+    /// # Panics
+    /// * If program doesn't exist
+    pub fn get_reserved_balance(env: Env, program_id: String) -> i128 {
+        Self::get_program_info(env, program_id).reserved_balance
+    }
+
+    /// Returns a page of a program's payout log, oldest first.
+    ///
+    /// Each record is stored under its own index, so a page only reads the
+    /// records it returns however long the log grows.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program to query
+    /// * `start` - Index of the first record to return
+    /// * `limit` - Maximum records to return (capped at 50)
+    pub fn get_payout_history(
+        env: Env,
+        program_id: String,
+        start: u32,
+        limit: u32,
+    ) -> Vec<PayoutRecord> {
+        let end = start
+            .saturating_add(limit.min(MAX_HISTORY_PAGE))
+            .min(Self::payout_history_len(&env, &program_id));
+        let mut page: Vec<PayoutRecord> = vec![&env];
+        for index in start..end {
+            let record: PayoutRecord = env
+                .storage()
+                .persistent()
+                .get(&DataKey::PayoutHistoryEntry(program_id.clone(), index))
+                .unwrap();
+            page.push_back(record);
+        }
+        page
+    }
+
     /// Update fee configuration (admin only - uses authorized_payout_key)
     ///
     /// # Arguments
@@ -2098,15 +4105,51 @@ impl ProgramEscrowContract {
         registry.len()
     }
 
-    // ========================================================================
-    // Monitoring & Analytics Functions
-    // ========================================================================
-
-    /// Health check - returns contract health status
-    pub fn health_check(env: Env) -> monitoring::HealthStatus {
-        monitoring::health_check(&env)
-    }
-
+    /// Sums `remaining_balance` over every registered program.
+    ///
+    /// Balances are added as raw amounts regardless of each program's
+    /// token, so the figure is only meaningful when programs share a token.
+    ///
+    /// # Returns
+    /// * `i128` - Funds still available for payouts across all programs
+    pub fn get_total_program_funds(env: Env) -> i128 {
+        let registry: Vec<String> = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_REGISTRY)
+            .unwrap_or(vec![&env]);
+
+        let mut total: i128 = 0;
+        for program_id in registry.iter() {
+            let program: Option<ProgramData> =
+                env.storage().instance().get(&DataKey::Program(program_id));
+            if let Some(program) = program {
+                total += program.remaining_balance;
+            }
+        }
+        total
+    }
+
+    /// Gets the lifetime total paid out across all programs.
+    ///
+    /// Kept as a running total updated on every payout, net of payout fees.
+    /// Clawbacks do not reduce it.
+    pub fn get_total_paid_out(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalPaidOut)
+            .unwrap_or(0)
+    }
+
+    // ========================================================================
+    // Monitoring & Analytics Functions
+    // ========================================================================
+
+    /// Health check - returns contract health status
+    pub fn health_check(env: Env) -> monitoring::HealthStatus {
+        monitoring::health_check(&env)
+    }
+
     /// Get analytics - returns usage analytics
     pub fn get_analytics(env: Env) -> monitoring::Analytics {
         monitoring::get_analytics(&env)
@@ -2175,6 +4218,35 @@ impl ProgramEscrowContract {
         anti_abuse::get_config(&env)
     }
 
+    /// Sets the most recipients `batch_payout` and `distribute_proportional`
+    /// accept in one call. Only the admin can call this.
+    ///
+    /// Oversized batches are rejected with `Error::BatchTooLarge` before any
+    /// transfer, rather than running out of resources part-way through.
+    ///
+    /// # Panics
+    /// * If no admin is set or `max_batch_size` is zero
+    pub fn set_max_batch_size(env: Env, max_batch_size: u32) {
+        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
+        admin.require_auth();
+
+        if max_batch_size == 0 {
+            panic!("Max batch size must be greater than zero");
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxBatchSize, &max_batch_size);
+    }
+
+    /// Gets the most recipients accepted by one batch payout.
+    pub fn get_max_batch_size(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxBatchSize)
+            .unwrap_or(DEFAULT_MAX_BATCH_SIZE)
+    }
+
     // ========================================================================
     // Schedule View Functions
     // ========================================================================
@@ -2330,22 +4402,23 @@ fn get_program_total_scheduled_amount(env: &Env, program_id: &String) -> i128 {
     total
 }
 
-/// ============================================================================
+// ============================================================================
 // Tests
 // ============================================================================
 
 #[cfg(test)]
+#[allow(clippy::inconsistent_digit_grouping)]
 mod test {
     use super::*;
     use soroban_sdk::{
-        testutils::{Address as _, Ledger},
-        token, Address, Env, String,
+        testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
+        token, Address, Env, IntoVal, String, TryFromVal, Val,
     };
 
     // Test helper to create a mock token contract
     fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::Client<'a> {
-        let token_address = env.register_stellar_asset_contract(admin.clone());
-        token::Client::new(env, &token_address)
+        let token_address = env.register_stellar_asset_contract_v2(admin.clone());
+        token::Client::new(env, &token_address.address())
     }
 
     // ========================================================================
@@ -2356,27 +4429,18 @@ mod test {
         env: &Env,
         client: &ProgramEscrowContractClient<'static>,
         authorized_key: &Address,
-        token: &Address,
         program_id: &String,
         total_amount: i128,
         winner: &Address,
         release_timestamp: u64,
     ) {
-        // Register program
-        client.initialize_program(program_id, authorized_key, token);
-
-        // Create and fund token
+        // Create token and register program against it
         let token_client = create_token_contract(env, authorized_key);
-        let token_admin = token::StellarAssetClient::new(env, &token_client.address);
-        token_admin.mint(authorized_key, &total_amount);
+        client.initialize_program(program_id, authorized_key, &token_client.address);
 
-        // Lock funds for program
-        token_client.approve(
-            authorized_key,
-            &env.current_contract_address(),
-            &total_amount,
-            &1000,
-        );
+        // Fund the contract and record the locked amount
+        let token_admin = token::StellarAssetClient::new(env, &token_client.address);
+        token_admin.mint(&client.address, &total_amount);
         client.lock_program_funds(program_id, &total_amount);
 
         // Create release schedule
@@ -2384,7 +4448,7 @@ mod test {
             program_id,
             &total_amount,
             &release_timestamp,
-            &winner,
+            winner,
         );
     }
 
@@ -2396,7 +4460,6 @@ mod test {
 
         let authorized_key = Address::generate(&env);
         let winner = Address::generate(&env);
-        let token = Address::generate(&env);
         let program_id = String::from_str(&env, "Hackathon2024");
         let amount = 1000_0000000;
         let release_timestamp = 1000;
@@ -2408,7 +4471,6 @@ mod test {
             &env,
             &client,
             &authorized_key,
-            &token,
             &program_id,
             amount,
             &winner,
@@ -2430,6 +4492,47 @@ mod test {
         // Event verification can be added later - focusing on core functionality
     }
 
+    #[test]
+    fn test_release_schedule_rejected_once_balance_is_spent() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let authorized_key = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let other = Address::generate(&env);
+        let program_id = String::from_str(&env, "Hackathon2024");
+        setup_program_with_schedule(
+            &env,
+            &client,
+            &authorized_key,
+            &program_id,
+            1_000,
+            &winner,
+            100,
+        );
+        // Direct payouts are not held back for the schedule
+        client.single_payout(&program_id, &other, &600);
+
+        env.ledger().set_timestamp(100);
+        let expected = soroban_sdk::Error::from_contract_error(Error::InsufficientFunds as u32);
+        assert_eq!(
+            client.try_release_prog_schedule_automatic(&program_id, &1),
+            Err(Ok(expected))
+        );
+        assert_eq!(
+            client.try_release_program_schedule_manual(&program_id, &1),
+            Err(Ok(expected))
+        );
+        assert!(
+            !client
+                .get_program_release_schedule(&program_id, &1)
+                .released
+        );
+        assert_eq!(client.get_remaining_balance(&program_id), 400);
+    }
+
     #[test]
     fn test_multiple_program_release_schedules() {
         let env = Env::default();
@@ -2439,7 +4542,6 @@ mod test {
         let authorized_key = Address::generate(&env);
         let winner1 = Address::generate(&env);
         let winner2 = Address::generate(&env);
-        let token = Address::generate(&env);
         let program_id = String::from_str(&env, "Hackathon2024");
         let amount1 = 600_0000000;
         let amount2 = 400_0000000;
@@ -2447,21 +4549,13 @@ mod test {
 
         env.mock_all_auths();
 
-        // Register program
-        client.initialize_program(&program_id, &authorized_key, &token);
-
-        // Create and fund token
+        // Create token and register program against it
         let token_client = create_token_contract(&env, &authorized_key);
-        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
-        token_admin.mint(&authorized_key, &total_amount);
+        client.initialize_program(&program_id, &authorized_key, &token_client.address);
 
-        // Lock funds for program
-        token_client.approve(
-            &authorized_key,
-            &env.current_contract_address(),
-            &total_amount,
-            &1000,
-        );
+        // Fund the contract and record the locked amount
+        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
+        token_admin.mint(&client.address, &total_amount);
         client.lock_program_funds(&program_id, &total_amount);
 
         // Create first release schedule
@@ -2503,7 +4597,6 @@ mod test {
 
         let authorized_key = Address::generate(&env);
         let winner = Address::generate(&env);
-        let token = Address::generate(&env);
         let program_id = String::from_str(&env, "Hackathon2024");
         let amount = 1000_0000000;
         let release_timestamp = 1000;
@@ -2515,7 +4608,6 @@ mod test {
             &env,
             &client,
             &authorized_key,
-            &token,
             &program_id,
             amount,
             &winner,
@@ -2537,7 +4629,7 @@ mod test {
         let schedule = client.get_program_release_schedule(&program_id, &1);
         assert!(schedule.released);
         assert_eq!(schedule.released_at, Some(1001));
-        assert_eq!(schedule.released_by, Some(env.current_contract_address()));
+        assert_eq!(schedule.released_by, Some(contract_id.clone()));
 
         // Check no pending schedules
         let pending = client.get_pending_program_schedules(&program_id);
@@ -2559,7 +4651,6 @@ mod test {
 
         let authorized_key = Address::generate(&env);
         let winner = Address::generate(&env);
-        let token = Address::generate(&env);
         let program_id = String::from_str(&env, "Hackathon2024");
         let amount = 1000_0000000;
         let release_timestamp = 1000;
@@ -2571,7 +4662,6 @@ mod test {
             &env,
             &client,
             &authorized_key,
-            &token,
             &program_id,
             amount,
             &winner,
@@ -2605,7 +4695,6 @@ mod test {
         let authorized_key = Address::generate(&env);
         let winner1 = Address::generate(&env);
         let winner2 = Address::generate(&env);
-        let token = Address::generate(&env);
         let program_id = String::from_str(&env, "Hackathon2024");
         let amount1 = 600_0000000;
         let amount2 = 400_0000000;
@@ -2613,21 +4702,13 @@ mod test {
 
         env.mock_all_auths();
 
-        // Register program
-        client.initialize_program(&program_id, &authorized_key, &token);
-
-        // Create and fund token
+        // Create token and register program against it
         let token_client = create_token_contract(&env, &authorized_key);
-        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
-        token_admin.mint(&authorized_key, &total_amount);
+        client.initialize_program(&program_id, &authorized_key, &token_client.address);
 
-        // Lock funds for program
-        token_client.approve(
-            &authorized_key,
-            &env.current_contract_address(),
-            &total_amount,
-            &1000,
-        );
+        // Fund the contract and record the locked amount
+        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
+        token_admin.mint(&client.address, &total_amount);
         client.lock_program_funds(&program_id, &total_amount);
 
         // Create first schedule
@@ -2682,7 +4763,6 @@ mod test {
         let winner1 = Address::generate(&env);
         let winner2 = Address::generate(&env);
         let winner3 = Address::generate(&env);
-        let token = Address::generate(&env);
         let program_id = String::from_str(&env, "Hackathon2024");
         let amount1 = 300_0000000;
         let amount2 = 300_0000000;
@@ -2692,21 +4772,13 @@ mod test {
 
         env.mock_all_auths();
 
-        // Register program
-        client.initialize_program(&program_id, &authorized_key, &token);
-
-        // Create and fund token
+        // Create token and register program against it
         let token_client = create_token_contract(&env, &authorized_key);
-        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
-        token_admin.mint(&authorized_key, &total_amount);
+        client.initialize_program(&program_id, &authorized_key, &token_client.address);
 
-        // Lock funds for program
-        token_client.approve(
-            &authorized_key,
-            &env.current_contract_address(),
-            &total_amount,
-            &1000,
-        );
+        // Fund the contract and record the locked amount
+        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
+        token_admin.mint(&client.address, &total_amount);
         client.lock_program_funds(&program_id, &total_amount);
 
         // Create overlapping schedules (all at same timestamp)
@@ -2786,266 +4858,2289 @@ mod test {
     }
 
     #[test]
-    fn test_multiple_programs_isolation() {
+    fn test_multiple_programs_isolation() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let backend1 = Address::generate(&env);
+        let backend2 = Address::generate(&env);
+        let backend3 = Address::generate(&env);
+        let token = Address::generate(&env);
+
+        // Register three programs
+        let prog1 = String::from_str(&env, "ETHGlobal2024");
+        let prog2 = String::from_str(&env, "Stellar2024");
+        let prog3 = String::from_str(&env, "BuildathonQ1");
+
+        client.initialize_program(&prog1, &backend1, &token);
+        client.initialize_program(&prog2, &backend2, &token);
+        client.initialize_program(&prog3, &backend3, &token);
+
+        // Verify all exist
+        assert!(client.program_exists(&prog1));
+        assert!(client.program_exists(&prog2));
+        assert!(client.program_exists(&prog3));
+        assert_eq!(client.get_program_count(), 3);
+
+        // Verify complete isolation
+        let info1 = client.get_program_info(&prog1);
+        let info2 = client.get_program_info(&prog2);
+        let info3 = client.get_program_info(&prog3);
+
+        assert_eq!(info1.program_id, prog1);
+        assert_eq!(info2.program_id, prog2);
+        assert_eq!(info3.program_id, prog3);
+
+        assert_eq!(info1.authorized_payout_key, backend1);
+        assert_eq!(info2.authorized_payout_key, backend2);
+        assert_eq!(info3.authorized_payout_key, backend3);
+
+        // Verify list programs
+        let programs = client.list_programs(&0, &10);
+        assert_eq!(programs.len(), 3);
+    }
+
+    #[test]
+    fn test_list_programs_paginates() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let backend = Address::generate(&env);
+        let token = Address::generate(&env);
+        let ids = [
+            String::from_str(&env, "P1"),
+            String::from_str(&env, "P2"),
+            String::from_str(&env, "P3"),
+            String::from_str(&env, "P4"),
+            String::from_str(&env, "P5"),
+        ];
+        for id in ids.iter() {
+            client.initialize_program(id, &backend, &token);
+        }
+
+        let first = client.list_programs(&0, &2);
+        assert_eq!(first, vec![&env, ids[0].clone(), ids[1].clone()]);
+        let second = client.list_programs(&2, &2);
+        assert_eq!(second, vec![&env, ids[2].clone(), ids[3].clone()]);
+        let last = client.list_programs(&4, &2);
+        assert_eq!(last, vec![&env, ids[4].clone()]);
+        assert_eq!(client.list_programs(&5, &2).len(), 0);
+    }
+
+    #[test]
+    fn test_get_programs_aligns_with_input() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let backend = Address::generate(&env);
+        let token = Address::generate(&env);
+        let prog_a = String::from_str(&env, "A");
+        let prog_b = String::from_str(&env, "B");
+        let missing = String::from_str(&env, "Missing");
+        client.initialize_program(&prog_a, &backend, &token);
+        client.initialize_program(&prog_b, &backend, &token);
+
+        let programs = client.get_programs(&vec![&env, prog_b.clone(), missing, prog_a.clone()]);
+        assert_eq!(programs.len(), 3);
+        assert_eq!(programs.get(0).unwrap().unwrap().program_id, prog_b);
+        assert!(programs.get(1).unwrap().is_none());
+        assert_eq!(programs.get(2).unwrap().unwrap().program_id, prog_a);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #14)")] // ProgramExists
+    fn test_duplicate_program_registration() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let backend = Address::generate(&env);
+        let token = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        // Register once - should succeed
+        client.initialize_program(&prog_id, &backend, &token);
+
+        // Register again - should panic
+        client.initialize_program(&prog_id, &backend, &token);
+    }
+
+    #[test]
+    fn test_reinitialization_keeps_existing_program() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        setup_funded_program(&env, &client, &backend, &prog_id, 5_000);
+
+        let other_key = Address::generate(&env);
+        let other_token = Address::generate(&env);
+        let expected = soroban_sdk::Error::from_contract_error(Error::ProgramExists as u32);
+        assert_eq!(
+            client.try_initialize_program(&prog_id, &other_key, &other_token),
+            Err(Ok(expected))
+        );
+
+        let info = client.get_program_info(&prog_id);
+        assert_eq!(info.authorized_payout_key, backend);
+        assert_eq!(info.total_funds, 5_000);
+        assert_eq!(info.remaining_balance, 5_000);
+        assert_eq!(client.get_program_count(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Program ID cannot be empty")]
+    fn test_empty_program_id() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let backend = Address::generate(&env);
+        let token = Address::generate(&env);
+        let empty_id = String::from_str(&env, "");
+
+        client.initialize_program(&empty_id, &backend, &token);
+    }
+
+    #[test]
+    #[should_panic(expected = "Program not found")]
+    fn test_get_nonexistent_program() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let prog_id = String::from_str(&env, "DoesNotExist");
+        client.get_program_info(&prog_id);
+    }
+
+    // ========================================================================
+    // Fund Locking Tests
+    // ========================================================================
+
+    #[test]
+    fn test_lock_funds_single_program() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        // Register program
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+
+        // Lock funds
+        let amount = 10_000_0000000i128; // 10,000 USDC
+        let updated = client.lock_program_funds(&prog_id, &amount);
+
+        assert_eq!(updated.total_funds, amount);
+        assert_eq!(updated.remaining_balance, amount);
+    }
+
+    #[test]
+    fn test_lock_funds_multiple_programs_isolation() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend1 = Address::generate(&env);
+        let backend2 = Address::generate(&env);
+
+        let prog1 = String::from_str(&env, "Program1");
+        let prog2 = String::from_str(&env, "Program2");
+
+        // Register programs
+        client.initialize_program(&prog1, &backend1, &token_client.address);
+        client.initialize_program(&prog2, &backend2, &token_client.address);
+
+        // Lock different amounts in each program
+        let amount1 = 5_000_0000000i128;
+        let amount2 = 10_000_0000000i128;
+
+        client.lock_program_funds(&prog1, &amount1);
+        client.lock_program_funds(&prog2, &amount2);
+
+        // Verify isolation - funds don't mix
+        let info1 = client.get_program_info(&prog1);
+        let info2 = client.get_program_info(&prog2);
+
+        assert_eq!(info1.total_funds, amount1);
+        assert_eq!(info1.remaining_balance, amount1);
+        assert_eq!(info2.total_funds, amount2);
+        assert_eq!(info2.remaining_balance, amount2);
+    }
+
+    #[test]
+    fn test_lock_funds_cumulative() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+
+        // Lock funds multiple times
+        client.lock_program_funds(&prog_id, &1_000_0000000);
+        client.lock_program_funds(&prog_id, &2_000_0000000);
+        client.lock_program_funds(&prog_id, &3_000_0000000);
+
+        let info = client.get_program_info(&prog_id);
+        assert_eq!(info.total_funds, 6_000_0000000);
+        assert_eq!(info.remaining_balance, 6_000_0000000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Amount must be greater than zero")]
+    fn test_lock_zero_funds() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let backend = Address::generate(&env);
+        let token = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        client.initialize_program(&prog_id, &backend, &token);
+        client.lock_program_funds(&prog_id, &0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #1)")]
+    fn test_lock_funds_overflow() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let backend = Address::generate(&env);
+        let token = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        client.initialize_program(&prog_id, &backend, &token);
+        client.lock_program_funds(&prog_id, &i128::MAX);
+
+        // A further top-up must not wrap around
+        client.lock_program_funds(&prog_id, &1);
+    }
+
+    // ========================================================================
+    // Batch Payout Tests
+    // ========================================================================
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #2)")]
+    fn test_batch_payout_mismatched_lengths() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Test");
+
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+        client.lock_program_funds(&prog_id, &10_000_0000000);
+
+        let recipients = soroban_sdk::vec![&env, Address::generate(&env), Address::generate(&env)];
+        let amounts = soroban_sdk::vec![&env, 1_000_0000000i128]; // Mismatch!
+
+        client.batch_payout(&prog_id, &recipients, &amounts);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #3)")]
+    fn test_batch_payout_empty_batch() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Test");
+
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+        client.lock_program_funds(&prog_id, &10_000_0000000);
+
+        let recipients: soroban_sdk::Vec<Address> = soroban_sdk::vec![&env];
+        let amounts: soroban_sdk::Vec<i128> = soroban_sdk::vec![&env];
+
+        client.batch_payout(&prog_id, &recipients, &amounts);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #4)")]
+    fn test_batch_payout_insufficient_balance() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Test");
+
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+        client.lock_program_funds(&prog_id, &5_000_0000000);
+
+        let recipients = soroban_sdk::vec![&env, Address::generate(&env)];
+        let amounts = soroban_sdk::vec![&env, 10_000_0000000i128]; // More than available!
+
+        client.batch_payout(&prog_id, &recipients, &amounts);
+    }
+
+    #[test]
+    fn test_batch_payout_over_budget_pays_nobody() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
+
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Test");
+
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+        token_admin.mint(&contract_id, &10_000);
+        client.lock_program_funds(&prog_id, &10_000);
+
+        let winner1 = Address::generate(&env);
+        let winner2 = Address::generate(&env);
+        let winner3 = Address::generate(&env);
+        let recipients = soroban_sdk::vec![&env, winner1.clone(), winner2.clone(), winner3.clone()];
+        // The last payout tips the batch over the 10_000 budget
+        let amounts = soroban_sdk::vec![&env, 4_000i128, 4_000, 2_001];
+
+        let result = client.try_batch_payout(&prog_id, &recipients, &amounts);
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(
+                Error::InsufficientFunds as u32
+            )))
+        );
+
+        assert_eq!(token_client.balance(&winner1), 0);
+        assert_eq!(token_client.balance(&winner2), 0);
+        assert_eq!(token_client.balance(&winner3), 0);
+        assert_eq!(token_client.balance(&contract_id), 10_000);
+        assert_eq!(client.get_remaining_balance(&prog_id), 10_000);
+    }
+
+    #[test]
+    fn test_distribute_proportional_exact_split() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let token_client = create_token_contract(&env, &admin);
+        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
+
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Test");
+
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+        token_admin.mint(&contract_id, &10_001);
+        client.lock_program_funds(&prog_id, &10_001);
+
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+        let third = Address::generate(&env);
+        let recipients = soroban_sdk::vec![&env, first.clone(), second.clone(), third.clone()];
+        let weights = soroban_sdk::vec![&env, 50u32, 30, 20];
+
+        let data = client.distribute_proportional(&prog_id, &recipients, &weights);
+
+        // 5_000.5 and 3_000.3 round down; the remainder lands on the last recipient
+        assert_eq!(token_client.balance(&first), 5_000);
+        assert_eq!(token_client.balance(&second), 3_000);
+        assert_eq!(token_client.balance(&third), 2_001);
+        assert_eq!(token_client.balance(&contract_id), 0);
+        assert_eq!(data.remaining_balance, 0);
+        assert_eq!(client.get_payout_history(&prog_id, &0, &10).len(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #5)")]
+    fn test_distribute_proportional_zero_weight() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        let backend = Address::generate(&env);
+        let token = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Test");
+
+        client.initialize_program(&prog_id, &backend, &token);
+        client.lock_program_funds(&prog_id, &1_000);
+
+        let recipients = soroban_sdk::vec![&env, Address::generate(&env)];
+        let weights = soroban_sdk::vec![&env, 0u32];
+        client.distribute_proportional(&prog_id, &recipients, &weights);
+    }
+
+    #[test]
+    fn test_split_equal_gives_remainder_to_first() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Test");
+        let token_client = setup_funded_program(&env, &client, &backend, &prog_id, 1_000);
+
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+        let third = Address::generate(&env);
+        let recipients = soroban_sdk::vec![&env, first.clone(), second.clone(), third.clone()];
+
+        let data = client.split_equal(&prog_id, &recipients, &100);
+
+        assert_eq!(token_client.balance(&first), 34);
+        assert_eq!(token_client.balance(&second), 33);
+        assert_eq!(token_client.balance(&third), 33);
+        assert_eq!(data.remaining_balance, 900);
+        assert_eq!(client.get_payout_history(&prog_id, &0, &10).len(), 3);
+    }
+
+    #[test]
+    fn test_split_equal_rejects_overdraw_and_empty() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Test");
+        setup_funded_program(&env, &client, &backend, &prog_id, 50);
+
+        let recipients = soroban_sdk::vec![&env, Address::generate(&env)];
+        assert_eq!(
+            client.try_split_equal(&prog_id, &recipients, &100),
+            Err(Ok(soroban_sdk::Error::from_contract_error(
+                Error::InsufficientFunds as u32
+            )))
+        );
+        assert_eq!(
+            client.try_split_equal(&prog_id, &soroban_sdk::vec![&env], &10),
+            Err(Ok(soroban_sdk::Error::from_contract_error(
+                Error::EmptyBatch as u32
+            )))
+        );
+        assert_eq!(client.get_remaining_balance(&prog_id), 50);
+    }
+
+    #[test]
+    fn test_program_count() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+        assert_eq!(client.get_program_count(), 0);
+
+        let backend = Address::generate(&env);
+        let token = Address::generate(&env);
+
+        client.initialize_program(&String::from_str(&env, "P1"), &backend, &token);
+        assert_eq!(client.get_program_count(), 1);
+
+        client.initialize_program(&String::from_str(&env, "P2"), &backend, &token);
+        assert_eq!(client.get_program_count(), 2);
+
+        client.initialize_program(&String::from_str(&env, "P3"), &backend, &token);
+        assert_eq!(client.get_program_count(), 3);
+    }
+
+    #[test]
+    fn test_recipient_cap_blocks_repeated_payouts() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let token_client = create_token_contract(&env, &admin);
+        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
+
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Capped");
+        client.initialize_program_with_cap(&prog_id, &backend, &token_client.address, &1_000);
+        token_admin.mint(&contract_id, &10_000);
+        client.lock_program_funds(&prog_id, &10_000);
+
+        let winner = Address::generate(&env);
+        client.single_payout(&prog_id, &winner, &600);
+        client.batch_payout(
+            &prog_id,
+            &soroban_sdk::vec![&env, winner.clone()],
+            &soroban_sdk::vec![&env, 400i128],
+        );
+        assert_eq!(client.get_recipient_paid(&prog_id, &winner), 1_000);
+
+        // One more unit crosses the cap
+        assert_eq!(
+            client.try_single_payout(&prog_id, &winner, &1),
+            Err(Ok(soroban_sdk::Error::from_contract_error(
+                Error::RecipientCapExceeded as u32
+            )))
+        );
+        assert_eq!(token_client.balance(&winner), 1_000);
+        assert_eq!(client.get_remaining_balance(&prog_id), 9_000);
+    }
+
+    // ========================================================================
+    // Program Closure Tests
+    // ========================================================================
+
+    fn setup_funded_program<'a>(
+        env: &Env,
+        client: &ProgramEscrowContractClient<'a>,
+        backend: &Address,
+        prog_id: &String,
+        amount: i128,
+    ) -> token::Client<'a> {
+        let admin = Address::generate(env);
+        let token_client = create_token_contract(env, &admin);
+        let token_admin = token::StellarAssetClient::new(env, &token_client.address);
+
+        client.initialize_program(prog_id, backend, &token_client.address);
+        token_admin.mint(&client.address, &amount);
+        client.lock_program_funds(prog_id, &amount);
+        token_client
+    }
+
+    #[test]
+    fn test_close_program_sweeps_remaining_balance() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Grant");
+        let token_client = setup_funded_program(&env, &client, &backend, &prog_id, 10_000);
+
+        let winner = Address::generate(&env);
+        client.single_payout(&prog_id, &winner, &4_000);
+
+        let closed = client.close_program(&prog_id, &false);
+
+        assert_eq!(closed.status, ProgramStatus::Closed);
+        assert_eq!(closed.remaining_balance, 0);
+        assert_eq!(token_client.balance(&backend), 6_000);
+        assert_eq!(token_client.balance(&contract_id), 0);
+    }
+
+    #[test]
+    fn test_payouts_rejected_after_close() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Grant");
+        setup_funded_program(&env, &client, &backend, &prog_id, 10_000);
+
+        client.close_program(&prog_id, &false);
+
+        let closed_err = soroban_sdk::Error::from_contract_error(Error::ProgramClosed as u32);
+        let winner = Address::generate(&env);
+        assert_eq!(
+            client.try_single_payout(&prog_id, &winner, &1),
+            Err(Ok(closed_err))
+        );
+        assert_eq!(
+            client.try_batch_payout(
+                &prog_id,
+                &soroban_sdk::vec![&env, winner],
+                &soroban_sdk::vec![&env, 1i128]
+            ),
+            Err(Ok(closed_err))
+        );
+    }
+
+    #[test]
+    fn test_program_pause_blocks_payouts_until_resumed() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Grant");
+        let token_client = setup_funded_program(&env, &client, &backend, &prog_id, 10_000);
+        let winner = Address::generate(&env);
+
+        let paused = client.pause_program(&prog_id);
+        assert!(paused.paused);
+
+        let paused_err = soroban_sdk::Error::from_contract_error(Error::ProgramPaused as u32);
+        assert_eq!(
+            client.try_single_payout(&prog_id, &winner, &1_000),
+            Err(Ok(paused_err))
+        );
+        assert_eq!(
+            client.try_batch_payout(
+                &prog_id,
+                &soroban_sdk::vec![&env, winner.clone()],
+                &soroban_sdk::vec![&env, 1_000i128]
+            ),
+            Err(Ok(paused_err))
+        );
+
+        // Funding and queries are unaffected
+        client.lock_program_funds(&prog_id, &500);
+        assert_eq!(client.get_program_info(&prog_id).remaining_balance, 10_500);
+
+        client.resume_program(&prog_id);
+        client.single_payout(&prog_id, &winner, &1_000);
+        assert_eq!(token_client.balance(&winner), 1_000);
+    }
+
+    // ========================================================================
+    // Strict Batch Tests
+    // ========================================================================
+
+    #[test]
+    fn test_batch_payout_strict_rejects_duplicates() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Grant");
+        let token_client = setup_funded_program(&env, &client, &backend, &prog_id, 10_000);
+
+        let r1 = Address::generate(&env);
+        let r2 = Address::generate(&env);
+        let result = client.try_batch_payout_strict(
+            &prog_id,
+            &soroban_sdk::vec![&env, r1.clone(), r2.clone(), r1.clone()],
+            &soroban_sdk::vec![&env, 100i128, 200, 100],
+        );
+        assert_eq!(
+            result,
+            Err(Ok(soroban_sdk::Error::from_contract_error(
+                Error::DuplicateRecipient as u32
+            )))
+        );
+        assert_eq!(token_client.balance(&r1), 0);
+    }
+
+    #[test]
+    fn test_batch_payout_strict_unique_succeeds() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Grant");
+        let token_client = setup_funded_program(&env, &client, &backend, &prog_id, 10_000);
+
+        let r1 = Address::generate(&env);
+        let r2 = Address::generate(&env);
+        let data = client.batch_payout_strict(
+            &prog_id,
+            &soroban_sdk::vec![&env, r1.clone(), r2.clone()],
+            &soroban_sdk::vec![&env, 100i128, 200],
+        );
+        assert_eq!(token_client.balance(&r1), 100);
+        assert_eq!(token_client.balance(&r2), 200);
+        assert_eq!(data.remaining_balance, 9_700);
+    }
+
+    // ========================================================================
+    // Max Batch Size Tests
+    // ========================================================================
+
+    fn batch_of(env: &Env, size: u32) -> (Vec<Address>, Vec<i128>) {
+        let mut recipients = Vec::new(env);
+        let mut amounts = Vec::new(env);
+        for _ in 0..size {
+            recipients.push_back(Address::generate(env));
+            amounts.push_back(10);
+        }
+        (recipients, amounts)
+    }
+
+    #[test]
+    fn test_batch_payout_at_default_max_batch_size() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.budget().reset_unlimited();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Grant");
+        let token_client = setup_funded_program(&env, &client, &backend, &prog_id, 10_000);
+
+        assert_eq!(client.get_max_batch_size(), 100);
+        let (recipients, amounts) = batch_of(&env, 100);
+        let data = client.batch_payout(&prog_id, &recipients, &amounts);
+        assert_eq!(data.remaining_balance, 9_000);
+        assert_eq!(token_client.balance(&recipients.get(99).unwrap()), 10);
+    }
+
+    #[test]
+    fn test_batch_payout_over_default_max_batch_size() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Grant");
+        let token_client = setup_funded_program(&env, &client, &backend, &prog_id, 10_000);
+
+        let (recipients, amounts) = batch_of(&env, 101);
+        let expected = soroban_sdk::Error::from_contract_error(Error::BatchTooLarge as u32);
+        assert_eq!(
+            client.try_batch_payout(&prog_id, &recipients, &amounts),
+            Err(Ok(expected))
+        );
+        assert_eq!(token_client.balance(&recipients.get(0).unwrap()), 0);
+        assert_eq!(client.get_program_info(&prog_id).remaining_balance, 10_000);
+    }
+
+    #[test]
+    fn test_set_max_batch_size_applies_to_distribute_proportional() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Grant");
+        setup_funded_program(&env, &client, &backend, &prog_id, 10_000);
+
+        client.set_admin(&admin);
+        client.set_max_batch_size(&3);
+        assert_eq!(client.get_max_batch_size(), 3);
+
+        let (recipients, _) = batch_of(&env, 4);
+        let weights = soroban_sdk::vec![&env, 1u32, 1, 1, 1];
+        let expected = soroban_sdk::Error::from_contract_error(Error::BatchTooLarge as u32);
+        assert_eq!(
+            client.try_distribute_proportional(&prog_id, &recipients, &weights),
+            Err(Ok(expected))
+        );
+
+        let (recipients, _) = batch_of(&env, 3);
+        let weights = soroban_sdk::vec![&env, 1u32, 1, 1];
+        let data = client.distribute_proportional(&prog_id, &recipients, &weights);
+        assert_eq!(data.remaining_balance, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Max batch size must be greater than zero")]
+    fn test_set_max_batch_size_rejects_zero() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        client.set_admin(&Address::generate(&env));
+        client.set_max_batch_size(&0);
+    }
+
+    // ========================================================================
+    // Idempotent Payout Tests
+    // ========================================================================
+
+    #[test]
+    fn test_single_payout_idempotent_pays_once() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Grant");
+        let token_client = setup_funded_program(&env, &client, &backend, &prog_id, 10_000);
+
+        let winner = Address::generate(&env);
+        let ref_id = String::from_str(&env, "payout-42");
+
+        client.single_payout_idempotent(&prog_id, &winner, &2_500, &ref_id);
+        // Retry of the same submission
+        let data = client.single_payout_idempotent(&prog_id, &winner, &2_500, &ref_id);
+
+        assert_eq!(token_client.balance(&winner), 2_500);
+        assert_eq!(data.remaining_balance, 7_500);
+        assert_eq!(client.get_payout_history(&prog_id, &0, &10).len(), 1);
+        // The legacy inline log is no longer grown
+        assert_eq!(data.payout_history.len(), 0);
+    }
+
+    // ========================================================================
+    // Payout History Tests
+    // ========================================================================
+
+    #[test]
+    fn test_payout_history_paging() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Grant");
+        setup_funded_program(&env, &client, &backend, &prog_id, 10_000);
+
+        let r1 = Address::generate(&env);
+        let r2 = Address::generate(&env);
+        let r3 = Address::generate(&env);
+        client.single_payout(&prog_id, &r1, &100);
+        client.batch_payout(
+            &prog_id,
+            &soroban_sdk::vec![&env, r2.clone(), r3.clone()],
+            &soroban_sdk::vec![&env, 200i128, 300],
+        );
+
+        let all = client.get_payout_history(&prog_id, &0, &10);
+        assert_eq!(all.len(), 3);
+        assert_eq!(all.get(0).unwrap().recipient, r1);
+        assert_eq!(all.get(0).unwrap().amount, 100);
+        assert_eq!(all.get(1).unwrap().recipient, r2);
+        assert_eq!(all.get(2).unwrap().recipient, r3);
+        assert_eq!(all.get(2).unwrap().amount, 300);
+
+        let page = client.get_payout_history(&prog_id, &1, &1);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.get(0).unwrap().recipient, r2);
+
+        assert_eq!(client.get_payout_history(&prog_id, &3, &10).len(), 0);
+    }
+
+    // ========================================================================
+    // Funder Attribution Tests
+    // ========================================================================
+
+    #[test]
+    fn test_funders_attributed_and_refunded_pro_rata() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let token_client = create_token_contract(&env, &admin);
+        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
+
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "CoFunded");
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+
+        let sponsor_a = Address::generate(&env);
+        let sponsor_b = Address::generate(&env);
+        token_admin.mint(&sponsor_a, &8_000);
+        token_admin.mint(&sponsor_b, &2_000);
+        client.lock_program_funds_from(&prog_id, &sponsor_a, &7_500);
+        client.lock_program_funds_from(&prog_id, &sponsor_b, &2_000);
+        client.lock_program_funds_from(&prog_id, &sponsor_a, &500);
+
+        let funders = client.get_funders(&prog_id);
+        assert_eq!(funders.len(), 2);
+        assert_eq!(funders.get(0).unwrap(), (sponsor_a.clone(), 8_000));
+        assert_eq!(funders.get(1).unwrap(), (sponsor_b.clone(), 2_000));
+
+        // Spend half, then close and refund the rest 80/20
+        client.single_payout(&prog_id, &Address::generate(&env), &5_000);
+        client.close_program(&prog_id, &true);
+
+        assert_eq!(token_client.balance(&sponsor_a), 4_000);
+        assert_eq!(token_client.balance(&sponsor_b), 1_000);
+        assert_eq!(token_client.balance(&backend), 0);
+        assert_eq!(token_client.balance(&contract_id), 0);
+    }
+
+    #[test]
+    fn test_unfunded_attribution_cannot_drain_co_funder() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let token_client = create_token_contract(&env, &admin);
+        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
+
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "CoFunded");
+        client.initialize_program(&prog_id, &backend, &token_client.address);
+
+        let sponsor = Address::generate(&env);
+        let attacker = Address::generate(&env);
+        token_admin.mint(&sponsor, &5_000);
+        client.lock_program_funds_from(&prog_id, &sponsor, &5_000);
+
+        // The attacker holds no tokens, so the contribution cannot be booked
+        assert!(client
+            .try_lock_program_funds_from(&prog_id, &attacker, &5_000)
+            .is_err());
+        assert_eq!(client.get_funders(&prog_id).len(), 1);
+        assert_eq!(client.get_remaining_balance(&prog_id), 5_000);
+
+        client.close_program(&prog_id, &true);
+        assert_eq!(token_client.balance(&sponsor), 5_000);
+        assert_eq!(token_client.balance(&attacker), 0);
+    }
+
+    #[test]
+    fn test_close_refund_returns_unattributed_funds_to_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "CoFunded");
+        let token_client = setup_funded_program(&env, &client, &backend, &prog_id, 9_000);
+        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
+
+        let sponsor = Address::generate(&env);
+        token_admin.mint(&sponsor, &1_000);
+        client.lock_program_funds_from(&prog_id, &sponsor, &1_000);
+
+        // Half the pool is spent; each source gets half of what it put in
+        client.single_payout(&prog_id, &Address::generate(&env), &5_000);
+        client.close_program(&prog_id, &true);
+
+        assert_eq!(token_client.balance(&sponsor), 500);
+        assert_eq!(token_client.balance(&backend), 4_500);
+        assert_eq!(token_client.balance(&contract_id), 0);
+    }
+
+    fn setup_expiring_program<'a>(
+        env: &Env,
+        client: &ProgramEscrowContractClient<'a>,
+        prog_id: &String,
+        sponsor: &Address,
+        expiry: u64,
+    ) -> token::Client<'a> {
+        let admin = Address::generate(env);
+        let token_client = create_token_contract(env, &admin);
+        let token_admin = token::StellarAssetClient::new(env, &token_client.address);
+
+        let backend = Address::generate(env);
+        client.initialize_program_with_expiry(prog_id, &backend, &token_client.address, &expiry);
+        token_admin.mint(sponsor, &10_000);
+        client.lock_program_funds_from(prog_id, sponsor, &10_000);
+        token_client
+    }
+
+    #[test]
+    fn test_refund_program_blocked_before_expiry() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1_000);
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let prog_id = String::from_str(&env, "Expiring");
+        let sponsor = Address::generate(&env);
+        let token_client = setup_expiring_program(&env, &client, &prog_id, &sponsor, 5_000);
+        assert_eq!(client.get_program_expiry(&prog_id), Some(5_000));
+
+        env.ledger().set_timestamp(4_999);
+        let expected = soroban_sdk::Error::from_contract_error(Error::ProgramNotExpired as u32);
+        assert_eq!(client.try_refund_program(&prog_id), Err(Ok(expected)));
+        assert_eq!(client.get_remaining_balance(&prog_id), 10_000);
+        assert_eq!(token_client.balance(&sponsor), 0);
+    }
+
+    #[test]
+    fn test_refund_program_after_expiry_returns_funds_without_auth() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1_000);
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let prog_id = String::from_str(&env, "Expiring");
+        let sponsor = Address::generate(&env);
+        let token_client = setup_expiring_program(&env, &client, &prog_id, &sponsor, 5_000);
+
+        // Anyone can trigger the refund once the program has expired
+        env.set_auths(&[]);
+        env.ledger().set_timestamp(5_000);
+        let program = client.refund_program(&prog_id);
+
+        assert_eq!(program.status, ProgramStatus::Closed);
+        assert_eq!(program.remaining_balance, 0);
+        assert_eq!(token_client.balance(&sponsor), 10_000);
+        assert_eq!(token_client.balance(&contract_id), 0);
+
+        let expected = soroban_sdk::Error::from_contract_error(Error::ProgramClosed as u32);
+        assert_eq!(client.try_refund_program(&prog_id), Err(Ok(expected)));
+    }
+
+    #[test]
+    fn test_refund_program_splits_attributed_and_unattributed_funds() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(1_000);
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let prog_id = String::from_str(&env, "Expiring");
+        let sponsor = Address::generate(&env);
+        let token_client = setup_expiring_program(&env, &client, &prog_id, &sponsor, 5_000);
+
+        // The organizer tops up 30_000 without attribution
+        env.ledger().set_timestamp(2_000);
+        let backend = client.get_program_info(&prog_id).authorized_payout_key;
+        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
+        token_admin.mint(&contract_id, &30_000);
+        client.lock_program_funds(&prog_id, &30_000);
+
+        // A permissionless refund cannot hand the sponsor the organizer's funds
+        env.set_auths(&[]);
+        env.ledger().set_timestamp(5_000);
+        client.refund_program(&prog_id);
+
+        assert_eq!(token_client.balance(&sponsor), 10_000);
+        assert_eq!(token_client.balance(&backend), 30_000);
+        assert_eq!(token_client.balance(&contract_id), 0);
+    }
+
+    #[test]
+    fn test_refund_program_requires_expiry() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "NoExpiry");
+        setup_funded_program(&env, &client, &backend, &prog_id, 10_000);
+
+        assert_eq!(client.get_program_expiry(&prog_id), None);
+        let expected = soroban_sdk::Error::from_contract_error(Error::ExpiryNotConfigured as u32);
+        assert_eq!(client.try_refund_program(&prog_id), Err(Ok(expected)));
+    }
+
+    // ========================================================================
+    // Scheduled Payout Tests
+    // ========================================================================
+
+    #[test]
+    fn test_scheduled_payout_reserves_and_waits_for_unlock() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Vesting");
+        let token_client = setup_funded_program(&env, &client, &backend, &prog_id, 10_000);
+
+        let grantee = Address::generate(&env);
+        let schedule_id = client.schedule_payout(&prog_id, &grantee, &6_000, &1_000);
+
+        // Reserved funds are no longer available to other payouts
+        assert_eq!(client.get_remaining_balance(&prog_id), 4_000);
+        assert!(client
+            .try_single_payout(&prog_id, &Address::generate(&env), &5_000)
+            .is_err());
+
+        env.ledger().set_timestamp(999);
+        assert_eq!(
+            client.try_execute_scheduled_payout(&prog_id, &schedule_id),
+            Err(Ok(soroban_sdk::Error::from_contract_error(
+                Error::PayoutNotUnlocked as u32
+            )))
+        );
+
+        env.ledger().set_timestamp(1_000);
+        let executed = client.execute_scheduled_payout(&prog_id, &schedule_id);
+        assert!(executed.executed);
+        assert_eq!(token_client.balance(&grantee), 6_000);
+        assert_eq!(client.get_remaining_balance(&prog_id), 4_000);
+
+        assert_eq!(
+            client.try_execute_scheduled_payout(&prog_id, &schedule_id),
+            Err(Ok(soroban_sdk::Error::from_contract_error(
+                Error::PayoutAlreadyExecuted as u32
+            )))
+        );
+    }
+
+    #[test]
+    fn test_scheduled_payout_must_unlock_in_the_future() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Vesting");
+        setup_funded_program(&env, &client, &backend, &prog_id, 10_000);
+        env.ledger().set_timestamp(1_000);
+
+        let grantee = Address::generate(&env);
+        let expected = soroban_sdk::Error::from_contract_error(Error::InvalidUnlockTime as u32);
+        for unlock_at in [0u64, 1_000] {
+            assert_eq!(
+                client.try_schedule_payout(&prog_id, &grantee, &1_000, &unlock_at),
+                Err(Ok(expected))
+            );
+        }
+        assert_eq!(client.get_remaining_balance(&prog_id), 10_000);
+
+        client.schedule_payout(&prog_id, &grantee, &1_000, &1_001);
+        assert_eq!(client.get_reserved_balance(&prog_id), 1_000);
+    }
+
+    #[test]
+    fn test_close_program_cancels_open_schedules_and_reservations() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Vesting");
+        let token_client = setup_funded_program(&env, &client, &backend, &prog_id, 10_000);
+
+        let grantee = Address::generate(&env);
+        let schedule_id = client.schedule_payout(&prog_id, &grantee, &4_000, &1_000);
+        let r1 = Address::generate(&env);
+        let r2 = Address::generate(&env);
+        let reservation_id = client.reserve_batch(
+            &prog_id,
+            &soroban_sdk::vec![&env, r1.clone(), r2.clone()],
+            &soroban_sdk::vec![&env, 1_000i128, 2_000],
+        );
+        client.execute_reserved(&prog_id, &reservation_id, &0, &1);
+        assert_eq!(client.get_reserved_balance(&prog_id), 6_000);
+
+        // Reserved funds are swept along with the remaining balance
+        let closed = client.close_program(&prog_id, &false);
+        assert_eq!(closed.remaining_balance, 0);
+        assert_eq!(closed.reserved_balance, 0);
+        assert_eq!(token_client.balance(&backend), 9_000);
+        assert_eq!(token_client.balance(&contract_id), 0);
+
+        env.ledger().set_timestamp(1_000);
+        let expected = soroban_sdk::Error::from_contract_error(Error::ProgramClosed as u32);
+        assert_eq!(
+            client.try_execute_scheduled_payout(&prog_id, &schedule_id),
+            Err(Ok(expected))
+        );
+        assert_eq!(
+            client.try_execute_reserved(&prog_id, &reservation_id, &1, &1),
+            Err(Ok(expected))
+        );
+        assert_eq!(token_client.balance(&grantee), 0);
+        assert_eq!(token_client.balance(&r2), 0);
+    }
+
+    // ========================================================================
+    // Minimum Payout Tests
+    // ========================================================================
+
+    fn setup_program_with_min_payout<'a>(
+        env: &Env,
+        client: &ProgramEscrowContractClient<'a>,
+        backend: &Address,
+        prog_id: &String,
+        min_payout: i128,
+    ) {
+        let token_admin = Address::generate(env);
+        let token_client = create_token_contract(env, &token_admin);
+        token::StellarAssetClient::new(env, &token_client.address).mint(&client.address, &10_000);
+        client.initialize_program_with_minimum(
+            prog_id,
+            backend,
+            &token_client.address,
+            &min_payout,
+        );
+        client.lock_program_funds(prog_id, &10_000);
+    }
+
+    #[test]
+    fn test_single_payout_over_remaining_balance_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_a = String::from_str(&env, "GrantA");
+        let prog_b = String::from_str(&env, "GrantB");
+        let token_client = setup_funded_program(&env, &client, &backend, &prog_a, 1_000);
+
+        // A second program's funds sit in the same contract balance, so the
+        // token transfer alone would not catch the overdraft
+        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
+        client.initialize_program(&prog_b, &backend, &token_client.address);
+        token_admin.mint(&client.address, &5_000);
+        client.lock_program_funds(&prog_b, &5_000);
+
+        let winner = Address::generate(&env);
+        let expected = soroban_sdk::Error::from_contract_error(Error::InsufficientFunds as u32);
+        assert_eq!(
+            client.try_single_payout(&prog_a, &winner, &1_001),
+            Err(Ok(expected))
+        );
+        assert_eq!(token_client.balance(&winner), 0);
+        assert_eq!(token_client.balance(&client.address), 6_000);
+        assert_eq!(client.get_program_info(&prog_a).remaining_balance, 1_000);
+    }
+
+    #[test]
+    fn test_single_payout_at_min_payout_succeeds() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+        setup_program_with_min_payout(&env, &client, &backend, &prog_id, 100);
+
+        assert_eq!(client.get_min_payout(&prog_id), 100);
+        client.single_payout(&prog_id, &Address::generate(&env), &100);
+        assert_eq!(client.get_remaining_balance(&prog_id), 9_900);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #16)")] // PayoutTooSmall
+    fn test_single_payout_below_min_payout_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+        setup_program_with_min_payout(&env, &client, &backend, &prog_id, 100);
+
+        client.single_payout(&prog_id, &Address::generate(&env), &99);
+    }
+
+    #[test]
+    fn test_batch_payout_enforces_min_payout_per_entry() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+        setup_program_with_min_payout(&env, &client, &backend, &prog_id, 100);
+        let r1 = Address::generate(&env);
+        let r2 = Address::generate(&env);
+
+        let expected = soroban_sdk::Error::from_contract_error(Error::PayoutTooSmall as u32);
+        assert_eq!(
+            client.try_batch_payout(
+                &prog_id,
+                &vec![&env, r1.clone(), r2.clone()],
+                &vec![&env, 500, 99]
+            ),
+            Err(Ok(expected))
+        );
+        assert_eq!(client.get_remaining_balance(&prog_id), 10_000);
+
+        client.batch_payout(&prog_id, &vec![&env, r1, r2], &vec![&env, 500, 100]);
+        assert_eq!(client.get_remaining_balance(&prog_id), 9_400);
+    }
+
+    #[test]
+    fn test_split_equal_enforces_min_payout_per_share() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+        setup_program_with_min_payout(&env, &client, &backend, &prog_id, 100);
+        let recipients = vec![&env, Address::generate(&env), Address::generate(&env)];
+
+        let expected = soroban_sdk::Error::from_contract_error(Error::PayoutTooSmall as u32);
+        assert_eq!(
+            client.try_split_equal(&prog_id, &recipients, &199),
+            Err(Ok(expected))
+        );
+        assert_eq!(client.get_remaining_balance(&prog_id), 10_000);
+
+        client.split_equal(&prog_id, &recipients, &200);
+        assert_eq!(client.get_remaining_balance(&prog_id), 9_800);
+    }
+
+    #[test]
+    fn test_zero_min_payout_means_no_minimum() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+        setup_program_with_min_payout(&env, &client, &backend, &prog_id, 0);
+
+        client.single_payout(&prog_id, &Address::generate(&env), &1);
+        assert_eq!(client.get_remaining_balance(&prog_id), 9_999);
+    }
+
+    // ========================================================================
+    // Payout Clawback Tests
+    // ========================================================================
+
+    #[test]
+    fn test_clawback_with_recipient_confirmation() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let wrong_winner = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        let token_client = setup_funded_program(&env, &client, &backend, &prog_id, 1_000);
+        client.set_clawback_window(&prog_id, &3_600);
+        client.single_payout(&prog_id, &wrong_winner, &400);
+        assert_eq!(token_client.balance(&wrong_winner), 400);
+
+        env.ledger().with_mut(|li| li.timestamp += 600);
+        let program = client.clawback_payout(&prog_id, &0);
+        assert_eq!(program.remaining_balance, 1_000);
+        assert_eq!(token_client.balance(&wrong_winner), 0);
+        assert_eq!(token_client.balance(&client.address), 1_000);
+        assert_eq!(client.get_recipient_paid(&prog_id, &wrong_winner), 0);
+
+        let expected =
+            soroban_sdk::Error::from_contract_error(Error::PayoutAlreadyClawedBack as u32);
+        assert_eq!(client.try_clawback_payout(&prog_id, &0), Err(Ok(expected)));
+    }
+
+    #[test]
+    fn test_clawback_requires_recipient_auth() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        setup_funded_program(&env, &client, &backend, &prog_id, 1_000);
+        client.set_clawback_window(&prog_id, &3_600);
+        client.single_payout(&prog_id, &winner, &400);
+
+        // Only the admin signs; the recipient does not confirm
+        env.mock_auths(&[MockAuth {
+            address: &backend,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "clawback_payout",
+                args: (prog_id.clone(), 0u32).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        assert!(client.try_clawback_payout(&prog_id, &0).is_err());
+        assert_eq!(client.get_remaining_balance(&prog_id), 600);
+    }
+
+    #[test]
+    fn test_clawback_rejected_outside_window() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        setup_funded_program(&env, &client, &backend, &prog_id, 1_000);
+        client.single_payout(&prog_id, &winner, &400);
+
+        // No window configured
+        let expected = soroban_sdk::Error::from_contract_error(Error::ClawbackWindowClosed as u32);
+        assert_eq!(client.try_clawback_payout(&prog_id, &0), Err(Ok(expected)));
+
+        client.set_clawback_window(&prog_id, &3_600);
+        env.ledger().with_mut(|li| li.timestamp += 3_601);
+        let expected = soroban_sdk::Error::from_contract_error(Error::ClawbackWindowClosed as u32);
+        assert_eq!(client.try_clawback_payout(&prog_id, &0), Err(Ok(expected)));
+
+        let expected = soroban_sdk::Error::from_contract_error(Error::PayoutNotFound as u32);
+        assert_eq!(client.try_clawback_payout(&prog_id, &5), Err(Ok(expected)));
+    }
+
+    #[test]
+    fn test_clawback_rejected_after_close() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+
+        let token_client = setup_funded_program(&env, &client, &backend, &prog_id, 1_000);
+        client.set_clawback_window(&prog_id, &3_600);
+        client.single_payout(&prog_id, &winner, &400);
+        client.close_program(&prog_id, &false);
+
+        let expected = soroban_sdk::Error::from_contract_error(Error::ProgramClosed as u32);
+        assert_eq!(client.try_clawback_payout(&prog_id, &0), Err(Ok(expected)));
+        assert_eq!(token_client.balance(&winner), 400);
+        assert_eq!(token_client.balance(&contract_id), 0);
+        assert_eq!(client.get_remaining_balance(&prog_id), 0);
+    }
+
+    // ========================================================================
+    // Recipient Blocklist Tests
+    // ========================================================================
+
+    #[test]
+    fn test_blocked_recipient_cannot_be_paid_until_unblocked() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let blocked = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+        let token_client = setup_funded_program(&env, &client, &backend, &prog_id, 1_000);
+
+        client.block_recipient(&prog_id, &blocked);
+        assert!(client.is_recipient_blocked(&prog_id, &blocked));
+
+        let expected = soroban_sdk::Error::from_contract_error(Error::RecipientBlocked as u32);
+        assert_eq!(
+            client.try_single_payout(&prog_id, &blocked, &100),
+            Err(Ok(expected))
+        );
+
+        client.unblock_recipient(&prog_id, &blocked);
+        assert!(!client.is_recipient_blocked(&prog_id, &blocked));
+        client.single_payout(&prog_id, &blocked, &100);
+        assert_eq!(token_client.balance(&blocked), 100);
+    }
+
+    #[test]
+    fn test_blocked_recipient_fails_whole_batch() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let ok_recipient = Address::generate(&env);
+        let blocked = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+        let token_client = setup_funded_program(&env, &client, &backend, &prog_id, 1_000);
+
+        client.block_recipient(&prog_id, &blocked);
+
+        let expected = soroban_sdk::Error::from_contract_error(Error::RecipientBlocked as u32);
+        assert_eq!(
+            client.try_batch_payout(
+                &prog_id,
+                &vec![&env, ok_recipient.clone(), blocked.clone()],
+                &vec![&env, 100, 100]
+            ),
+            Err(Ok(expected))
+        );
+        assert_eq!(token_client.balance(&ok_recipient), 0);
+        assert_eq!(client.get_remaining_balance(&prog_id), 1_000);
+
+        client.unblock_recipient(&prog_id, &blocked);
+        client.batch_payout(
+            &prog_id,
+            &vec![&env, ok_recipient.clone(), blocked.clone()],
+            &vec![&env, 100, 100],
+        );
+        assert_eq!(token_client.balance(&blocked), 100);
+    }
+
+    #[test]
+    fn test_blocklist_rechecked_when_scheduled_and_reserved_payouts_execute() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let ok_recipient = Address::generate(&env);
+        let blocked = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+        let token_client = setup_funded_program(&env, &client, &backend, &prog_id, 1_000);
+
+        let schedule_id = client.schedule_payout(&prog_id, &blocked, &100, &100);
+        let reservation_id = client.reserve_batch(
+            &prog_id,
+            &vec![&env, ok_recipient.clone(), blocked.clone()],
+            &vec![&env, 200, 300],
+        );
+        client.block_recipient(&prog_id, &blocked);
+        env.ledger().set_timestamp(100);
+
+        let expected = soroban_sdk::Error::from_contract_error(Error::RecipientBlocked as u32);
+        assert_eq!(
+            client.try_execute_scheduled_payout(&prog_id, &schedule_id),
+            Err(Ok(expected))
+        );
+        assert_eq!(
+            client.try_execute_reserved(&prog_id, &reservation_id, &0, &2),
+            Err(Ok(expected))
+        );
+        assert_eq!(token_client.balance(&ok_recipient), 0);
+        assert_eq!(token_client.balance(&blocked), 0);
+        assert_eq!(client.get_reserved_balance(&prog_id), 600);
+
+        // Entries before the blocked one can still be paid in their own chunk.
+        client.execute_reserved(&prog_id, &reservation_id, &0, &1);
+        assert_eq!(token_client.balance(&ok_recipient), 200);
+        assert_eq!(client.get_reserved_balance(&prog_id), 400);
+    }
+
+    // ========================================================================
+    // Payout Approval Tests
+    // ========================================================================
+
+    fn setup_program_with_approver<'a>(
+        env: &Env,
+        client: &ProgramEscrowContractClient<'a>,
+        backend: &Address,
+        approver: &Address,
+        prog_id: &String,
+    ) -> token::Client<'a> {
+        let token_admin = Address::generate(env);
+        let token_client = create_token_contract(env, &token_admin);
+        token::StellarAssetClient::new(env, &token_client.address).mint(&client.address, &10_000);
+        client.initialize_program_with_approver(
+            prog_id,
+            backend,
+            &token_client.address,
+            approver,
+            &1_000,
+        );
+        client.lock_program_funds(prog_id, &10_000);
+        token_client
+    }
+
+    #[test]
+    fn test_payout_proposal_approved_and_executed() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let approver = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Grants");
+        let token_client =
+            setup_program_with_approver(&env, &client, &backend, &approver, &prog_id);
+
+        // Small payouts still go straight through
+        client.single_payout(&prog_id, &Address::generate(&env), &999);
+
+        let expected = soroban_sdk::Error::from_contract_error(Error::ApprovalRequired as u32);
+        assert_eq!(
+            client.try_single_payout(&prog_id, &winner, &5_000),
+            Err(Ok(expected))
+        );
+
+        let payout_id = client.propose_payout(&prog_id, &winner, &5_000);
+        assert_eq!(token_client.balance(&winner), 0);
+        assert!(!client.get_pending_payout(&prog_id, &payout_id).executed);
+
+        let program = client.approve_payout(&prog_id, &payout_id);
+        assert_eq!(program.remaining_balance, 10_000 - 999 - 5_000);
+        assert_eq!(token_client.balance(&winner), 5_000);
+        assert!(client.get_pending_payout(&prog_id, &payout_id).executed);
+
+        let (_, topics, data) = env.events().all().last().unwrap();
+        assert_eq!(
+            topics,
+            (symbol_short!("PayAppr"), prog_id.clone()).into_val(&env)
+        );
+        let event: events::PayoutApproved = data.into_val(&env);
+        assert_eq!(event.approver, approver);
+        assert_eq!(event.amount, 5_000);
+
+        let expected = soroban_sdk::Error::from_contract_error(Error::PayoutAlreadyExecuted as u32);
+        assert_eq!(
+            client.try_approve_payout(&prog_id, &payout_id),
+            Err(Ok(expected))
+        );
+    }
+
+    #[test]
+    fn test_every_direct_payout_path_requires_approval() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let approver = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Grants");
+        let token_client =
+            setup_program_with_approver(&env, &client, &backend, &approver, &prog_id);
+
+        let expected = soroban_sdk::Error::from_contract_error(Error::ApprovalRequired as u32);
+        assert_eq!(
+            client.try_split_equal(&prog_id, &vec![&env, winner.clone()], &10_000),
+            Err(Ok(expected))
+        );
+        assert_eq!(
+            client.try_distribute_proportional(
+                &prog_id,
+                &vec![&env, winner.clone(), Address::generate(&env)],
+                &vec![&env, 9, 1]
+            ),
+            Err(Ok(expected))
+        );
+        assert_eq!(
+            client.try_schedule_payout(&prog_id, &winner, &5_000, &1_000),
+            Err(Ok(expected))
+        );
+
+        assert_eq!(token_client.balance(&winner), 0);
+        assert_eq!(client.get_remaining_balance(&prog_id), 10_000);
+        assert_eq!(client.get_reserved_balance(&prog_id), 0);
+    }
+
+    #[test]
+    fn test_payout_approval_by_wrong_party_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let approver = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Grants");
+        let token_client =
+            setup_program_with_approver(&env, &client, &backend, &approver, &prog_id);
+
+        let payout_id = client.propose_payout(&prog_id, &winner, &5_000);
+
+        // The program admin cannot approve its own proposal
+        env.mock_auths(&[MockAuth {
+            address: &backend,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "approve_payout",
+                args: (prog_id.clone(), payout_id).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        assert!(client.try_approve_payout(&prog_id, &payout_id).is_err());
+        assert_eq!(token_client.balance(&winner), 0);
+        assert_eq!(client.get_remaining_balance(&prog_id), 10_000);
+    }
+
+    #[test]
+    fn test_propose_payout_without_approver_rejected() {
         let env = Env::default();
+        env.mock_all_auths();
         let contract_id = env.register_contract(None, ProgramEscrowContract);
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+        setup_funded_program(&env, &client, &backend, &prog_id, 1_000);
 
-        let backend1 = Address::generate(&env);
-        let backend2 = Address::generate(&env);
-        let backend3 = Address::generate(&env);
-        let token = Address::generate(&env);
-
-        // Register three programs
-        let prog1 = String::from_str(&env, "ETHGlobal2024");
-        let prog2 = String::from_str(&env, "Stellar2024");
-        let prog3 = String::from_str(&env, "BuildathonQ1");
-
-        client.initialize_program(&prog1, &backend1, &token);
-        client.initialize_program(&prog2, &backend2, &token);
-        client.initialize_program(&prog3, &backend3, &token);
-
-        // Verify all exist
-        assert!(client.program_exists(&prog1));
-        assert!(client.program_exists(&prog2));
-        assert!(client.program_exists(&prog3));
-        assert_eq!(client.get_program_count(), 3);
-
-        // Verify complete isolation
-        let info1 = client.get_program_info(&prog1);
-        let info2 = client.get_program_info(&prog2);
-        let info3 = client.get_program_info(&prog3);
-
-        assert_eq!(info1.program_id, prog1);
-        assert_eq!(info2.program_id, prog2);
-        assert_eq!(info3.program_id, prog3);
+        let expected = soroban_sdk::Error::from_contract_error(Error::ApprovalNotConfigured as u32);
+        assert_eq!(
+            client.try_propose_payout(&prog_id, &Address::generate(&env), &500),
+            Err(Ok(expected))
+        );
+    }
 
-        assert_eq!(info1.authorized_payout_key, backend1);
-        assert_eq!(info2.authorized_payout_key, backend2);
-        assert_eq!(info3.authorized_payout_key, backend3);
+    #[test]
+    fn test_payout_to_contract_address_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+        setup_funded_program(&env, &client, &backend, &prog_id, 10_000);
 
-        // Verify list programs
-        let programs = client.list_programs();
-        assert_eq!(programs.len(), 3);
+        let expected = soroban_sdk::Error::from_contract_error(Error::InvalidRecipient as u32);
+        assert_eq!(
+            client.try_single_payout(&prog_id, &contract_id, &100),
+            Err(Ok(expected))
+        );
+        assert_eq!(
+            client.try_batch_payout(
+                &prog_id,
+                &vec![&env, Address::generate(&env), contract_id.clone()],
+                &vec![&env, 100i128, 100i128]
+            ),
+            Err(Ok(expected))
+        );
+        assert_eq!(client.get_remaining_balance(&prog_id), 10_000);
     }
 
+    // ========================================================================
+    // Reserved Batch Payout Tests
+    // ========================================================================
+
     #[test]
-    #[should_panic(expected = "Program already exists")]
-    fn test_duplicate_program_registration() {
+    fn test_reserved_batch_paid_in_chunks_exactly_once() {
         let env = Env::default();
+        env.mock_all_auths();
+        env.budget().reset_unlimited();
         let contract_id = env.register_contract(None, ProgramEscrowContract);
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
-
         let backend = Address::generate(&env);
-        let token = Address::generate(&env);
         let prog_id = String::from_str(&env, "Hackathon2024");
+        let token = setup_funded_program(&env, &client, &backend, &prog_id, 10_000);
 
-        // Register once - should succeed
-        client.initialize_program(&prog_id, &backend, &token);
+        let mut recipients = vec![&env];
+        let mut amounts = vec![&env];
+        for i in 0..25 {
+            recipients.push_back(Address::generate(&env));
+            amounts.push_back(100 + i as i128);
+        }
+        let total: i128 = amounts.iter().sum();
 
-        // Register again - should panic
-        client.initialize_program(&prog_id, &backend, &token);
+        let reservation_id = client.reserve_batch(&prog_id, &recipients, &amounts);
+        assert_eq!(
+            client.get_program_info(&prog_id).remaining_balance,
+            10_000 - total
+        );
+        assert_eq!(token.balance(&contract_id), 10_000);
+
+        let mut start = 0;
+        while start < recipients.len() {
+            let reservation = client.execute_reserved(&prog_id, &reservation_id, &start, &10);
+            start = reservation.paid_count;
+        }
+        assert_eq!(start, 25);
+
+        for i in 0..recipients.len() {
+            assert_eq!(
+                token.balance(&recipients.get(i).unwrap()),
+                amounts.get(i).unwrap()
+            );
+        }
+        assert_eq!(token.balance(&contract_id), 10_000 - total);
+
+        // Replaying an earlier chunk cannot pay anyone twice
+        let expected = soroban_sdk::Error::from_contract_error(Error::ReservationOutOfOrder as u32);
+        assert_eq!(
+            client.try_execute_reserved(&prog_id, &reservation_id, &0, &10),
+            Err(Ok(expected))
+        );
+        assert_eq!(
+            client
+                .execute_reserved(&prog_id, &reservation_id, &25, &10)
+                .paid_count,
+            25
+        );
+        assert_eq!(token.balance(&contract_id), 10_000 - total);
     }
 
     #[test]
-    #[should_panic(expected = "Program ID cannot be empty")]
-    fn test_empty_program_id() {
+    fn test_reserved_and_available_balances_sum_to_locked() {
         let env = Env::default();
+        env.mock_all_auths();
         let contract_id = env.register_contract(None, ProgramEscrowContract);
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
-
         let backend = Address::generate(&env);
-        let token = Address::generate(&env);
-        let empty_id = String::from_str(&env, "");
+        let prog_id = String::from_str(&env, "Hackathon2024");
+        let token = setup_funded_program(&env, &client, &backend, &prog_id, 10_000);
+
+        let r1 = Address::generate(&env);
+        let r2 = Address::generate(&env);
+        let reservation_id = client.reserve_batch(
+            &prog_id,
+            &vec![&env, r1, r2],
+            &vec![&env, 1_500i128, 500i128],
+        );
+        client.schedule_payout(&prog_id, &Address::generate(&env), &1_000, &100);
 
-        client.initialize_program(&empty_id, &backend, &token);
+        assert_eq!(client.get_reserved_balance(&prog_id), 3_000);
+        assert_eq!(client.get_remaining_balance(&prog_id), 7_000);
+        assert_eq!(
+            client.get_reserved_balance(&prog_id) + client.get_remaining_balance(&prog_id),
+            token.balance(&contract_id)
+        );
+
+        client.execute_reserved(&prog_id, &reservation_id, &0, &1);
+        assert_eq!(client.get_reserved_balance(&prog_id), 1_500);
+        assert_eq!(client.get_remaining_balance(&prog_id), 7_000);
+        assert_eq!(
+            client.get_reserved_balance(&prog_id) + client.get_remaining_balance(&prog_id),
+            token.balance(&contract_id)
+        );
     }
 
     #[test]
-    #[should_panic(expected = "Program not found")]
-    fn test_get_nonexistent_program() {
+    #[should_panic(expected = "Error(Contract, #4)")] // InsufficientFunds
+    fn test_reserve_batch_over_budget_rejected() {
         let env = Env::default();
+        env.mock_all_auths();
         let contract_id = env.register_contract(None, ProgramEscrowContract);
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Hackathon2024");
+        setup_funded_program(&env, &client, &backend, &prog_id, 1_000);
 
-        let prog_id = String::from_str(&env, "DoesNotExist");
-        client.get_program_info(&prog_id);
+        client.reserve_batch(
+            &prog_id,
+            &vec![&env, Address::generate(&env), Address::generate(&env)],
+            &vec![&env, 600i128, 600i128],
+        );
     }
 
     // ========================================================================
-    // Fund Locking Tests
+    // Program Event Tests
     // ========================================================================
 
+    /// Topics and data of the latest event with `name` as its first topic.
+    fn find_event(env: &Env, name: Symbol) -> (soroban_sdk::Vec<Val>, Val) {
+        env.events()
+            .all()
+            .iter()
+            .rev()
+            .find_map(|(_, topics, data)| {
+                let first: Val = topics.get(0)?;
+                Symbol::try_from_val(env, &first)
+                    .ok()
+                    .filter(|symbol| *symbol == name)
+                    .map(|_| (topics, data))
+            })
+            .expect("event not emitted")
+    }
+
     #[test]
-    fn test_lock_funds_single_program() {
+    fn test_program_lifecycle_events() {
         let env = Env::default();
         env.mock_all_auths();
+        env.ledger().set_timestamp(1_000);
 
-        let admin = Address::generate(&env);
         let contract_id = env.register_contract(None, ProgramEscrowContract);
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
-        let token_client = create_token_contract(&env, &admin);
-
         let backend = Address::generate(&env);
-        let prog_id = String::from_str(&env, "Hackathon2024");
+        let admin = Address::generate(&env);
+        let token_client = create_token_contract(&env, &admin);
+        let token_admin = token::StellarAssetClient::new(&env, &token_client.address);
+        let prog_id = String::from_str(&env, "Events");
 
-        // Register program
         client.initialize_program(&prog_id, &backend, &token_client.address);
+        let (topics, data) = find_event(&env, symbol_short!("prog_init"));
+        assert_eq!(
+            topics,
+            (symbol_short!("prog_init"), prog_id.clone()).into_val(&env)
+        );
+        let event: events::ProgramInitialized = data.into_val(&env);
+        assert_eq!(
+            event,
+            events::ProgramInitialized {
+                program_id: prog_id.clone(),
+                authorized_payout_key: backend.clone(),
+                token_address: token_client.address.clone(),
+                total_funds: 0,
+                timestamp: 1_000,
+            }
+        );
 
-        // Lock funds
-        let amount = 10_000_0000000i128; // 10,000 USDC
-        let updated = client.lock_program_funds(&prog_id, &amount);
+        token_admin.mint(&client.address, &10_000);
+        env.ledger().set_timestamp(1_100);
+        client.lock_program_funds(&prog_id, &10_000);
+        let (topics, data) = find_event(&env, symbol_short!("prog_lock"));
+        assert_eq!(
+            topics,
+            (symbol_short!("prog_lock"), prog_id.clone()).into_val(&env)
+        );
+        let event: events::ProgramFundsLocked = data.into_val(&env);
+        assert_eq!(
+            event,
+            events::ProgramFundsLocked {
+                program_id: prog_id.clone(),
+                amount: 10_000,
+                remaining_balance: 10_000,
+                locked_at: 1_100,
+            }
+        );
 
-        assert_eq!(updated.total_funds, amount);
-        assert_eq!(updated.remaining_balance, amount);
+        env.ledger().set_timestamp(2_000);
+        client.close_program(&prog_id, &false);
+        let (topics, data) = find_event(&env, symbol_short!("prg_close"));
+        assert_eq!(
+            topics,
+            (symbol_short!("prg_close"), prog_id.clone()).into_val(&env)
+        );
+        let event: events::ProgramClosed = data.into_val(&env);
+        assert_eq!(event.swept_amount, 10_000);
+        assert_eq!(event.timestamp, 2_000);
     }
 
     #[test]
-    fn test_lock_funds_multiple_programs_isolation() {
+    fn test_payout_events() {
         let env = Env::default();
         env.mock_all_auths();
+        env.ledger().set_timestamp(5_000);
 
-        let admin = Address::generate(&env);
         let contract_id = env.register_contract(None, ProgramEscrowContract);
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
-        let token_client = create_token_contract(&env, &admin);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Events");
+        setup_funded_program(&env, &client, &backend, &prog_id, 10_000);
 
-        let backend1 = Address::generate(&env);
-        let backend2 = Address::generate(&env);
+        let winner = Address::generate(&env);
+        env.ledger().set_timestamp(5_100);
+        client.single_payout(&prog_id, &winner, &1_000);
+        let (topics, data) = find_event(&env, symbol_short!("payout"));
+        assert_eq!(
+            topics,
+            (symbol_short!("payout"), prog_id.clone(), winner.clone()).into_val(&env)
+        );
+        let event: events::SinglePayout = data.into_val(&env);
+        assert_eq!(
+            event,
+            events::SinglePayout {
+                program_id: prog_id.clone(),
+                recipient: winner,
+                amount: 1_000,
+                remaining_balance: 9_000,
+                timestamp: 5_100,
+            }
+        );
 
-        let prog1 = String::from_str(&env, "Program1");
-        let prog2 = String::from_str(&env, "Program2");
+        let recipients = vec![&env, Address::generate(&env), Address::generate(&env)];
+        let amounts = vec![&env, 2_000i128, 3_000i128];
+        env.ledger().set_timestamp(5_200);
+        client.batch_payout(&prog_id, &recipients, &amounts);
+        let (topics, data) = find_event(&env, symbol_short!("batch_pay"));
+        assert_eq!(
+            topics,
+            (symbol_short!("batch_pay"), prog_id.clone()).into_val(&env)
+        );
+        let event: events::BatchPayout = data.into_val(&env);
+        assert_eq!(
+            event,
+            events::BatchPayout {
+                program_id: prog_id,
+                recipient_count: 2,
+                total_amount: 5_000,
+                remaining_balance: 4_000,
+                timestamp: 5_200,
+            }
+        );
+    }
 
-        // Register programs
-        client.initialize_program(&prog1, &backend1, &token_client.address);
-        client.initialize_program(&prog2, &backend2, &token_client.address);
+    #[test]
+    fn test_program_administration_events() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Events");
+        setup_funded_program(&env, &client, &backend, &prog_id, 10_000);
+
+        client.pause_program(&prog_id);
+        let (topics, data) = find_event(&env, symbol_short!("PrgPause"));
+        assert_eq!(
+            topics,
+            (symbol_short!("PrgPause"), prog_id.clone()).into_val(&env)
+        );
+        let event: events::ProgramPauseChanged = data.into_val(&env);
+        assert!(event.paused);
+
+        client.resume_program(&prog_id);
+        let (_, data) = find_event(&env, symbol_short!("PrgResume"));
+        let event: events::ProgramPauseChanged = data.into_val(&env);
+        assert!(!event.paused);
+
+        let recipient = Address::generate(&env);
+        client.block_recipient(&prog_id, &recipient);
+        let (topics, data) = find_event(&env, symbol_short!("RcptBlock"));
+        assert_eq!(
+            topics,
+            (symbol_short!("RcptBlock"), prog_id.clone()).into_val(&env)
+        );
+        let event: events::RecipientBlockChanged = data.into_val(&env);
+        assert_eq!(
+            event,
+            events::RecipientBlockChanged {
+                program_id: prog_id,
+                recipient,
+                blocked: true,
+            }
+        );
+    }
 
-        // Lock different amounts in each program
-        let amount1 = 5_000_0000000i128;
-        let amount2 = 10_000_0000000i128;
+    #[test]
+    fn test_deferred_payout_events() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, ProgramEscrowContract);
+        let client = ProgramEscrowContractClient::new(&env, &contract_id);
+        let backend = Address::generate(&env);
+        let prog_id = String::from_str(&env, "Events");
+        setup_funded_program(&env, &client, &backend, &prog_id, 10_000);
+
+        let grantee = Address::generate(&env);
+        let schedule_id = client.schedule_payout(&prog_id, &grantee, &1_000, &100);
+        let (topics, data) = find_event(&env, symbol_short!("PaySched"));
+        assert_eq!(
+            topics,
+            (symbol_short!("PaySched"), prog_id.clone()).into_val(&env)
+        );
+        let event: events::PayoutScheduled = data.into_val(&env);
+        assert_eq!(
+            event,
+            events::PayoutScheduled {
+                program_id: prog_id.clone(),
+                schedule_id,
+                recipient: grantee.clone(),
+                amount: 1_000,
+                unlock_at: 100,
+            }
+        );
 
-        client.lock_program_funds(&prog1, &amount1);
-        client.lock_program_funds(&prog2, &amount2);
+        let recipients = vec![&env, Address::generate(&env), Address::generate(&env)];
+        let reservation_id = client.reserve_batch(&prog_id, &recipients, &vec![&env, 200, 300]);
+        let (_, data) = find_event(&env, symbol_short!("BatchRsv"));
+        let event: events::BatchReserved = data.into_val(&env);
+        assert_eq!(
+            event,
+            events::BatchReserved {
+                program_id: prog_id.clone(),
+                reservation_id,
+                recipient_count: 2,
+                total_amount: 500,
+            }
+        );
 
-        // Verify isolation - funds don't mix
-        let info1 = client.get_program_info(&prog1);
-        let info2 = client.get_program_info(&prog2);
+        env.ledger().set_timestamp(100);
+        client.execute_scheduled_payout(&prog_id, &schedule_id);
+        let (_, data) = find_event(&env, symbol_short!("PaySchExe"));
+        let event: events::ScheduledPayoutExecuted = data.into_val(&env);
+        assert_eq!(
+            event,
+            events::ScheduledPayoutExecuted {
+                program_id: prog_id.clone(),
+                schedule_id,
+                recipient: grantee,
+                amount: 1_000,
+                timestamp: 100,
+            }
+        );
 
-        assert_eq!(info1.total_funds, amount1);
-        assert_eq!(info1.remaining_balance, amount1);
-        assert_eq!(info2.total_funds, amount2);
-        assert_eq!(info2.remaining_balance, amount2);
+        client.execute_reserved(&prog_id, &reservation_id, &0, &2);
+        let (_, data) = find_event(&env, symbol_short!("RsvPaid"));
+        let event: events::ReservedChunkPaid = data.into_val(&env);
+        assert_eq!(
+            event,
+            events::ReservedChunkPaid {
+                program_id: prog_id,
+                reservation_id,
+                start: 0,
+                end: 2,
+                amount: 500,
+            }
+        );
     }
 
+    // ========================================================================
+    // Program Admin Transfer Tests
+    // ========================================================================
+
     #[test]
-    fn test_lock_funds_cumulative() {
+    fn test_program_admin_transfer_cycle() {
         let env = Env::default();
         env.mock_all_auths();
-
-        let admin = Address::generate(&env);
         let contract_id = env.register_contract(None, ProgramEscrowContract);
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
-        let token_client = create_token_contract(&env, &admin);
-
         let backend = Address::generate(&env);
+        let new_admin = Address::generate(&env);
+        let winner = Address::generate(&env);
         let prog_id = String::from_str(&env, "Hackathon2024");
 
-        client.initialize_program(&prog_id, &backend, &token_client.address);
+        setup_funded_program(&env, &client, &backend, &prog_id, 1_000);
+        assert_eq!(client.get_program_admin(&prog_id), backend);
 
-        // Lock funds multiple times
-        client.lock_program_funds(&prog_id, &1_000_0000000);
-        client.lock_program_funds(&prog_id, &2_000_0000000);
-        client.lock_program_funds(&prog_id, &3_000_0000000);
+        client.propose_program_admin(&prog_id, &new_admin);
+        // Proposal alone changes nothing
+        assert_eq!(client.get_program_admin(&prog_id), backend);
 
-        let info = client.get_program_info(&prog_id);
-        assert_eq!(info.total_funds, 6_000_0000000);
-        assert_eq!(info.remaining_balance, 6_000_0000000);
+        let program = client.accept_program_admin(&prog_id);
+        assert_eq!(program.authorized_payout_key, new_admin);
+        assert_eq!(client.get_program_admin(&prog_id), new_admin);
+
+        let (_, topics, data) = env.events().all().last().unwrap();
+        assert_eq!(
+            topics,
+            (symbol_short!("PrgAdmXfr"), prog_id.clone()).into_val(&env)
+        );
+        let event: events::ProgramAdminTransferred = data.into_val(&env);
+        assert_eq!(event.previous_admin, backend);
+        assert_eq!(event.new_admin, new_admin);
+
+        // The pending proposal is consumed
+        let expected = soroban_sdk::Error::from_contract_error(Error::NoPendingAdmin as u32);
+        assert_eq!(client.try_accept_program_admin(&prog_id), Err(Ok(expected)));
+
+        client.single_payout(&prog_id, &winner, &100);
+        assert_eq!(client.get_remaining_balance(&prog_id), 900);
     }
 
     #[test]
-    #[should_panic(expected = "Amount must be greater than zero")]
-    fn test_lock_zero_funds() {
+    fn test_non_admin_cannot_propose_program_admin() {
         let env = Env::default();
+        env.mock_all_auths();
         let contract_id = env.register_contract(None, ProgramEscrowContract);
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
-
         let backend = Address::generate(&env);
-        let token = Address::generate(&env);
+        let attacker = Address::generate(&env);
         let prog_id = String::from_str(&env, "Hackathon2024");
 
-        client.initialize_program(&prog_id, &backend, &token);
-        client.lock_program_funds(&prog_id, &0);
+        client.initialize_program(&prog_id, &backend, &Address::generate(&env));
+
+        env.mock_auths(&[MockAuth {
+            address: &attacker,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "propose_program_admin",
+                args: (prog_id.clone(), attacker.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        assert!(client
+            .try_propose_program_admin(&prog_id, &attacker)
+            .is_err());
+        assert_eq!(client.get_program_admin(&prog_id), backend);
     }
 
     // ========================================================================
-    // Batch Payout Tests
+    // Aggregate Balance Tests
     // ========================================================================
 
     #[test]
-    #[should_panic(expected = "Recipients and amounts vectors must have the same length")]
-    fn test_batch_payout_mismatched_lengths() {
+    fn test_totals_aggregate_across_programs() {
         let env = Env::default();
         env.mock_all_auths();
 
-        let admin = Address::generate(&env);
         let contract_id = env.register_contract(None, ProgramEscrowContract);
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
-        let token_client = create_token_contract(&env, &admin);
-
         let backend = Address::generate(&env);
-        let prog_id = String::from_str(&env, "Test");
-
-        client.initialize_program(&prog_id, &backend, &token_client.address);
-        client.lock_program_funds(&prog_id, &10_000_0000000);
-
-        let recipients = soroban_sdk::vec![&env, Address::generate(&env), Address::generate(&env)];
-        let amounts = soroban_sdk::vec![&env, 1_000_0000000i128]; // Mismatch!
+        let first = String::from_str(&env, "Grant");
+        let second = String::from_str(&env, "Hackathon");
+        setup_funded_program(&env, &client, &backend, &first, 10_000);
+        setup_funded_program(&env, &client, &backend, &second, 5_000);
+        assert_eq!(client.get_total_program_funds(), 15_000);
+        assert_eq!(client.get_total_paid_out(), 0);
+
+        client.single_payout(&first, &Address::generate(&env), &3_000);
+        client.batch_payout(
+            &second,
+            &vec![&env, Address::generate(&env), Address::generate(&env)],
+            &vec![&env, 1_000, 500],
+        );
 
-        client.batch_payout(&prog_id, &recipients, &amounts);
+        assert_eq!(client.get_total_program_funds(), 10_500);
+        assert_eq!(client.get_total_paid_out(), 4_500);
     }
 
     #[test]
-    #[should_panic(expected = "Insufficient balance")]
-    fn test_batch_payout_insufficient_balance() {
+    fn test_program_info_counts_payouts_and_recipients() {
         let env = Env::default();
         env.mock_all_auths();
 
-        let admin = Address::generate(&env);
         let contract_id = env.register_contract(None, ProgramEscrowContract);
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
-        let token_client = create_token_contract(&env, &admin);
-
         let backend = Address::generate(&env);
-        let prog_id = String::from_str(&env, "Test");
-
-        client.initialize_program(&prog_id, &backend, &token_client.address);
-        client.lock_program_funds(&prog_id, &5_000_0000000);
-
-        let recipients = soroban_sdk::vec![&env, Address::generate(&env)];
-        let amounts = soroban_sdk::vec![&env, 10_000_0000000i128]; // More than available!
+        let prog_id = String::from_str(&env, "Grant");
+        setup_funded_program(&env, &client, &backend, &prog_id, 10_000);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let carol = Address::generate(&env);
+        client.single_payout(&prog_id, &alice, &1_000);
+        client.single_payout(&prog_id, &alice, &500);
+        client.batch_payout(
+            &prog_id,
+            &vec![&env, alice.clone(), bob.clone(), bob.clone()],
+            &vec![&env, 100, 200, 300],
+        );
+        client.single_payout(&prog_id, &carol, &400);
 
-        client.batch_payout(&prog_id, &recipients, &amounts);
+        let info = client.get_program_info(&prog_id);
+        assert_eq!(info.payout_count, 6);
+        assert_eq!(info.unique_recipients, 3);
     }
 
+    // ========================================================================
+    // Monitoring Tests
+    // ========================================================================
+
     #[test]
-    fn test_program_count() {
+    fn test_monitoring_counters_accumulate_across_calls() {
         let env = Env::default();
+        env.mock_all_auths();
+
         let contract_id = env.register_contract(None, ProgramEscrowContract);
         let client = ProgramEscrowContractClient::new(&env, &contract_id);
-
-        assert_eq!(client.get_program_count(), 0);
-
         let backend = Address::generate(&env);
-        let token = Address::generate(&env);
-
-        client.initialize_program(&String::from_str(&env, "P1"), &backend, &token);
-        assert_eq!(client.get_program_count(), 1);
-
-        client.initialize_program(&String::from_str(&env, "P2"), &backend, &token);
-        assert_eq!(client.get_program_count(), 2);
+        let prog_id = String::from_str(&env, "Metrics");
+        setup_funded_program(&env, &client, &backend, &prog_id, 10_000);
+
+        let after_setup = client.get_analytics().operation_count;
+        assert_eq!(after_setup, 2); // init + lock
+
+        env.ledger().set_timestamp(100);
+        client.single_payout(&prog_id, &Address::generate(&env), &1_000);
+        let after_payout = client.get_analytics().operation_count;
+        assert!(after_payout > after_setup);
+
+        env.ledger().set_timestamp(200);
+        client.batch_payout(
+            &prog_id,
+            &soroban_sdk::vec![&env, Address::generate(&env)],
+            &soroban_sdk::vec![&env, 1_000i128],
+        );
+        let after_batch = client.get_analytics().operation_count;
+        assert!(after_batch > after_payout);
+        assert_eq!(client.get_state_snapshot().total_operations, after_batch);
 
-        client.initialize_program(&String::from_str(&env, "P3"), &backend, &token);
-        assert_eq!(client.get_program_count(), 3);
+        let stats = client.get_performance_stats(&symbol_short!("payout"));
+        assert_eq!(stats.call_count, 1);
+        assert_eq!(stats.last_called, 100);
     }
 
     // ========================================================================